@@ -0,0 +1,15 @@
+contract Notes {
+    string[] notes;
+
+    // The data account grows when the new note does not fit, and the
+    // payer tops up the rent
+    @payer(payer)
+    function add(string note) external {
+        notes.push(note);
+    }
+
+    // Make space for notes up front
+    function reserve(uint64 size) external {
+        tx.accounts.dataAccount.realloc(size);
+    }
+}
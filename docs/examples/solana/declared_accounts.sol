@@ -0,0 +1,16 @@
+contract Counter {
+    address authority;
+    uint64 count;
+
+    constructor(address initial_authority) {
+        authority = initial_authority;
+    }
+
+    @signer(authorityAccount)
+    @account(config)
+    function increment() external {
+        require(tx.accounts.authorityAccount.key == authority, "wrong authority");
+        require(tx.accounts.config.data.length > 0, "config not initialized");
+        count += 1;
+    }
+}
@@ -0,0 +1,8 @@
+@emit_cpi(true)
+contract Auction {
+    event BidPlaced(address bidder, uint64 amount);
+
+    function bid(address bidder, uint64 amount) public {
+        emit BidPlaced(bidder, amount);
+    }
+}
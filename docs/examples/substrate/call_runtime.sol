@@ -0,0 +1,12 @@
+import {call_runtime} from "substrate";
+
+contract Remarks {
+    // The pallet index of `System` and the call index of `remark_with_event` in the chain runtime
+    uint8 constant SYSTEM = 0;
+    uint8 constant REMARK_WITH_EVENT = 7;
+
+    function remark(bytes message) public {
+        bool ok = call_runtime(abi.encode(SYSTEM, REMARK_WITH_EVENT, message));
+        require(ok, "runtime call failed");
+    }
+}
@@ -0,0 +1,15 @@
+contract vault {
+    bool transient locked;
+    uint64 balance;
+
+    modifier nonReentrant() {
+        require(!locked, "reentrant call");
+        locked = true;
+        _;
+        locked = false;
+    }
+
+    function deposit(uint64 amount) public nonReentrant {
+        balance += amount;
+    }
+}
@@ -0,0 +1,22 @@
+import {ecdsa_recover, ecdsa_to_eth_address} from "substrate";
+
+contract Signatures {
+    // Returns the Ethereum address which signed the message hash
+    function signer(
+        bytes32 message_hash,
+        bytes signature
+    ) public pure returns (bytes20) {
+        (bool recovered, bytes public_key) = ecdsa_recover(
+            signature,
+            message_hash
+        );
+        require(recovered, "invalid signature");
+
+        (bool converted, bytes20 eth_address) = ecdsa_to_eth_address(
+            public_key
+        );
+        require(converted, "invalid public key");
+
+        return eth_address;
+    }
+}
@@ -0,0 +1,24 @@
+import "substrate";
+
+contract Proxy {
+    address implementation;
+    address admin;
+
+    constructor(address _implementation) {
+        implementation = _implementation;
+        admin = msg.sender;
+    }
+
+    // Run the code of the implementation contract on the storage of this contract
+    function forward(bytes input) public returns (bytes) {
+        (bool ok, bytes ret) = implementation.delegatecall(input);
+        require(ok, "delegatecall failed");
+        return ret;
+    }
+
+    // Replace the code of this contract; the storage is left as is
+    function upgrade(bytes32 code_hash) public {
+        require(msg.sender == admin, "only admin");
+        set_code_hash(code_hash);
+    }
+}
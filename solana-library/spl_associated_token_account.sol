@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Disclaimer: This library provides a way for Solidity to interact with Solana's SPL Associated Token Account program.
+// Although it is production ready, it has not been audited for security, so use it at your own risk.
+
+import 'solana';
+
+library SplAssociatedTokenAccount {
+	address constant associatedTokenProgramId = address"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+	address constant tokenProgramId = address"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+	address constant systemAddress = address"11111111111111111111111111111111";
+
+	enum AssociatedTokenAccountInstruction {
+		Create, // 0
+		CreateIdempotent, // 1
+		RecoverNested // 2
+	}
+
+	/// Derive the address of the associated token account of a wallet for an spl-token mint
+	///
+	/// @param wallet the public key of the wallet which owns the associated token account
+	/// @param mint the mint for this token
+	/// @return the address of the associated token account
+	function get_associated_token_address(address wallet, address mint) internal pure returns (address) {
+		return get_associated_token_address_with_program_id(wallet, mint, tokenProgramId);
+	}
+
+	/// Derive the address of the associated token account of a wallet for a mint owned by
+	/// @token_program_id, e.g. Token-2022.
+	///
+	/// @param wallet the public key of the wallet which owns the associated token account
+	/// @param mint the mint for this token
+	/// @param token_program_id the token program which owns the mint
+	/// @return the address of the associated token account
+	function get_associated_token_address_with_program_id(address wallet, address mint, address token_program_id) internal pure returns (address) {
+		(address ata, ) = try_find_program_address(
+			[abi.encode(wallet), abi.encode(token_program_id), abi.encode(mint)],
+			associatedTokenProgramId
+		);
+
+		return ata;
+	}
+
+	/// Create the associated token account of a wallet. The transaction fails if the account already exists.
+	/// This transaction should be signed by the payer.
+	///
+	/// @param payer the account which pays for the new account
+	/// @param wallet the public key of the wallet which owns the associated token account
+	/// @param mint the mint for this token
+	/// @param token_program_id the token program which owns the mint
+	function create(address payer, address wallet, address mint, address token_program_id) internal {
+		create_instruction(AssociatedTokenAccountInstruction.Create, payer, wallet, mint, token_program_id);
+	}
+
+	/// Create the associated token account of a wallet, unless it already exists. This transaction should be
+	/// signed by the payer.
+	///
+	/// @param payer the account which pays for the new account
+	/// @param wallet the public key of the wallet which owns the associated token account
+	/// @param mint the mint for this token
+	/// @param token_program_id the token program which owns the mint
+	function create_idempotent(address payer, address wallet, address mint, address token_program_id) internal {
+		create_instruction(AssociatedTokenAccountInstruction.CreateIdempotent, payer, wallet, mint, token_program_id);
+	}
+
+	function create_instruction(AssociatedTokenAccountInstruction instruction, address payer, address wallet, address mint, address token_program_id) private {
+		address ata = get_associated_token_address_with_program_id(wallet, mint, token_program_id);
+
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(instruction);
+
+		AccountMeta[6] metas = [
+			AccountMeta({pubkey: payer, is_writable: true, is_signer: true}),
+			AccountMeta({pubkey: ata, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: wallet, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: systemAddress, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: token_program_id, is_writable: false, is_signer: false})
+		];
+
+		associatedTokenProgramId.call{accounts: metas}(instr);
+	}
+}
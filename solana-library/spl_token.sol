@@ -145,6 +145,111 @@ library SplToken {
 		tokenProgramId.call{accounts: metas}(instr);
 	}
 
+	/// Transfer @amount token from @from to @to. The mint and its number of decimals are checked by the
+	/// token program. The transaction should be signed by the owner keypair of the from account.
+	///
+	/// @param from the account to transfer tokens from
+	/// @param mint the mint for this token
+	/// @param to the account to transfer tokens to
+	/// @param owner the publickey of the from account owner keypair
+	/// @param amount the amount to transfer
+	/// @param decimals the number of decimals of the mint
+	function transfer_checked(address from, address mint, address to, address owner, uint64 amount, uint8 decimals) internal {
+		bytes instr = abi.encode(uint8(TokenInstruction.TransferChecked), amount, decimals);
+
+		AccountMeta[4] metas = [
+			AccountMeta({pubkey: from, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: to, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		tokenProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Close a token account and transfer its lamports to @destination. The token balance must be zero.
+	/// This transaction should be signed by the owner or the close authority.
+	///
+	/// @param account the token account to close
+	/// @param destination the account which receives the lamports
+	/// @param owner the publickey of the account owner or close authority keypair
+	function close_account(address account, address destination, address owner) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.CloseAccount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: destination, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		tokenProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Set a new authority of a mint or token account. This transaction should be signed by the
+	/// current authority.
+	///
+	/// @param account the mint or token account whose authority is changed
+	/// @param current_authority the publickey of the current authority keypair
+	/// @param authority_type the type of authority to change
+	/// @param new_authority the new authority, or address(0) to remove the authority
+	function set_authority(address account, address current_authority, AuthorityType authority_type, address new_authority) internal {
+		bytes instr;
+
+		if (new_authority == address(0)) {
+			instr = abi.encode(uint8(TokenInstruction.SetAuthority), uint8(authority_type), uint8(0));
+		} else {
+			instr = abi.encode(uint8(TokenInstruction.SetAuthority), uint8(authority_type), uint8(1), new_authority);
+		}
+
+		AccountMeta[2] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: current_authority, is_writable: false, is_signer: true})
+		];
+
+		tokenProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Freeze a token account, so no tokens can be moved in or out. This transaction should be signed
+	/// by the freeze authority of the mint.
+	///
+	/// @param account the token account to freeze
+	/// @param mint the mint for this token
+	/// @param freeze_authority the publickey of the freeze authority keypair
+	function freeze_account(address account, address mint, address freeze_authority) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.FreezeAccount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: freeze_authority, is_writable: false, is_signer: true})
+		];
+
+		tokenProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Thaw a frozen token account. This transaction should be signed by the freeze authority of the mint.
+	///
+	/// @param account the token account to thaw
+	/// @param mint the mint for this token
+	/// @param freeze_authority the publickey of the freeze authority keypair
+	function thaw_account(address account, address mint, address freeze_authority) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.ThawAccount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: freeze_authority, is_writable: false, is_signer: true})
+		];
+
+		tokenProgramId.call{accounts: metas}(instr);
+	}
+
 	/// Get the total supply for the mint, i.e. the total amount in circulation
 	/// @param mint the mint for this token
 	function total_supply(address mint) internal view returns (uint64) {
@@ -217,7 +322,7 @@ library SplToken {
 				is_native_present: ai.data.readUint32LE(109) > 0,
 				is_native: ai.data.readUint64LE(113),
 				delegated_amount: ai.data.readUint64LE(121),
-				close_authority_present: ai.data.readUint32LE(129) > 10,
+				close_authority_present: ai.data.readUint32LE(129) > 0,
 				close_authority: ai.data.readAddress(133)
 			}
 		);
@@ -0,0 +1,599 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Disclaimer: This library provides a way for Solidity to interact with Solana's SPL Token-2022 program. Although it is production ready,
+// it has not been audited for security, so use it at your own risk.
+
+import 'solana';
+
+library SplToken2022 {
+	address constant token2022ProgramId = address"TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+	address constant systemAddress = address"11111111111111111111111111111111";
+
+	/// The size of a token account without extensions. Mints with extensions are padded to this size as well.
+	uint64 constant BASE_ACCOUNT_LENGTH = 165;
+
+	enum TokenInstruction {
+		InitializeMint, // 0
+		InitializeAccount, // 1
+		InitializeMultisig, // 2
+		Transfer, // 3
+		Approve, // 4
+		Revoke, // 5
+		SetAuthority, // 6
+		MintTo, // 7
+		Burn, // 8
+		CloseAccount, // 9
+		FreezeAccount, // 10
+		ThawAccount, // 11
+		TransferChecked, // 12
+		ApproveChecked, // 13
+		MintToChecked, // 14
+		BurnChecked, // 15
+		InitializeAccount2, // 16
+		SyncNative, // 17
+		InitializeAccount3, // 18
+		InitializeMultisig2, // 19
+		InitializeMint2, // 20
+		GetAccountDataSize, // 21
+		InitializeImmutableOwner, // 22
+		AmountToUiAmount, // 23
+		UiAmountToAmount, // 24
+		InitializeMintCloseAuthority, // 25
+		TransferFeeExtension, // 26
+		ConfidentialTransferExtension, // 27
+		DefaultAccountStateExtension, // 28
+		Reallocate, // 29
+		MemoTransferExtension, // 30
+		CreateNativeMint, // 31
+		InitializeNonTransferableMint, // 32
+		InterestBearingMintExtension, // 33
+		CpiGuardExtension, // 34
+		InitializePermanentDelegate // 35
+	}
+
+	/// The extensions a mint or token account can have
+	enum ExtensionType {
+		Uninitialized, // 0
+		TransferFeeConfig, // 1
+		TransferFeeAmount, // 2
+		MintCloseAuthority, // 3
+		ConfidentialTransferMint, // 4
+		ConfidentialTransferAccount, // 5
+		DefaultAccountState, // 6
+		ImmutableOwner, // 7
+		MemoTransfer, // 8
+		NonTransferable, // 9
+		InterestBearingConfig, // 10
+		CpiGuard, // 11
+		PermanentDelegate, // 12
+		NonTransferableAccount, // 13
+		TransferHook, // 14
+		TransferHookAccount, // 15
+		ConfidentialTransferFeeConfig, // 16
+		ConfidentialTransferFeeAmount, // 17
+		MetadataPointer, // 18
+		TokenMetadata // 19
+	}
+
+	// A mint or token account has authorities, whose type is one of the members of this enum.
+	enum AuthorityType {
+		MintTokens, // 0
+		FreezeAccount, // 1
+		AccountOwner, // 2
+		CloseAccount, // 3
+		TransferFeeConfig, // 4
+		WithheldWithdraw, // 5
+		CloseMint, // 6
+		InterestRate, // 7
+		PermanentDelegate, // 8
+		ConfidentialTransferMint, // 9
+		TransferHookProgramId, // 10
+		ConfidentialTransferFeeConfig, // 11
+		MetadataPointer // 12
+	}
+
+	/// This enum represents the state of a token account
+	enum AccountState {
+		Uninitialized,
+		Initialized,
+		Frozen
+	}
+
+	/// Mint new tokens. The transaction should be signed by the mint authority keypair
+	///
+	/// @param mint the account of the mint
+	/// @param account the token account where the minted tokens should go
+	/// @param authority the public key of the mint authority
+	/// @param amount the amount of tokens to mint
+	function mint_to(address mint, address account, address authority, uint64 amount) internal {
+		bytes instr = abi.encode(uint8(TokenInstruction.MintTo), amount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: authority, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Burn @amount tokens in account. This transaction should be signed by the owner.
+	///
+	/// @param account the acount for which tokens should be burned
+	/// @param mint the mint for this token
+	/// @param owner the publickey of the account owner keypair
+	/// @param amount the amount to burn
+	function burn(address account, address mint, address owner, uint64 amount) internal {
+		bytes instr = abi.encode(uint8(TokenInstruction.Burn), amount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Transfer @amount token from @from to @to. Token-2022 requires the checked transfer for mints
+	/// with extensions. The transaction should be signed by the owner keypair of the from account.
+	///
+	/// @param from the account to transfer tokens from
+	/// @param mint the mint for this token
+	/// @param to the account to transfer tokens to
+	/// @param owner the publickey of the from account owner keypair
+	/// @param amount the amount to transfer
+	/// @param decimals the number of decimals of the mint
+	function transfer_checked(address from, address mint, address to, address owner, uint64 amount, uint8 decimals) internal {
+		bytes instr = abi.encode(uint8(TokenInstruction.TransferChecked), amount, decimals);
+
+		AccountMeta[4] metas = [
+			AccountMeta({pubkey: from, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: to, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Close a token account and transfer its lamports to @destination. The token balance must be zero.
+	/// This transaction should be signed by the owner or the close authority.
+	///
+	/// @param account the token account to close
+	/// @param destination the account which receives the lamports
+	/// @param owner the publickey of the account owner or close authority keypair
+	function close_account(address account, address destination, address owner) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.CloseAccount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: destination, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Set a new authority of a mint or token account. This transaction should be signed by the
+	/// current authority.
+	///
+	/// @param account the mint or token account whose authority is changed
+	/// @param current_authority the publickey of the current authority keypair
+	/// @param authority_type the type of authority to change
+	/// @param new_authority the new authority, or address(0) to remove the authority
+	function set_authority(address account, address current_authority, AuthorityType authority_type, address new_authority) internal {
+		bytes instr = abi.encodePacked(uint8(TokenInstruction.SetAuthority), uint8(authority_type), optional_pubkey(new_authority));
+
+		AccountMeta[2] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: current_authority, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Freeze a token account, so no tokens can be moved in or out. This transaction should be signed
+	/// by the freeze authority of the mint.
+	///
+	/// @param account the token account to freeze
+	/// @param mint the mint for this token
+	/// @param freeze_authority the publickey of the freeze authority keypair
+	function freeze_account(address account, address mint, address freeze_authority) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.FreezeAccount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: freeze_authority, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Thaw a frozen token account. This transaction should be signed by the freeze authority of the mint.
+	///
+	/// @param account the token account to thaw
+	/// @param mint the mint for this token
+	/// @param freeze_authority the publickey of the freeze authority keypair
+	function thaw_account(address account, address mint, address freeze_authority) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.ThawAccount);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: freeze_authority, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Initialize the close authority of a mint. This must be done before the mint is initialized.
+	///
+	/// @param mint the account of the mint
+	/// @param close_authority the authority which may close the mint, or address(0) for none
+	function initialize_mint_close_authority(address mint, address close_authority) internal {
+		bytes instr = abi.encodePacked(uint8(TokenInstruction.InitializeMintCloseAuthority), optional_pubkey(close_authority));
+
+		AccountMeta[1] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Initialize the transfer fee of a mint. This must be done before the mint is initialized.
+	///
+	/// @param mint the account of the mint
+	/// @param config_authority the authority which may change the fee, or address(0) for none
+	/// @param withdraw_authority the authority which may withdraw the withheld fees, or address(0) for none
+	/// @param transfer_fee_basis_points the fee in hundredths of a percent of the transferred amount
+	/// @param maximum_fee the maximum fee of a single transfer
+	function initialize_transfer_fee_config(address mint, address config_authority, address withdraw_authority, uint16 transfer_fee_basis_points, uint64 maximum_fee) internal {
+		bytes instr = abi.encodePacked(
+			uint8(TokenInstruction.TransferFeeExtension),
+			uint8(0), // InitializeTransferFeeConfig
+			optional_pubkey(config_authority),
+			optional_pubkey(withdraw_authority),
+			transfer_fee_basis_points,
+			maximum_fee
+		);
+
+		AccountMeta[1] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Transfer @amount token from @from to @to for a mint with a transfer fee. The @fee must match the
+	/// fee calculated by the token program. The transaction should be signed by the owner keypair of the
+	/// from account.
+	///
+	/// @param from the account to transfer tokens from
+	/// @param mint the mint for this token
+	/// @param to the account to transfer tokens to
+	/// @param owner the publickey of the from account owner keypair
+	/// @param amount the amount to transfer
+	/// @param decimals the number of decimals of the mint
+	/// @param fee the expected fee of the transfer
+	function transfer_checked_with_fee(address from, address mint, address to, address owner, uint64 amount, uint8 decimals, uint64 fee) internal {
+		bytes instr = abi.encode(
+			uint8(TokenInstruction.TransferFeeExtension),
+			uint8(1), // TransferCheckedWithFee
+			amount,
+			decimals,
+			fee
+		);
+
+		AccountMeta[4] metas = [
+			AccountMeta({pubkey: from, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: mint, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: to, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Withdraw the fees withheld in the mint. This transaction should be signed by the withdraw
+	/// withheld authority.
+	///
+	/// @param mint the account of the mint
+	/// @param destination the token account which receives the fees
+	/// @param withdraw_authority the publickey of the withdraw withheld authority keypair
+	function withdraw_withheld_tokens_from_mint(address mint, address destination, address withdraw_authority) internal {
+		bytes instr = abi.encode(
+			uint8(TokenInstruction.TransferFeeExtension),
+			uint8(2) // WithdrawWithheldTokensFromMint
+		);
+
+		AccountMeta[3] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: destination, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: withdraw_authority, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Set the transfer fee of a mint, which takes effect two epochs later. This transaction should be
+	/// signed by the transfer fee config authority.
+	///
+	/// @param mint the account of the mint
+	/// @param config_authority the publickey of the transfer fee config authority keypair
+	/// @param transfer_fee_basis_points the fee in hundredths of a percent of the transferred amount
+	/// @param maximum_fee the maximum fee of a single transfer
+	function set_transfer_fee(address mint, address config_authority, uint16 transfer_fee_basis_points, uint64 maximum_fee) internal {
+		bytes instr = abi.encode(
+			uint8(TokenInstruction.TransferFeeExtension),
+			uint8(5), // SetTransferFee
+			transfer_fee_basis_points,
+			maximum_fee
+		);
+
+		AccountMeta[2] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: config_authority, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Require or stop requiring a memo for incoming transfers of a token account. This transaction
+	/// should be signed by the owner.
+	///
+	/// @param account the token account
+	/// @param owner the publickey of the account owner keypair
+	/// @param required whether incoming transfers require a memo
+	function set_required_memo_transfers(address account, address owner, bool required) internal {
+		bytes instr = abi.encode(
+			uint8(TokenInstruction.MemoTransferExtension),
+			required ? uint8(0) : uint8(1) // Enable or Disable
+		);
+
+		AccountMeta[2] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Initialize the default state of new token accounts of a mint. This must be done before the mint
+	/// is initialized.
+	///
+	/// @param mint the account of the mint
+	/// @param state the state of new token accounts
+	function initialize_default_account_state(address mint, AccountState state) internal {
+		bytes instr = abi.encode(
+			uint8(TokenInstruction.DefaultAccountStateExtension),
+			uint8(0), // Initialize
+			uint8(state)
+		);
+
+		AccountMeta[1] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Make the owner of a token account immutable. This must be done before the account is initialized.
+	///
+	/// @param account the token account
+	function initialize_immutable_owner(address account) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.InitializeImmutableOwner);
+
+		AccountMeta[1] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Make the tokens of a mint non-transferable. This must be done before the mint is initialized.
+	///
+	/// @param mint the account of the mint
+	function initialize_non_transferable_mint(address mint) internal {
+		bytes instr = new bytes(1);
+
+		instr[0] = uint8(TokenInstruction.InitializeNonTransferableMint);
+
+		AccountMeta[1] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Initialize the permanent delegate of a mint, which may transfer or burn tokens from any account
+	/// of the mint. This must be done before the mint is initialized.
+	///
+	/// @param mint the account of the mint
+	/// @param delegate the permanent delegate
+	function initialize_permanent_delegate(address mint, address delegate) internal {
+		bytes instr = abi.encode(uint8(TokenInstruction.InitializePermanentDelegate), delegate);
+
+		AccountMeta[1] metas = [
+			AccountMeta({pubkey: mint, is_writable: true, is_signer: false})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Grow a token account so it can hold the given extensions. This transaction should be signed by
+	/// the payer and the owner.
+	///
+	/// @param account the token account
+	/// @param payer the account which pays for the additional space
+	/// @param owner the publickey of the account owner keypair
+	/// @param extensions the extensions to make room for
+	function reallocate(address account, address payer, address owner, ExtensionType[] extensions) internal {
+		bytes instr = new bytes(1 + extensions.length * 2);
+
+		instr[0] = uint8(TokenInstruction.Reallocate);
+
+		for (uint32 i = 0; i < extensions.length; i++) {
+			instr.writeUint16LE(uint16(extensions[i]), 1 + i * 2);
+		}
+
+		AccountMeta[4] metas = [
+			AccountMeta({pubkey: account, is_writable: true, is_signer: false}),
+			AccountMeta({pubkey: payer, is_writable: true, is_signer: true}),
+			AccountMeta({pubkey: systemAddress, is_writable: false, is_signer: false}),
+			AccountMeta({pubkey: owner, is_writable: false, is_signer: true})
+		];
+
+		token2022ProgramId.call{accounts: metas}(instr);
+	}
+
+	/// Get the account info for an account. This walks the transaction account infos
+	/// and find the account info, or the transaction fails.
+	///
+	/// @param account the account for which we want to have the acount info.
+	function get_account_info(address account) internal view returns (AccountInfo) {
+		for (uint64 i = 0; i < tx.accounts.length; i++) {
+			AccountInfo ai = tx.accounts[i];
+			if (ai.key == account) {
+				return ai;
+			}
+		}
+
+		revert("account missing");
+	}
+
+	/// Get the balance for an account.
+	///
+	/// @param account the account for which we want to know a balance
+	function get_balance(address account) internal view returns (uint64) {
+		AccountInfo ai = get_account_info(account);
+
+		return ai.data.readUint64LE(64);
+	}
+
+	/// Get the total supply for the mint, i.e. the total amount in circulation
+	/// @param mint the mint for this token
+	function total_supply(address mint) internal view returns (uint64) {
+		AccountInfo ai = get_account_info(mint);
+
+		return ai.data.readUint64LE(36);
+	}
+
+	/// Find an extension of a mint or token account. The extensions are stored as type-length-value
+	/// entries after the base account and a byte for the account type.
+	///
+	/// @param account the mint or token account
+	/// @param extension the extension to look for
+	/// @return whether the account has the extension, and the data of the extension
+	function get_extension_data(address account, ExtensionType extension) internal view returns (bool, bytes) {
+		AccountInfo ai = get_account_info(account);
+		uint64 offset = BASE_ACCOUNT_LENGTH + 1;
+
+		while (offset + 4 <= ai.data.length) {
+			uint16 ty = ai.data.readUint16LE(offset);
+			uint16 length = ai.data.readUint16LE(offset + 2);
+
+			if (ty == uint16(ExtensionType.Uninitialized)) {
+				break;
+			}
+
+			offset += 4;
+
+			if (ty == uint16(extension)) {
+				bytes data = new bytes(length);
+
+				for (uint64 i = 0; i < length; i++) {
+					data[i] = ai.data[offset + i];
+				}
+
+				return (true, data);
+			}
+
+			offset += length;
+		}
+
+		return (false, hex"");
+	}
+
+	/// A transfer fee, which is effective from @epoch onwards
+	struct TransferFee {
+		uint64 epoch;
+		uint64 maximum_fee;
+		uint16 transfer_fee_basis_points;
+	}
+
+	/// This struct is the return of 'get_transfer_fee_config'
+	struct TransferFeeConfig {
+		address transfer_fee_config_authority;
+		address withdraw_withheld_authority;
+		uint64 withheld_amount;
+		TransferFee older_transfer_fee;
+		TransferFee newer_transfer_fee;
+	}
+
+	/// Retrieve the transfer fee configuration of a mint. The transaction fails if the mint has no
+	/// transfer fee.
+	///
+	/// @param mint the account of the mint
+	/// @return the TransferFeeConfig struct
+	function get_transfer_fee_config(address mint) internal view returns (TransferFeeConfig) {
+		(bool found, bytes data) = get_extension_data(mint, ExtensionType.TransferFeeConfig);
+
+		require(found, "no transfer fee");
+
+		return TransferFeeConfig({
+			transfer_fee_config_authority: data.readAddress(0),
+			withdraw_withheld_authority: data.readAddress(32),
+			withheld_amount: data.readUint64LE(64),
+			older_transfer_fee: TransferFee({
+				epoch: data.readUint64LE(72),
+				maximum_fee: data.readUint64LE(80),
+				transfer_fee_basis_points: data.readUint16LE(88)
+			}),
+			newer_transfer_fee: TransferFee({
+				epoch: data.readUint64LE(90),
+				maximum_fee: data.readUint64LE(98),
+				transfer_fee_basis_points: data.readUint16LE(106)
+			})
+		});
+	}
+
+	/// Calculate the fee of a transfer the same way the token program does, so it can be passed
+	/// to 'transfer_checked_with_fee'.
+	///
+	/// @param config the transfer fee configuration of the mint
+	/// @param epoch the current epoch
+	/// @param amount the amount to transfer
+	/// @return the fee of the transfer
+	function calculate_fee(TransferFeeConfig config, uint64 epoch, uint64 amount) internal pure returns (uint64) {
+		TransferFee fee = epoch >= config.newer_transfer_fee.epoch ? config.newer_transfer_fee : config.older_transfer_fee;
+
+		if (fee.transfer_fee_basis_points == 0 || amount == 0) {
+			return 0;
+		}
+
+		// the fee is rounded up
+		uint128 raw = (uint128(amount) * fee.transfer_fee_basis_points + 9999) / 10000;
+
+		return raw > fee.maximum_fee ? fee.maximum_fee : uint64(raw);
+	}
+
+	/// Encode an optional public key as a token instruction argument, which must be packed into the
+	/// instruction with abi.encodePacked(). address(0) means none.
+	function optional_pubkey(address key) private pure returns (bytes) {
+		if (key == address(0)) {
+			return abi.encode(uint8(0));
+		}
+
+		return abi.encode(uint8(1), key);
+	}
+}
@@ -6,6 +6,29 @@ use crate::pt;
 use crate::pt::Loc;
 use std::fmt;
 
+/// The generic codes which the [`Diagnostic`] constructors set, for diagnostics which do not
+/// have a more specific code.
+pub mod codes {
+    /// A debug message
+    pub const DEBUG: &str = "I0002";
+    /// An informational message
+    pub const INFO: &str = "I0001";
+    /// An error without a more specific type
+    pub const ERROR: &str = "E0001";
+    /// A parser error
+    pub const PARSER_ERROR: &str = "E0002";
+    /// A syntax error
+    pub const SYNTAX_ERROR: &str = "E0003";
+    /// A declaration error
+    pub const DECLARATION_ERROR: &str = "E0004";
+    /// A cast error
+    pub const CAST_ERROR: &str = "E0005";
+    /// A type error
+    pub const TYPE_ERROR: &str = "E0006";
+    /// A warning without a more specific code
+    pub const WARNING: &str = "W0001";
+}
+
 /// The level of a diagnostic.
 #[derive(Clone, Debug, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub enum Level {
@@ -78,6 +101,9 @@ pub struct Diagnostic {
     pub message: String,
     /// Extra notes about the diagnostic.
     pub notes: Vec<Note>,
+    /// The stable code which identifies the kind of diagnostic, like `W0012`. The
+    /// constructors set a generic code from [`codes`] for the level and type of the diagnostic.
+    pub code: &'static str,
 }
 
 impl Diagnostic {
@@ -86,6 +112,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Debug,
             ty: ErrorType::None,
+            code: codes::DEBUG,
             loc,
             message,
             notes: Vec::new(),
@@ -97,6 +124,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Info,
             ty: ErrorType::None,
+            code: codes::INFO,
             loc,
             message,
             notes: Vec::new(),
@@ -108,6 +136,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::ParserError,
+            code: codes::PARSER_ERROR,
             loc,
             message,
             notes: Vec::new(),
@@ -119,6 +148,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::SyntaxError,
+            code: codes::SYNTAX_ERROR,
             loc,
             message,
             notes: Vec::new(),
@@ -130,6 +160,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::DeclarationError,
+            code: codes::DECLARATION_ERROR,
             loc,
             message,
             notes: Vec::new(),
@@ -141,6 +172,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::CastError,
+            code: codes::CAST_ERROR,
             loc,
             message,
             notes: Vec::new(),
@@ -152,6 +184,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::CastError,
+            code: codes::CAST_ERROR,
             loc,
             message,
             notes: vec![Note {
@@ -166,6 +199,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::TypeError,
+            code: codes::TYPE_ERROR,
             loc,
             message,
             notes: Vec::new(),
@@ -177,6 +211,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Warning,
             ty: ErrorType::CastError,
+            code: codes::WARNING,
             loc,
             message,
             notes: Vec::new(),
@@ -188,6 +223,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Warning,
             ty: ErrorType::Warning,
+            code: codes::WARNING,
             loc,
             message,
             notes: Vec::new(),
@@ -199,6 +235,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Warning,
             ty: ErrorType::Warning,
+            code: codes::WARNING,
             loc,
             message,
             notes: vec![Note {
@@ -213,6 +250,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Warning,
             ty: ErrorType::Warning,
+            code: codes::WARNING,
             loc,
             message,
             notes,
@@ -224,6 +262,7 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::None,
+            code: codes::ERROR,
             loc,
             message,
             notes: vec![Note {
@@ -238,9 +277,16 @@ impl Diagnostic {
         Diagnostic {
             level: Level::Error,
             ty: ErrorType::None,
+            code: codes::ERROR,
             loc,
             message,
             notes,
         }
     }
+
+    /// Replace the generic code of the diagnostic with a more specific one.
+    pub fn with_code(mut self, code: &'static str) -> Self {
+        self.code = code;
+        self
+    }
 }
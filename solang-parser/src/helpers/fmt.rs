@@ -1082,6 +1082,7 @@ impl Display for pt::VariableAttribute {
             Self::Visibility(vis) => vis.fmt(f),
             Self::Constant(_) => f.write_str("constant"),
             Self::Immutable(_) => f.write_str("immutable"),
+            Self::Transient(_) => f.write_str("transient"),
             Self::Override(_, idents) => {
                 f.write_str("override")?;
                 if !idents.is_empty() {
@@ -2481,6 +2482,7 @@ mod tests {
             pt::VariableAttribute: {
                 pt::VariableAttribute::Constant(loc!()) => "constant",
                 pt::VariableAttribute::Immutable(loc!()) => "immutable",
+                pt::VariableAttribute::Transient(loc!()) => "transient",
 
                 pt::VariableAttribute::Override(loc!(), vec![]) => "override",
                 pt::VariableAttribute::Override(loc!(), vec![idp!["a", "b"]]) => "override(a.b)",
@@ -433,6 +433,7 @@ impl_for_enums! {
         Self::Visibility(ref l, ..) => l.loc_opt().unwrap_or_default(),
         Self::Constant(l, ..)
         | Self::Immutable(l, ..)
+        | Self::Transient(l, ..)
         | Self::Override(l, ..) => l,
     }
 
@@ -169,6 +169,7 @@ pub enum Token<'input> {
     Using,
     Modifier,
     Immutable,
+    Transient,
     Unchecked,
 
     Assembly,
@@ -307,6 +308,7 @@ impl<'input> fmt::Display for Token<'input> {
             Token::Using => write!(f, "using"),
             Token::Modifier => write!(f, "modifier"),
             Token::Immutable => write!(f, "immutable"),
+            Token::Transient => write!(f, "transient"),
             Token::Unchecked => write!(f, "unchecked"),
             Token::Assembly => write!(f, "assembly"),
             Token::Let => write!(f, "let"),
@@ -548,6 +550,7 @@ static KEYWORDS: phf::Map<&'static str, Token> = phf_map! {
     "using" => Token::Using,
     "modifier" => Token::Modifier,
     "immutable" => Token::Immutable,
+    "transient" => Token::Transient,
     "unchecked" => Token::Unchecked,
     "assembly" => Token::Assembly,
     "let" => Token::Let,
@@ -842,6 +842,9 @@ pub enum VariableAttribute {
     /// `immutable`
     Immutable(Loc),
 
+    /// `transient`
+    Transient(Loc),
+
     /// `ovveride(<1>,*)`
     Override(Loc, Vec<IdentifierPath>),
 }
@@ -3,9 +3,10 @@
 use crate::sema::ast::{
     ArrayLength, Contract, Function, Namespace, Parameter, StructDecl, StructType, Tag, Type,
 };
+use crate::sema::solana_accounts::BuiltinAccounts;
 use anchor_syn::idl::{
     Idl, IdlAccount, IdlAccountItem, IdlEnumVariant, IdlEvent, IdlEventField, IdlField,
-    IdlInstruction, IdlType, IdlTypeDefinition, IdlTypeDefinitionTy,
+    IdlInstruction, IdlPda, IdlSeed, IdlSeedConst, IdlType, IdlTypeDefinition, IdlTypeDefinitionTy,
 };
 use base58::ToBase58;
 use num_traits::ToPrimitive;
@@ -162,7 +163,8 @@ fn idl_instructions(
 
         let accounts = func
             .solana_accounts
-            .borrow()
+            .read()
+            .unwrap()
             .iter()
             .map(|(account_name, account)| {
                 IdlAccountItem::IdlAccount(IdlAccount {
@@ -171,7 +173,18 @@ fn idl_instructions(
                     is_signer: account.is_signer,
                     is_optional: Some(false),
                     docs: None,
-                    pda: None,
+                    pda: if account_name == BuiltinAccounts::EventAuthority {
+                        // The event authority is derived from a fixed seed, see sol_emit_cpi_event()
+                        Some(IdlPda {
+                            seeds: vec![IdlSeed::Const(IdlSeedConst {
+                                ty: IdlType::String,
+                                value: json!("__event_authority"),
+                            })],
+                            program_id: None,
+                        })
+                    } else {
+                        None
+                    },
                     relations: vec![],
                 })
             })
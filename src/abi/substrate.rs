@@ -261,9 +261,11 @@ pub fn gen_project(contract_no: usize, ns: &ast::Namespace) -> InkProject {
     // This is only used by off-chain tooling. At the moment there is no such tooling available yet.
     // So it is not exactly clear yet what this should look like.
     // For now it just contains all root layouts (you get all storage keys in use).
+    // Transient variables do not persist, so they are not part of the storage layout.
     let fields: Vec<FieldLayout<PortableForm>> = ns.contracts[contract_no]
         .layout
         .iter()
+        .filter(|layout| !layout.transient)
         .filter_map(|layout| {
             let var = &ns.contracts[layout.contract_no].variables[layout.var_no];
             if let Some(slot) = layout.slot.to_u32() {
@@ -0,0 +1,98 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use sha2::{Digest, Sha256};
+use solang::{
+    codegen::Options,
+    file_resolver::FileResolver,
+    sema::ast::{Contract, Namespace},
+    Target,
+};
+use std::{fs, path::PathBuf};
+
+/// The incremental build cache stores the code of each contract, keyed by a hash of everything
+/// which goes into building it: the compiler version, the target, the options, and the contents
+/// of all the source files in the namespace. Contracts whose key is found are not built through
+/// llvm again. Entries are never removed; the cache directory can simply be deleted.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: &str) -> Self {
+        Cache {
+            dir: PathBuf::from(dir),
+        }
+    }
+
+    /// Calculate the key for a contract
+    pub fn key(
+        contract: &Contract,
+        ns: &Namespace,
+        resolver: &FileResolver,
+        opt: &Options,
+    ) -> String {
+        let mut hasher = Sha256::new();
+
+        hasher.update(env!("SOLANG_VERSION"));
+
+        match ns.target {
+            Target::Substrate {
+                address_length,
+                value_length,
+                api_version,
+            } => hasher.update(format!(
+                "substrate {address_length} {value_length} {api_version:?}"
+            )),
+            target => hasher.update(target.to_string()),
+        }
+
+        hasher.update(format!("{opt:?}"));
+
+        hasher.update(&contract.name);
+
+        // the files are listed in the order they were imported, which depends on the sources only
+        for file in &ns.files {
+            hasher.update(file.path.to_string_lossy().as_bytes());
+
+            if file.cache_no.is_some() {
+                let (contents, _) = resolver.get_file_contents_and_number(&file.path);
+
+                hasher.update(contents.len().to_le_bytes());
+                hasher.update(contents.as_bytes());
+            }
+        }
+
+        hex::encode(hasher.finalize())
+    }
+
+    /// Look up the code for a key
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        fs::read(self.dir.join(key)).ok()
+    }
+
+    /// Store the code for a key. Failing to write to the cache is not fatal, the next build
+    /// simply has to build the contract again.
+    pub fn put(&self, key: &str, code: &[u8]) {
+        if let Err(err) = self.write(key, code) {
+            eprintln!(
+                "warning: cannot write to build cache '{}': {}",
+                self.dir.display(),
+                err
+            );
+        }
+    }
+
+    fn write(&self, key: &str, code: &[u8]) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        // write to a temporary file first, so that an interrupted build never leaves a truncated
+        // entry behind
+        let tmp = tempfile::NamedTempFile::new_in(&self.dir)?;
+
+        fs::write(tmp.path(), code)?;
+
+        tmp.persist(self.dir.join(key)).map_err(|err| err.error)?;
+
+        Ok(())
+    }
+}
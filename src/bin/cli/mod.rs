@@ -9,11 +9,19 @@ use clap_complete::Shell;
 use contract_build::OptimizationPasses;
 
 use serde::Deserialize;
-use std::{ffi::OsString, path::PathBuf, process::exit};
+use std::{
+    collections::{BTreeMap, HashMap},
+    ffi::{OsStr, OsString},
+    fs,
+    path::{Path, PathBuf},
+    process::exit,
+};
 
+use crate::deps::{self, Dependency};
 use solang::{
     codegen::{OptimizationLevel, Options},
-    file_resolver::FileResolver,
+    file_resolver::{FileResolver, Remapping},
+    sema::lints::{resolve_lints, LintLevel},
     Target,
 };
 
@@ -33,6 +41,14 @@ pub enum Commands {
     #[command(about = "Generate documention for contracts using doc comments")]
     Doc(Doc),
 
+    #[command(
+        about = "Check Solidity source files for errors and warnings, without generating code"
+    )]
+    Check(Check),
+
+    #[command(about = "Explain a diagnostic code, or list all codes")]
+    Explain(Explain),
+
     #[command(about = "Print shell completion for various shells to STDOUT")]
     ShellComplete(ShellComplete),
 
@@ -44,6 +60,18 @@ pub enum Commands {
 
     #[command(about = "Create a new Solang project")]
     New(New),
+
+    #[command(about = "Unpack the dependencies in solang.toml and lock their content hashes")]
+    Deps(Deps),
+}
+
+#[derive(Args)]
+pub struct Deps {
+    #[arg(name = "CONFFILE", help = "Take the dependencies from configuration file", long = "config-file", value_parser = ValueParser::os_string(), num_args = 1, default_value = "solang.toml")]
+    pub configuration_file: OsString,
+
+    #[arg(name = "VERIFY", help = "Check that the dependencies match solang.lock, without changing anything", long = "verify", action = ArgAction::SetTrue)]
+    pub verify: bool,
 }
 
 #[derive(Args)]
@@ -97,6 +125,34 @@ pub struct Doc {
     pub output_directory: Option<OsString>,
 }
 
+#[derive(Args)]
+pub struct Explain {
+    #[arg(
+        name = "CODE",
+        help = "Diagnostic code or name, like W0012 or unused-variable",
+        num_args = 1
+    )]
+    pub code: Option<String>,
+}
+
+#[derive(Args)]
+pub struct Check {
+    #[clap(flatten)]
+    pub package: DocPackage,
+
+    #[clap(flatten)]
+    pub target: TargetArg,
+
+    #[arg(name = "VERBOSE" ,help = "show debug messages", short = 'v', action = ArgAction::SetTrue, long = "verbose")]
+    pub verbose: bool,
+
+    #[arg(name = "FORMAT", help = "Output format for the diagnostics", long = "format", num_args = 1, value_parser = ["human", "json", "sarif"], default_value = "human")]
+    pub format: String,
+
+    #[arg(name = "CONFFILE", help = "Take the lints and dependencies from configuration file", long = "config-file", value_parser = ValueParser::os_string(), num_args = 1, default_value = "solang.toml")]
+    pub configuration_file: OsString,
+}
+
 #[derive(Args, Deserialize, Debug, PartialEq)]
 pub struct Compile {
     #[arg(name = "CONFFILE", help = "Take arguments from configuration file", long = "config-file", value_parser = ValueParser::os_string(), num_args = 0..=1, default_value = "solang.toml")]
@@ -121,6 +177,14 @@ pub struct Compile {
     #[clap(flatten)]
     #[serde(default = "Optimizations::default")]
     pub optimizations: Optimizations,
+
+    #[clap(skip)]
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintLevel>,
+
+    #[clap(skip)]
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, Dependency>,
 }
 
 impl Compile {
@@ -149,6 +213,11 @@ impl Compile {
                         .get_many::<(String, PathBuf)>("IMPORTMAP")
                         .map(|import_map| import_map.cloned().collect())
                 }
+                "REMAPPING" => {
+                    self.package.remappings = matches
+                        .get_many::<Remapping>("REMAPPING")
+                        .map(|remappings| remappings.cloned().collect())
+                }
 
                 // CompilerOutput args
                 "EMIT" => self.compiler_output.emit = matches.get_one::<String>("EMIT").cloned(),
@@ -167,6 +236,20 @@ impl Compile {
                 "VERBOSE" => {
                     self.compiler_output.verbose = *matches.get_one::<bool>("VERBOSE").unwrap()
                 }
+                "CACHE" => self.compiler_output.cache = *matches.get_one::<bool>("CACHE").unwrap(),
+                "CACHEDIR" => {
+                    self.compiler_output.cache_dir = matches.get_one::<String>("CACHEDIR").cloned()
+                }
+                "WATCH" => self.compiler_output.watch = *matches.get_one::<bool>("WATCH").unwrap(),
+                "REPORTCOMPUTEUNITS" => {
+                    self.compiler_output.report_compute_units =
+                        *matches.get_one::<bool>("REPORTCOMPUTEUNITS").unwrap()
+                }
+                "COMPUTEUNITSARGS" => {
+                    self.compiler_output.compute_units_args = matches
+                        .get_many::<(String, Vec<u8>)>("COMPUTEUNITSARGS")
+                        .map(|args| args.cloned().collect())
+                }
 
                 // DebugFeatures args
                 "NOLOGAPIRETURNS" => {
@@ -220,6 +303,10 @@ impl Compile {
                 "VALUE_LENGTH" => {
                     self.target_arg.value_length = matches.get_one::<u64>("VALUE_LENGTH").cloned()
                 }
+                "SUBSTRATE_API_VERSION" => {
+                    self.target_arg.substrate_api_version =
+                        matches.get_one::<u64>("SUBSTRATE_API_VERSION").cloned()
+                }
 
                 _ => {}
             }
@@ -235,7 +322,7 @@ pub struct CompilerOutput {
     #[serde(deserialize_with = "deserialize_emit", default)]
     pub emit: Option<String>,
 
-    #[arg(name = "STD-JSON",help = "mimic solidity json output on stdout", conflicts_with_all = ["VERBOSE", "OUTPUT", "EMIT"] , action = ArgAction::SetTrue, long = "standard-json")]
+    #[arg(name = "STD-JSON",help = "mimic solidity json output on stdout; without input files, read solidity standard json input from stdin", conflicts_with_all = ["VERBOSE", "OUTPUT", "EMIT"] , action = ArgAction::SetTrue, long = "standard-json")]
     #[serde(default)]
     pub std_json_output: bool,
 
@@ -250,6 +337,26 @@ pub struct CompilerOutput {
     #[arg(name = "VERBOSE" ,help = "show debug messages", short = 'v', action = ArgAction::SetTrue, long = "verbose")]
     #[serde(default)]
     pub verbose: bool,
+
+    #[arg(name = "CACHE", help = "Reuse the code of contracts whose sources did not change since the previous build", long = "cache", action = ArgAction::SetTrue, conflicts_with = "EMIT")]
+    #[serde(default)]
+    pub cache: bool,
+
+    #[arg(name = "CACHEDIR", help = "Directory for the build cache [default: .solang/cache]", long = "cache-dir", num_args = 1, value_parser = ValueParser::string())]
+    #[serde(default)]
+    pub cache_dir: Option<String>,
+
+    #[arg(name = "WATCH", help = "Watch the source files and their imports, and build again when they change", long = "watch", action = ArgAction::SetTrue, conflicts_with_all = ["STD-JSON", "EMIT"])]
+    #[serde(skip)]
+    pub watch: bool,
+
+    #[arg(name = "REPORTCOMPUTEUNITS", help = "Run each function in a virtual machine and report the compute units used (Solana only)", long = "report-compute-units", action = ArgAction::SetTrue, conflicts_with_all = ["STD-JSON", "EMIT"])]
+    #[serde(default)]
+    pub report_compute_units: bool,
+
+    #[arg(name = "COMPUTEUNITSARGS", help = "Borsh encoded arguments for a function in the compute units report [format: function=hex]", value_parser = ValueParser::new(parse_compute_units_args), action = ArgAction::Append, long = "compute-units-args", num_args = 1, requires = "REPORTCOMPUTEUNITS")]
+    #[serde(skip)]
+    pub compute_units_args: Option<Vec<(String, Vec<u8>)>>,
 }
 
 #[derive(Args)]
@@ -274,6 +381,9 @@ pub struct CompileTargetArg {
 
     #[arg(name = "VALUE_LENGTH", help = "Value length on Substrate", long = "value-length", num_args = 1, value_parser = value_parser!(u64).range(4..1024))]
     pub value_length: Option<u64>,
+
+    #[arg(name = "SUBSTRATE_API_VERSION", help = "Version of the contracts pallet host functions to use on Substrate", long = "substrate-api-version", num_args = 1, value_parser = value_parser!(u64).range(1..=2))]
+    pub substrate_api_version: Option<u64>,
 }
 
 #[derive(Args)]
@@ -289,6 +399,9 @@ pub struct DocPackage {
 
     #[arg(name = "IMPORTMAP", help = "Map directory to search for solidity files [format: map=path]",value_parser = ValueParser::new(parse_import_map) , action = ArgAction::Append, long = "importmap", short = 'm', num_args = 1)]
     pub import_map: Option<Vec<(String, PathBuf)>>,
+
+    #[arg(name = "REMAPPING", help = "Rewrite the prefix of imports [format: context:prefix=target]", value_parser = ValueParser::new(parse_remapping), action = ArgAction::Append, long = "remapping", num_args = 1)]
+    pub remappings: Option<Vec<Remapping>>,
 }
 
 #[derive(Args, Deserialize, Debug, PartialEq)]
@@ -306,6 +419,10 @@ pub struct CompilePackage {
     #[arg(name = "IMPORTMAP", help = "Map directory to search for solidity files [format: map=path]",value_parser = ValueParser::new(parse_import_map) , action = ArgAction::Append, long = "importmap", short = 'm', num_args = 1)]
     #[serde(deserialize_with = "deserialize_inline_table", default)]
     pub import_map: Option<Vec<(String, PathBuf)>>,
+
+    #[arg(name = "REMAPPING", help = "Rewrite the prefix of imports [format: context:prefix=target]", value_parser = ValueParser::new(parse_remapping), action = ArgAction::Append, long = "remapping", num_args = 1)]
+    #[serde(deserialize_with = "deserialize_remappings", default)]
+    pub remappings: Option<Vec<Remapping>>,
 }
 
 #[derive(Args, Deserialize, Debug, PartialEq)]
@@ -387,6 +504,7 @@ pub trait TargetArgTrait {
     fn get_name(&self) -> &String;
     fn get_address_length(&self) -> &Option<u64>;
     fn get_value_length(&self) -> &Option<u64>;
+    fn get_substrate_api_version(&self) -> &Option<u64>;
 }
 
 impl TargetArgTrait for TargetArg {
@@ -401,6 +519,10 @@ impl TargetArgTrait for TargetArg {
     fn get_value_length(&self) -> &Option<u64> {
         &self.value_length
     }
+
+    fn get_substrate_api_version(&self) -> &Option<u64> {
+        &None
+    }
 }
 
 impl TargetArgTrait for CompileTargetArg {
@@ -420,6 +542,10 @@ impl TargetArgTrait for CompileTargetArg {
     fn get_value_length(&self) -> &Option<u64> {
         &self.value_length
     }
+
+    fn get_substrate_api_version(&self) -> &Option<u64> {
+        &self.substrate_api_version
+    }
 }
 
 pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
@@ -435,6 +561,11 @@ pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
             eprintln!("error: value length cannot be modified except for substrate target");
             exit(1);
         }
+
+        if target_arg.get_substrate_api_version().is_some() {
+            eprintln!("error: substrate api version cannot be set except for substrate target");
+            exit(1);
+        }
     }
 
     let target = match target_name.as_str() {
@@ -442,6 +573,13 @@ pub(crate) fn target_arg<T: TargetArgTrait>(target_arg: &T) -> Target {
         "substrate" => solang::Target::Substrate {
             address_length: target_arg.get_address_length().unwrap_or(32) as usize,
             value_length: target_arg.get_value_length().unwrap_or(16) as usize,
+            api_version: match target_arg.get_substrate_api_version() {
+                Some(version) => solang::SubstrateApiVersion::from(*version).unwrap_or_else(|| {
+                    eprintln!("error: substrate api version {version} is not supported");
+                    exit(1);
+                }),
+                None => solang::SubstrateApiVersion::default(),
+            },
         },
         "evm" => solang::Target::EVM,
         _ => unreachable!(),
@@ -457,6 +595,7 @@ pub trait PackageTrait {
     fn get_input(&self) -> &Vec<PathBuf>;
     fn get_import_path(&self) -> &Option<Vec<PathBuf>>;
     fn get_import_map(&self) -> &Option<Vec<(String, PathBuf)>>;
+    fn get_remappings(&self) -> &Option<Vec<Remapping>>;
 }
 
 impl PackageTrait for CompilePackage {
@@ -478,6 +617,10 @@ impl PackageTrait for CompilePackage {
     fn get_import_map(&self) -> &Option<Vec<(String, PathBuf)>> {
         &self.import_map
     }
+
+    fn get_remappings(&self) -> &Option<Vec<Remapping>> {
+        &self.remappings
+    }
 }
 
 impl PackageTrait for DocPackage {
@@ -492,6 +635,10 @@ impl PackageTrait for DocPackage {
     fn get_import_map(&self) -> &Option<Vec<(String, PathBuf)>> {
         &self.import_map
     }
+
+    fn get_remappings(&self) -> &Option<Vec<Remapping>> {
+        &self.remappings
+    }
 }
 
 pub fn imports_arg<T: PackageTrait>(package: &T) -> FileResolver {
@@ -526,6 +673,34 @@ pub fn imports_arg<T: PackageTrait>(package: &T) -> FileResolver {
         }
     }
 
+    if let Some(remappings) = package.get_remappings() {
+        let current_dir = match std::env::current_dir() {
+            Ok(dir) => dir,
+            Err(e) => {
+                eprintln!("error: cannot get current directory: {e}");
+                exit(1);
+            }
+        };
+
+        for remapping in remappings {
+            let mut remapping = remapping.clone();
+
+            remapping.absolute_context(&current_dir);
+
+            resolver.add_remapping(remapping);
+        }
+    }
+
+    // Foundry projects list their remappings in remappings.txt
+    let remappings_file = Path::new("remappings.txt");
+
+    if remappings_file.is_file() {
+        if let Err(e) = resolver.add_remappings_file(remappings_file) {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+    }
+
     resolver
 }
 
@@ -562,6 +737,60 @@ pub fn options_arg(debug: &DebugFeatures, optimizations: &Optimizations) -> Opti
     }
 }
 
+pub fn lints_arg(lints: &BTreeMap<String, LintLevel>) -> HashMap<&'static str, LintLevel> {
+    match resolve_lints(lints) {
+        Ok(lints) => lints,
+        Err(e) => {
+            eprintln!("error: {e}");
+            exit(1);
+        }
+    }
+}
+
+pub fn dependencies_arg(
+    resolver: &mut FileResolver,
+    dependencies: &BTreeMap<String, Dependency>,
+    config_file: &OsStr,
+) {
+    if let Err(e) = deps::add_import_maps(resolver, dependencies, &config_dir(config_file)) {
+        eprintln!("error: {e}");
+        exit(1);
+    }
+}
+
+/// The tables of a configuration file which are used by other commands than compile
+#[derive(Deserialize, Default)]
+pub struct Project {
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintLevel>,
+
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, Dependency>,
+}
+
+/// The directory of a configuration file, which relative paths in it are relative to
+pub fn config_dir(config_file: &OsStr) -> PathBuf {
+    Path::new(config_file)
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default()
+}
+
+/// Read the `[lints]` and `[dependencies]` tables from a configuration file, if it exists
+pub fn project_from_toml(path: &OsString) -> Project {
+    let Ok(toml_data) = fs::read_to_string(path) else {
+        return Project::default();
+    };
+
+    match toml::from_str(&toml_data) {
+        Ok(project) => project,
+        Err(err) => {
+            eprintln!("{err}");
+            exit(1);
+        }
+    }
+}
+
 // Parse the import map argument. This takes the form
 /// --import-map openzeppelin=/opt/openzeppelin-contracts/contract,
 /// and returns the name of the map and the path.
@@ -573,6 +802,26 @@ fn parse_import_map(map: &str) -> Result<(String, PathBuf), String> {
     }
 }
 
+/// Parse the remapping argument. This takes the form --remapping @openzeppelin/=lib/openzeppelin/,
+/// optionally with a context in front: --remapping lib/a:@openzeppelin/=lib/a/openzeppelin/
+fn parse_remapping(remapping: &str) -> Result<Remapping, String> {
+    remapping.parse()
+}
+
+/// Parse the arguments for a function in the compute units report. This takes the form
+/// --compute-units-args transfer=0a00000000000000, and returns the name of the function and
+/// the borsh encoded arguments.
+fn parse_compute_units_args(args: &str) -> Result<(String, Vec<u8>), String> {
+    if let Some((function, value)) = args.split_once('=') {
+        match hex::decode(value.trim_start_matches("0x")) {
+            Ok(encoded) => Ok((function.to_owned(), encoded)),
+            Err(err) => Err(format!("invalid hex: {err}")),
+        }
+    } else {
+        Err("contains no '='".to_owned())
+    }
+}
+
 fn deserialize_inline_table<'de, D>(
     deserializer: D,
 ) -> Result<Option<Vec<(String, PathBuf)>>, D::Error>
@@ -603,6 +852,22 @@ where
     }
 }
 
+fn deserialize_remappings<'de, D>(deserializer: D) -> Result<Option<Vec<Remapping>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let res: Option<Vec<String>> = Option::deserialize(deserializer)?;
+
+    match res {
+        Some(remappings) => remappings
+            .iter()
+            .map(|remapping| remapping.parse().map_err(serde::de::Error::custom))
+            .collect::<Result<_, _>>()
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
 fn deserialize_emit<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -5,8 +5,8 @@
 mod tests {
     use crate::{cli, options_arg, Cli, Commands};
     use clap::{CommandFactory, Parser};
-    use solang::codegen::Options;
-    use std::path::PathBuf;
+    use solang::{codegen::Options, file_resolver::Remapping, sema::lints::LintLevel};
+    use std::{collections::BTreeMap, path::PathBuf};
 
     #[test]
     fn test() {
@@ -15,7 +15,7 @@ mod tests {
 
     #[test]
     fn parse_compile_options() {
-        let mut command: Vec<&str> = "solang compile flipper.sol --target substrate --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ').collect();
+        let mut command: Vec<&str> = "solang compile flipper.sol --target substrate --value-length=31 --address-length=33 --substrate-api-version=1 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ').collect();
         let mut cli = Cli::parse_from(command);
 
         if let Commands::Compile(compile_args) = cli.command {
@@ -26,6 +26,10 @@ mod tests {
             assert_eq!(compile_args.target_arg.name.unwrap(), "substrate");
             assert_eq!(compile_args.target_arg.address_length.unwrap(), 33_u64);
             assert_eq!(compile_args.target_arg.value_length.unwrap(), 31_u64);
+            assert_eq!(
+                compile_args.target_arg.substrate_api_version.unwrap(),
+                1_u64
+            );
             assert!(!compile_args.optimizations.common_subexpression_elimination,);
             assert!(!compile_args.optimizations.constant_folding);
             assert!(!compile_args.optimizations.dead_storage);
@@ -44,6 +48,95 @@ mod tests {
             assert!(!compile_args.debug_features.log_runtime_errors);
             assert!(compile_args.debug_features.release);
         }
+
+        command = "solang compile flipper.sol --target solana --watch --cache"
+            .split(' ')
+            .collect();
+        cli = Cli::parse_from(command);
+
+        if let Commands::Compile(compile_args) = cli.command {
+            assert!(compile_args.compiler_output.watch);
+            assert!(compile_args.compiler_output.cache);
+        }
+
+        command = "solang compile flipper.sol --target solana --report-compute-units --compute-units-args flip=0x0102 --compute-units-args get=".split(' ').collect();
+        cli = Cli::parse_from(command);
+
+        if let Commands::Compile(compile_args) = cli.command {
+            assert!(compile_args.compiler_output.report_compute_units);
+            assert_eq!(
+                compile_args.compiler_output.compute_units_args.unwrap(),
+                vec![
+                    ("flip".to_owned(), vec![1, 2]),
+                    ("get".to_owned(), Vec::new())
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn parse_deps_options() {
+        let command = "solang deps --verify".split(' ');
+        let cli = Cli::parse_from(command);
+
+        if let Commands::Deps(deps_args) = cli.command {
+            assert!(deps_args.verify);
+            assert_eq!(deps_args.configuration_file, "solang.toml");
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn parse_dependencies_toml() {
+        let project: cli::Project = toml::from_str(
+            r#"
+        [dependencies]
+        openzeppelin = { path = "lib/openzeppelin-contracts/contracts" }
+        solady = { archive = "vendor/solady.tar", root = "solady/src" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            project.dependencies["openzeppelin"].path,
+            Some(PathBuf::from("lib/openzeppelin-contracts/contracts"))
+        );
+        assert_eq!(
+            project.dependencies["solady"].root,
+            Some(PathBuf::from("solady/src"))
+        );
+
+        let res: Result<cli::Project, _> = toml::from_str(
+            r#"
+        [dependencies]
+        openzeppelin = { url = "https://example.com" }"#,
+        );
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn parse_check_options() {
+        let command = "solang check flipper.sol --target solana --format json".split(' ');
+        let cli = Cli::parse_from(command);
+
+        if let Commands::Check(check_args) = cli.command {
+            assert_eq!(check_args.package.input, vec![PathBuf::from("flipper.sol")]);
+            assert_eq!(check_args.target.name, "solana");
+            assert_eq!(check_args.format, "json");
+            assert!(!check_args.verbose);
+        } else {
+            unreachable!();
+        }
+
+        let command = "solang check flipper.sol --target substrate".split(' ');
+        let cli = Cli::parse_from(command);
+
+        if let Commands::Check(check_args) = cli.command {
+            assert_eq!(check_args.format, "human");
+        } else {
+            unreachable!();
+        }
     }
 
     #[test]
@@ -70,6 +163,29 @@ mod tests {
             ]
         );
 
+        package_toml = r#"
+            input_files = ["flipper.sol"]
+            remappings = ["@openzeppelin/=lib/openzeppelin/", "lib/a:@b/=lib/a/b/"]
+        "#;
+
+        let package: cli::CompilePackage = toml::from_str(package_toml).unwrap();
+
+        assert_eq!(
+            package.remappings.unwrap(),
+            [
+                Remapping {
+                    context: None,
+                    prefix: "@openzeppelin/".to_owned(),
+                    target: "lib/openzeppelin/".to_owned()
+                },
+                Remapping {
+                    context: Some("lib/a".to_owned()),
+                    prefix: "@b/".to_owned(),
+                    target: "lib/a/b/".to_owned()
+                }
+            ]
+        );
+
         package_toml = r#"
             input_files = ["flipper.sol"]
             import_map = ["map_name.path"]
@@ -129,13 +245,15 @@ mod tests {
         let target_toml = r#"
         name = "substrate"  # Valid targets are "solana" and "substrate"
         address_length = 32
-        value_length = 16"#;
+        value_length = 16
+        substrate_api_version = 2"#;
 
         let target: cli::CompileTargetArg = toml::from_str(target_toml).unwrap();
 
         assert_eq!(target.name.unwrap(), "substrate");
         assert_eq!(target.address_length.unwrap(), 32);
         assert_eq!(target.value_length.unwrap(), 16);
+        assert_eq!(target.substrate_api_version.unwrap(), 2);
     }
 
     #[test]
@@ -146,6 +264,9 @@ mod tests {
         emit = "ast-dot"
         output_directory = "output"
         output_meta = "metadata"
+        report_compute_units = true
+        cache = true
+        cache_dir = ".cache"
         "#;
 
         let out: cli::CompilerOutput = toml::from_str(compiler_out).unwrap();
@@ -155,11 +276,46 @@ mod tests {
         assert_eq!(out.emit, Some("ast-dot".to_owned()));
         assert_eq!(out.output_directory, Some("output".to_owned()));
         assert_eq!(out.output_meta, Some("metadata".to_owned()));
+        assert!(out.report_compute_units);
+        assert!(out.cache);
+        assert_eq!(out.cache_dir, Some(".cache".to_owned()));
 
         let default_out: cli::CompilerOutput = toml::from_str("").unwrap();
 
         assert!(!default_out.verbose);
         assert!(!default_out.std_json_output);
+        assert!(!default_out.report_compute_units);
+        assert!(!default_out.cache);
+        assert_eq!(default_out.cache_dir, None);
+    }
+
+    #[test]
+    fn parse_lints() {
+        let lints_toml = r#"
+        [package]
+        input_files = ["flipper.sol"]
+
+        [target]
+        name = "solana"
+
+        [lints]
+        unused-parameter = "off"
+        W0021 = "error""#;
+
+        let compile: cli::Compile = toml::from_str(lints_toml).unwrap();
+
+        assert_eq!(
+            compile.lints,
+            BTreeMap::from([
+                ("W0021".to_owned(), LintLevel::Error),
+                ("unused-parameter".to_owned(), LintLevel::Off)
+            ])
+        );
+
+        let lints = cli::lints_arg(&compile.lints);
+
+        assert_eq!(lints["W0013"], LintLevel::Off);
+        assert_eq!(lints["W0021"], LintLevel::Error);
     }
 
     #[test]
@@ -175,19 +331,26 @@ mod tests {
                     input: Some(vec![PathBuf::from("flipper.sol")]),
                     contracts: Some(vec!["flipper".to_owned()]),
                     import_path: Some(vec![]),
-                    import_map: Some(vec![])
+                    import_map: Some(vec![]),
+                    remappings: None
                 },
                 compiler_output: cli::CompilerOutput {
                     emit: None,
                     std_json_output: false,
                     output_directory: None,
                     output_meta: None,
-                    verbose: false
+                    verbose: false,
+                    cache: false,
+                    cache_dir: None,
+                    watch: false,
+                    report_compute_units: false,
+                    compute_units_args: None
                 },
                 target_arg: cli::CompileTargetArg {
                     name: Some("solana".to_owned()),
                     address_length: None,
-                    value_length: None
+                    value_length: None,
+                    substrate_api_version: None
                 },
                 debug_features: cli::DebugFeatures {
                     log_api_return_codes: true,
@@ -205,11 +368,13 @@ mod tests {
                     opt_level: Some("default".to_owned()),
                     #[cfg(feature = "wasm_opt")]
                     wasm_opt_passes: None
-                }
+                },
+                lints: BTreeMap::new(),
+                dependencies: BTreeMap::new()
             }
         );
 
-        let command = "solang compile flipper.sol sesa.sol --config-file solang.toml --target substrate --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ');
+        let command = "solang compile flipper.sol sesa.sol --config-file solang.toml --target substrate --value-length=31 --address-length=33 --substrate-api-version=1 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ');
 
         let matches = Cli::command().get_matches_from(command);
 
@@ -228,19 +393,26 @@ mod tests {
                     ]),
                     contracts: Some(vec!["flipper".to_owned()]),
                     import_path: Some(vec![]),
-                    import_map: Some(vec![])
+                    import_map: Some(vec![]),
+                    remappings: None
                 },
                 compiler_output: cli::CompilerOutput {
                     emit: None,
                     std_json_output: false,
                     output_directory: None,
                     output_meta: None,
-                    verbose: false
+                    verbose: false,
+                    cache: false,
+                    cache_dir: None,
+                    watch: false,
+                    report_compute_units: false,
+                    compute_units_args: None
                 },
                 target_arg: cli::CompileTargetArg {
                     name: Some("substrate".to_owned()),
                     address_length: Some(33),
-                    value_length: Some(31)
+                    value_length: Some(31),
+                    substrate_api_version: Some(1)
                 },
                 debug_features: cli::DebugFeatures {
                     log_api_return_codes: true,
@@ -258,7 +430,9 @@ mod tests {
                     opt_level: Some("aggressive".to_owned()),
                     #[cfg(feature = "wasm_opt")]
                     wasm_opt_passes: None
-                }
+                },
+                lints: BTreeMap::new(),
+                dependencies: BTreeMap::new()
             }
         );
     }
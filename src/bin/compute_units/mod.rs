@@ -0,0 +1,839 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use anchor_syn::idl::{Idl, IdlAccountItem, IdlInstruction, IdlType, IdlTypeDefinitionTy};
+use base58::FromBase58;
+use num_traits::ToPrimitive;
+use sha2::{Digest, Sha256};
+use solana_rbpf::{
+    ebpf,
+    elf::Executable,
+    error::EbpfError,
+    memory_region::{AccessType, MemoryMapping, MemoryRegion},
+    verifier::RequisiteVerifier,
+    vm::{BuiltInProgram, Config, ContextObject, EbpfVm, ProgramResult, VerifiedExecutable},
+};
+use solang::{
+    abi::anchor::{discriminator, generate_anchor_idl},
+    sema::ast::Namespace,
+};
+use std::{collections::HashMap, mem::size_of, process::exit, sync::Arc};
+use tiny_keccak::{Hasher, Keccak};
+
+/// Compute units available to a single instruction, unless the transaction requests more
+const COMPUTE_UNIT_LIMIT: u64 = 200_000;
+const HEAP_SIZE: usize = 32 * 1024;
+const MAX_PERMITTED_DATA_INCREASE: usize = 10 * 1024;
+/// Space in the data account beyond the fixed layout, for dynamic storage
+const DATA_ACCOUNT_HEAP: usize = 4096;
+const ACCOUNT_LAMPORTS: u64 = 1_000_000_000;
+
+// Syscall costs, as charged by the Solana runtime
+const SYSCALL_BASE_COST: u64 = 100;
+const MEM_OP_BASE_COST: u64 = 10;
+const SHA256_BASE_COST: u64 = 85;
+const CREATE_PROGRAM_ADDRESS_UNITS: u64 = 1500;
+const INVOKE_UNITS: u64 = 1000;
+const CPI_BYTES_PER_UNIT: u64 = 250;
+const SYSVAR_BASE_COST: u64 = 100;
+
+const CLOCK_SIZE: usize = 40;
+const RENT_SIZE: usize = 24;
+const EPOCH_SCHEDULE_SIZE: usize = 40;
+
+type Account = [u8; 32];
+
+/// Error handling for syscall methods
+macro_rules! question_mark {
+    ( $value:expr, $result:ident ) => {{
+        let value = $value;
+        match value {
+            Err(err) => {
+                *$result = ProgramResult::Err(err);
+                return;
+            }
+            Ok(value) => value,
+        }
+    }};
+}
+
+/// Run every instruction of a Solana contract in an embedded eBPF virtual machine and print the
+/// number of compute units each one consumes. The constructor runs first so that the data account
+/// is initialized; each instruction after that sees the storage left behind by the previous ones.
+///
+/// Arguments are borsh encoded and are taken from `args` if the instruction is listed there, else
+/// every argument is set to its default value. Cross-program invocations are not executed; only
+/// the cost of the invoke syscall is counted.
+pub fn report(contract_no: usize, ns: &Namespace, code: &[u8], args: &HashMap<String, Vec<u8>>) {
+    let contract = &ns.contracts[contract_no];
+    let idl = generate_anchor_idl(contract_no, ns);
+
+    let program_id = if let Some(program_id) = &contract.program_id {
+        program_id.clone().try_into().unwrap()
+    } else {
+        account_key(&contract.name)
+    };
+
+    let data_len = contract.fixed_layout_size.to_usize().unwrap() + DATA_ACCOUNT_HEAP;
+
+    let mut runner = match Runner::new(code, program_id, data_len) {
+        Ok(runner) => runner,
+        Err(err) => {
+            eprintln!(
+                "error: cannot load contract {} in virtual machine: {err}",
+                contract.name
+            );
+            exit(1);
+        }
+    };
+
+    // the constructor should always run first
+    let mut instructions: Vec<&IdlInstruction> = idl.instructions.iter().collect();
+    instructions.sort_by_key(|instr| instr.name != "new");
+
+    let mut results = Vec::new();
+
+    for instr in instructions {
+        let mut calldata = discriminator("global", &instr.name);
+
+        if let Some(encoded) = args.get(&instr.name) {
+            calldata.extend_from_slice(encoded);
+        } else {
+            for arg in &instr.args {
+                default_value(&arg.ty, &idl, &mut calldata);
+            }
+        }
+
+        let (units, outcome) = runner.execute(instr, &calldata);
+
+        results.push((instr.name.as_str(), units, outcome));
+    }
+
+    let width = results
+        .iter()
+        .map(|(name, ..)| name.len())
+        .max()
+        .unwrap_or_default();
+
+    println!("Compute units for contract {}:", contract.name);
+
+    for (name, units, outcome) in results {
+        if let Err(err) = outcome {
+            println!("  {name:width$}  {units:>7}  (failed: {err})");
+        } else {
+            println!("  {name:width$}  {units:>7}");
+        }
+    }
+}
+
+/// Encode the default value for the given type, i.e. zero, false, or an empty string or array
+fn default_value(ty: &IdlType, idl: &Idl, calldata: &mut Vec<u8>) {
+    let zeros = |calldata: &mut Vec<u8>, len: usize| calldata.resize(calldata.len() + len, 0);
+
+    match ty {
+        IdlType::Bool | IdlType::U8 | IdlType::I8 => zeros(calldata, 1),
+        IdlType::U16 | IdlType::I16 => zeros(calldata, 2),
+        IdlType::U32 | IdlType::I32 | IdlType::F32 => zeros(calldata, 4),
+        IdlType::U64 | IdlType::I64 | IdlType::F64 => zeros(calldata, 8),
+        IdlType::U128 | IdlType::I128 => zeros(calldata, 16),
+        IdlType::U256 | IdlType::I256 | IdlType::PublicKey => zeros(calldata, 32),
+        // length prefix of zero
+        IdlType::Bytes | IdlType::String | IdlType::Vec(_) => zeros(calldata, 4),
+        // None
+        IdlType::Option(_) => zeros(calldata, 1),
+        IdlType::Array(ty, size) => {
+            for _ in 0..*size {
+                default_value(ty, idl, calldata);
+            }
+        }
+        IdlType::Defined(name) => {
+            let def = idl.types.iter().find(|def| def.name == *name).unwrap();
+
+            match &def.ty {
+                IdlTypeDefinitionTy::Struct { fields } => {
+                    for field in fields {
+                        default_value(&field.ty, idl, calldata);
+                    }
+                }
+                // first variant
+                IdlTypeDefinitionTy::Enum { .. } => zeros(calldata, 1),
+            }
+        }
+    }
+}
+
+/// Deterministic address for an account, so the report does not vary from run to run
+fn account_key(name: &str) -> Account {
+    Sha256::digest(name.as_bytes()).into()
+}
+
+fn sysvar_key(name: &str) -> Account {
+    name.from_base58().unwrap().try_into().unwrap()
+}
+
+#[derive(Clone)]
+struct AccountState {
+    key: Account,
+    owner: Account,
+    lamports: u64,
+    data: Vec<u8>,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+impl AccountState {
+    fn new(key: Account, data: Vec<u8>) -> Self {
+        AccountState {
+            key,
+            owner: [0; 32],
+            lamports: ACCOUNT_LAMPORTS,
+            data,
+            is_signer: false,
+            is_writable: false,
+        }
+    }
+}
+
+struct SyscallContext {
+    remaining: u64,
+    program_id: Account,
+    return_data: Vec<u8>,
+    panicked: bool,
+}
+
+impl ContextObject for SyscallContext {
+    fn trace(&mut self, _state: [u64; 12]) {}
+
+    fn consume(&mut self, amount: u64) {
+        self.remaining = self.remaining.saturating_sub(amount);
+    }
+
+    fn get_remaining(&self) -> u64 {
+        self.remaining
+    }
+}
+
+struct Runner {
+    executable: VerifiedExecutable<RequisiteVerifier, SyscallContext>,
+    program_id: Account,
+    data_account: AccountState,
+}
+
+impl Runner {
+    fn new(code: &[u8], program_id: Account, data_len: usize) -> Result<Self, EbpfError> {
+        let mut loader: BuiltInProgram<SyscallContext> = BuiltInProgram::new_loader(Config {
+            static_syscalls: false,
+            enable_symbol_and_section_labels: true,
+            dynamic_stack_frames: false,
+            ..Config::default()
+        });
+
+        loader.register_function_by_name("sol_panic_", sol_panic_)?;
+        loader.register_function_by_name("sol_log_", sol_log)?;
+        loader.register_function_by_name("sol_log_pubkey", sol_log_pubkey)?;
+        loader.register_function_by_name("sol_log_64_", sol_log_u64)?;
+        loader.register_function_by_name("sol_log_data", sol_log_data)?;
+        loader.register_function_by_name("sol_sha256", sol_sha256)?;
+        loader.register_function_by_name("sol_keccak256", sol_keccak256)?;
+        loader
+            .register_function_by_name("sol_create_program_address", sol_create_program_address)?;
+        loader.register_function_by_name(
+            "sol_try_find_program_address",
+            sol_try_find_program_address,
+        )?;
+        loader.register_function_by_name("sol_invoke_signed_c", sol_invoke_signed_c)?;
+        loader.register_function_by_name("sol_set_return_data", sol_set_return_data)?;
+        loader.register_function_by_name("sol_get_return_data", sol_get_return_data)?;
+        loader.register_function_by_name("sol_get_clock_sysvar", sol_get_clock_sysvar)?;
+        loader.register_function_by_name("sol_get_rent_sysvar", sol_get_rent_sysvar)?;
+        loader.register_function_by_name(
+            "sol_get_epoch_schedule_sysvar",
+            sol_get_epoch_schedule_sysvar,
+        )?;
+
+        let executable = Executable::<SyscallContext>::from_elf(code, Arc::new(loader))?;
+        let executable = VerifiedExecutable::from_executable(executable)?;
+
+        let mut data_account = AccountState::new(account_key("dataAccount"), vec![0; data_len]);
+        data_account.owner = program_id;
+
+        Ok(Runner {
+            executable,
+            program_id,
+            data_account,
+        })
+    }
+
+    /// Execute a single instruction, and return the compute units it consumed
+    fn execute(&mut self, instr: &IdlInstruction, calldata: &[u8]) -> (u64, Result<(), String>) {
+        let accounts: Vec<AccountState> = instr
+            .accounts
+            .iter()
+            .filter_map(|item| {
+                if let IdlAccountItem::IdlAccount(account) = item {
+                    let mut state = match account.name.as_str() {
+                        "dataAccount" => self.data_account.clone(),
+                        "systemProgram" => AccountState::new([0; 32], Vec::new()),
+                        "clock" => AccountState::new(
+                            sysvar_key("SysvarC1ock11111111111111111111111111111111"),
+                            clock(),
+                        ),
+                        "rent" => AccountState::new(
+                            sysvar_key("SysvarRent111111111111111111111111111111111"),
+                            rent(),
+                        ),
+                        "SysvarInstruction" => AccountState::new(
+                            sysvar_key("Sysvar1nstructions1111111111111111111111111"),
+                            Vec::new(),
+                        ),
+                        name => AccountState::new(account_key(name), Vec::new()),
+                    };
+
+                    state.is_signer = account.is_signer;
+                    state.is_writable = account.is_mut;
+
+                    Some(state)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let (mut input, data_offset) = serialize_parameters(
+            &accounts,
+            calldata,
+            &self.program_id,
+            &self.data_account.key,
+        );
+
+        let mut heap = vec![0u8; HEAP_SIZE];
+
+        let mut context = SyscallContext {
+            remaining: COMPUTE_UNIT_LIMIT,
+            program_id: self.program_id,
+            return_data: Vec::new(),
+            panicked: false,
+        };
+
+        let region = MemoryRegion::new_writable(&mut input, ebpf::MM_INPUT_START);
+
+        let res = match EbpfVm::new(&self.executable, &mut context, &mut heap, vec![region]) {
+            Ok(mut vm) => vm.execute_program(true).1,
+            Err(err) => ProgramResult::Err(err),
+        };
+
+        let units = COMPUTE_UNIT_LIMIT - context.remaining;
+
+        let outcome = match res {
+            ProgramResult::Ok(0) => Ok(()),
+            ProgramResult::Ok(code) => Err(format!("returned error {code:#x}")),
+            ProgramResult::Err(_) if context.panicked => Err("panicked".to_string()),
+            ProgramResult::Err(err) => Err(err.to_string()),
+        };
+
+        // keep the storage for the next instruction
+        if let Some(offset) = data_offset {
+            if outcome.is_ok() {
+                let len = u64::from_le_bytes(input[offset - 8..offset].try_into().unwrap());
+
+                self.data_account.lamports =
+                    u64::from_le_bytes(input[offset - 16..offset - 8].try_into().unwrap());
+                self.data_account.data = input[offset..offset + len as usize].to_vec();
+            }
+        }
+
+        (units, outcome)
+    }
+}
+
+/// Serialize the accounts and instruction data the way the Solana loader does. Also returns
+/// the offset of the data account's data, if it was passed.
+fn serialize_parameters(
+    accounts: &[AccountState],
+    calldata: &[u8],
+    program_id: &Account,
+    data_account: &Account,
+) -> (Vec<u8>, Option<usize>) {
+    let mut v: Vec<u8> = Vec::new();
+    let mut data_offset = None;
+
+    v.extend_from_slice(&(accounts.len() as u64).to_le_bytes());
+
+    for acc in accounts {
+        // dup_info
+        v.push(0xff);
+        v.push(acc.is_signer.into());
+        v.push(acc.is_writable.into());
+        // executable
+        v.push(0);
+        // original data length, stored in the padding
+        v.extend_from_slice(&(acc.data.len() as u32).to_le_bytes());
+        v.extend_from_slice(&acc.key);
+        v.extend_from_slice(&acc.owner);
+        v.extend_from_slice(&acc.lamports.to_le_bytes());
+        v.extend_from_slice(&(acc.data.len() as u64).to_le_bytes());
+
+        if acc.key == *data_account {
+            data_offset = Some(v.len());
+        }
+
+        v.extend_from_slice(&acc.data);
+        v.resize(v.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+
+        let padding = v.len() % 8;
+        if padding != 0 {
+            v.resize(v.len() + 8 - padding, 0);
+        }
+
+        // rent epoch
+        v.extend_from_slice(&0u64.to_le_bytes());
+    }
+
+    v.extend_from_slice(&(calldata.len() as u64).to_le_bytes());
+    v.extend_from_slice(calldata);
+    v.extend_from_slice(program_id);
+
+    (v, data_offset)
+}
+
+fn clock() -> Vec<u8> {
+    let mut clock = vec![0; CLOCK_SIZE];
+    // unix timestamp
+    clock[32..].copy_from_slice(&1_672_531_200u64.to_le_bytes());
+    clock
+}
+
+/// Rent::default(), laid out as #[repr(C)]
+fn rent() -> Vec<u8> {
+    let mut rent = Vec::new();
+    rent.extend_from_slice(&3480u64.to_le_bytes());
+    rent.extend_from_slice(&2.0f64.to_le_bytes());
+    rent.push(50);
+    rent.resize(RENT_SIZE, 0);
+    rent
+}
+
+/// EpochSchedule::default(), laid out as #[repr(C)]
+fn epoch_schedule() -> Vec<u8> {
+    let mut epoch_schedule = Vec::new();
+    epoch_schedule.extend_from_slice(&432000u64.to_le_bytes());
+    epoch_schedule.extend_from_slice(&432000u64.to_le_bytes());
+    epoch_schedule.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+    epoch_schedule.extend_from_slice(&14u64.to_le_bytes());
+    epoch_schedule.extend_from_slice(&524256u64.to_le_bytes());
+    epoch_schedule.resize(EPOCH_SCHEDULE_SIZE, 0);
+    epoch_schedule
+}
+
+fn translate_slice<'a, T>(
+    memory_mapping: &MemoryMapping,
+    access_type: AccessType,
+    vm_addr: u64,
+    len: u64,
+) -> Result<&'a mut [T], EbpfError> {
+    if len == 0 {
+        return Ok(&mut []);
+    }
+
+    match memory_mapping.map(
+        access_type,
+        vm_addr,
+        len.saturating_mul(size_of::<T>() as u64),
+        0,
+    ) {
+        ProgramResult::Ok(host_addr) => {
+            Ok(unsafe { std::slice::from_raw_parts_mut(host_addr as *mut T, len as usize) })
+        }
+        ProgramResult::Err(err) => Err(err),
+    }
+}
+
+/// Hash cost for each of the slices passed to sha256 or keccak256
+fn hash_cost(slices: &[(u64, u64)]) -> u64 {
+    slices.iter().fold(SHA256_BASE_COST, |cost, (_, len)| {
+        cost + std::cmp::max(MEM_OP_BASE_COST, len / 2)
+    })
+}
+
+fn translate_slices<'a>(
+    memory_mapping: &MemoryMapping,
+    src: u64,
+    len: u64,
+) -> Result<Vec<&'a [u8]>, EbpfError> {
+    let slices = translate_slice::<(u64, u64)>(memory_mapping, AccessType::Load, src, len)?;
+
+    slices
+        .iter()
+        .map(|(addr, len)| {
+            translate_slice::<u8>(memory_mapping, AccessType::Load, *addr, *len)
+                .map(|slice| &*slice)
+        })
+        .collect()
+}
+
+fn program_address(program_id: &[u8], seeds: &[&[u8]]) -> Account {
+    let mut hasher = Sha256::new();
+
+    for seed in seeds {
+        hasher.update(seed);
+    }
+
+    hasher.update(program_id);
+    hasher.update(b"ProgramDerivedAddress");
+
+    hasher.finalize().into()
+}
+
+fn sol_panic_(
+    context: &mut SyscallContext,
+    _src: u64,
+    _len: u64,
+    _dest: u64,
+    _arg4: u64,
+    _arg5: u64,
+    _memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.panicked = true;
+
+    *result = ProgramResult::Err(EbpfError::ExecutionOverrun(0));
+}
+
+fn sol_log(
+    context: &mut SyscallContext,
+    _vm_addr: u64,
+    len: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    _memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(std::cmp::max(SYSCALL_BASE_COST, len));
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_log_pubkey(
+    context: &mut SyscallContext,
+    _pubkey_addr: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    _memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(SYSCALL_BASE_COST);
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_log_u64(
+    context: &mut SyscallContext,
+    _arg1: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    _memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(SYSCALL_BASE_COST);
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_log_data(
+    context: &mut SyscallContext,
+    addr: u64,
+    len: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    let fields = question_mark!(
+        translate_slice::<(u64, u64)>(memory_mapping, AccessType::Load, addr, len),
+        result
+    );
+
+    context.consume(fields.iter().fold(SYSCALL_BASE_COST, |cost, (_, len)| {
+        cost + SYSCALL_BASE_COST + len
+    }));
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_sha256(
+    context: &mut SyscallContext,
+    src: u64,
+    len: u64,
+    dest: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    let slices = question_mark!(
+        translate_slice::<(u64, u64)>(memory_mapping, AccessType::Load, src, len),
+        result
+    );
+
+    context.consume(hash_cost(slices));
+
+    let mut hasher = Sha256::new();
+
+    for slice in question_mark!(translate_slices(memory_mapping, src, len), result) {
+        hasher.update(slice);
+    }
+
+    let hash_result = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Store, dest, 32),
+        result
+    );
+
+    hash_result.copy_from_slice(&hasher.finalize());
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_keccak256(
+    context: &mut SyscallContext,
+    src: u64,
+    len: u64,
+    dest: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    let slices = question_mark!(
+        translate_slice::<(u64, u64)>(memory_mapping, AccessType::Load, src, len),
+        result
+    );
+
+    context.consume(hash_cost(slices));
+
+    let mut hasher = Keccak::v256();
+
+    for slice in question_mark!(translate_slices(memory_mapping, src, len), result) {
+        hasher.update(slice);
+    }
+
+    let hash_result = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Store, dest, 32),
+        result
+    );
+
+    hasher.finalize(hash_result);
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_create_program_address(
+    context: &mut SyscallContext,
+    seed_ptr: u64,
+    seed_len: u64,
+    program_id: u64,
+    dest: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(CREATE_PROGRAM_ADDRESS_UNITS);
+
+    let seeds = question_mark!(translate_slices(memory_mapping, seed_ptr, seed_len), result);
+
+    let program_id = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Load, program_id, 32),
+        result
+    );
+
+    let address = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Store, dest, 32),
+        result
+    );
+
+    address.copy_from_slice(&program_address(program_id, &seeds));
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_try_find_program_address(
+    context: &mut SyscallContext,
+    seed_ptr: u64,
+    seed_len: u64,
+    program_id: u64,
+    dest: u64,
+    bump: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(CREATE_PROGRAM_ADDRESS_UNITS);
+
+    let mut seeds = question_mark!(translate_slices(memory_mapping, seed_ptr, seed_len), result);
+
+    let program_id = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Load, program_id, 32),
+        result
+    );
+
+    // the real runtime checks that the address is not on the ed25519 curve; the first bump is
+    // always accepted here
+    let bump_seed = [u8::MAX];
+    seeds.push(&bump_seed);
+
+    let address = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Store, dest, 32),
+        result
+    );
+
+    address.copy_from_slice(&program_address(program_id, &seeds));
+
+    let bump_result = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Store, bump, 1),
+        result
+    );
+
+    bump_result.copy_from_slice(&bump_seed);
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_invoke_signed_c(
+    context: &mut SyscallContext,
+    instruction_addr: u64,
+    _account_infos_addr: u64,
+    _account_infos_len: u64,
+    _signers_seeds_addr: u64,
+    _signers_seeds_len: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    // SolInstruction is program_id, accounts, accounts_len, data and data_len
+    let instruction = question_mark!(
+        translate_slice::<u64>(memory_mapping, AccessType::Load, instruction_addr, 5),
+        result
+    );
+
+    context.consume(INVOKE_UNITS + instruction[4] / CPI_BYTES_PER_UNIT);
+
+    // The callee is not executed, so it does not return anything
+    context.return_data.clear();
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_set_return_data(
+    context: &mut SyscallContext,
+    addr: u64,
+    len: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(SYSCALL_BASE_COST + len / CPI_BYTES_PER_UNIT);
+
+    let buf = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Load, addr, len),
+        result
+    );
+
+    context.return_data = buf.to_vec();
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_get_return_data(
+    context: &mut SyscallContext,
+    addr: u64,
+    len: u64,
+    program_id_addr: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(SYSCALL_BASE_COST + (len + 32) / CPI_BYTES_PER_UNIT);
+
+    let length = std::cmp::min(len, context.return_data.len() as u64);
+
+    if length > 0 {
+        let buf = question_mark!(
+            translate_slice::<u8>(memory_mapping, AccessType::Store, addr, length),
+            result
+        );
+
+        buf.copy_from_slice(&context.return_data[..length as usize]);
+
+        let program_id = question_mark!(
+            translate_slice::<u8>(memory_mapping, AccessType::Store, program_id_addr, 32),
+            result
+        );
+
+        program_id.copy_from_slice(&context.program_id);
+    }
+
+    *result = ProgramResult::Ok(context.return_data.len() as u64);
+}
+
+fn get_sysvar(
+    context: &mut SyscallContext,
+    sysvar: &[u8],
+    addr: u64,
+    memory_mapping: &MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.consume(SYSVAR_BASE_COST + sysvar.len() as u64);
+
+    let buf = question_mark!(
+        translate_slice::<u8>(memory_mapping, AccessType::Store, addr, sysvar.len() as u64),
+        result
+    );
+
+    buf.copy_from_slice(sysvar);
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_get_clock_sysvar(
+    context: &mut SyscallContext,
+    addr: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    get_sysvar(context, &clock(), addr, memory_mapping, result);
+}
+
+fn sol_get_rent_sysvar(
+    context: &mut SyscallContext,
+    addr: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    get_sysvar(context, &rent(), addr, memory_mapping, result);
+}
+
+fn sol_get_epoch_schedule_sysvar(
+    context: &mut SyscallContext,
+    addr: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    get_sysvar(context, &epoch_schedule(), addr, memory_mapping, result);
+}
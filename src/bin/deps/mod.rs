@@ -0,0 +1,389 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use solang::file_resolver::FileResolver;
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+use walkdir::WalkDir;
+
+/// The directory where archives are unpacked, relative to the directory of solang.toml
+pub const VENDOR_DIR: &str = ".solang/deps";
+
+/// The lock file with the content hashes of the dependencies, next to solang.toml
+pub const LOCK_FILE: &str = "solang.lock";
+
+/// A dependency in the `[dependencies]` table of solang.toml. The name of the dependency is
+/// registered as an import map, so `import "openzeppelin/token/ERC20/ERC20.sol";` works for
+/// a dependency called `openzeppelin`. Relative paths are relative to the directory of
+/// solang.toml.
+#[derive(Deserialize, Debug, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct Dependency {
+    /// A directory with the sources
+    pub path: Option<PathBuf>,
+    /// An uncompressed tar archive with the sources, which `solang deps` unpacks
+    pub archive: Option<PathBuf>,
+    /// The directory inside the archive which has the sources
+    pub root: Option<PathBuf>,
+}
+
+/// An entry in solang.lock
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Locked {
+    source: String,
+    sha256: String,
+    /// For an archive, the hash of the unpacked directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    unpacked_sha256: Option<String>,
+}
+
+impl Dependency {
+    fn check(&self, name: &str) -> Result<(), String> {
+        match (&self.path, &self.archive, &self.root) {
+            (Some(_), None, None) | (None, Some(_), _) => Ok(()),
+            (Some(_), None, Some(_)) => Err(format!(
+                "dependency '{name}': root can only be used with an archive"
+            )),
+            _ => Err(format!(
+                "dependency '{name}': exactly one of path or archive must be given"
+            )),
+        }
+    }
+
+    /// The directory which the import map for this dependency points to
+    fn import_dir(&self, name: &str, base: &Path) -> PathBuf {
+        if let Some(path) = &self.path {
+            base.join(path)
+        } else {
+            let dir = vendor_dir(name, base);
+
+            match &self.root {
+                Some(root) => dir.join(root),
+                None => dir,
+            }
+        }
+    }
+
+    fn source(&self) -> String {
+        match (&self.path, &self.archive) {
+            (Some(path), _) => format!("path {}", path.display()),
+            (_, Some(archive)) => format!("archive {}", archive.display()),
+            _ => unreachable!(),
+        }
+    }
+
+    /// The content hash of a dependency. For a directory, this covers the paths and contents
+    /// of all the files in it; for an archive, the archive file itself.
+    fn hash(&self, name: &str, base: &Path) -> Result<String, String> {
+        if let Some(path) = &self.path {
+            hash_dir(name, &base.join(path))
+        } else {
+            let archive = base.join(self.archive.as_ref().unwrap());
+            let contents = fs::read(&archive)
+                .map_err(|err| format!("dependency '{name}': {}: {err}", archive.display()))?;
+
+            Ok(hex::encode(Sha256::digest(contents)))
+        }
+    }
+}
+
+/// The directory which the archive of a dependency is unpacked into
+fn vendor_dir(name: &str, base: &Path) -> PathBuf {
+    base.join(VENDOR_DIR).join(name)
+}
+
+/// The hash of the paths and contents of all the files in a directory
+fn hash_dir(name: &str, dir: &Path) -> Result<String, String> {
+    let mut hasher = Sha256::new();
+    let mut files = Vec::new();
+
+    for entry in WalkDir::new(dir) {
+        let entry = entry.map_err(|err| format!("dependency '{name}': {err}"))?;
+
+        if entry.file_type().is_file() {
+            files.push(entry.into_path());
+        }
+    }
+
+    // the order in which the directory is walked is not stable
+    files.sort();
+
+    for file in files {
+        let contents = fs::read(&file)
+            .map_err(|err| format!("dependency '{name}': {}: {err}", file.display()))?;
+        let relative = file.strip_prefix(dir).unwrap();
+
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update(contents.len().to_le_bytes());
+        hasher.update(&contents);
+    }
+
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Register each dependency as an import map. `base` is the directory of solang.toml.
+pub fn add_import_maps(
+    resolver: &mut FileResolver,
+    dependencies: &BTreeMap<String, Dependency>,
+    base: &Path,
+) -> Result<(), String> {
+    for (name, dependency) in dependencies {
+        dependency.check(name)?;
+
+        let dir = dependency.import_dir(name, base);
+
+        if dependency.archive.is_some() && !dir.is_dir() {
+            return Err(format!(
+                "dependency '{name}' is not unpacked; run 'solang deps' first"
+            ));
+        }
+
+        resolver
+            .add_import_map(OsString::from(name), dir.clone())
+            .map_err(|err| format!("dependency '{name}': '{}': {err}", dir.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Unpack the archives into the vendor directory, and write the content hashes of all the
+/// dependencies to solang.lock. `base` is the directory of solang.toml.
+pub fn vendor(dependencies: &BTreeMap<String, Dependency>, base: &Path) -> Result<(), String> {
+    let mut lock = BTreeMap::new();
+
+    for (name, dependency) in dependencies {
+        dependency.check(name)?;
+
+        let unpacked_sha256 = if let Some(archive) = &dependency.archive {
+            let dir = vendor_dir(name, base);
+
+            if dir.exists() {
+                fs::remove_dir_all(&dir)
+                    .map_err(|err| format!("cannot remove '{}': {err}", dir.display()))?;
+            }
+
+            unpack(&base.join(archive), &dir)
+                .map_err(|err| format!("dependency '{name}': {err}"))?;
+
+            Some(hash_dir(name, &dir)?)
+        } else {
+            None
+        };
+
+        lock.insert(
+            name.clone(),
+            Locked {
+                source: dependency.source(),
+                sha256: dependency.hash(name, base)?,
+                unpacked_sha256,
+            },
+        );
+    }
+
+    let lock_file = base.join(LOCK_FILE);
+
+    let contents = format!(
+        "# This file is generated by solang deps. It records the content hash of each dependency.\n\n{}",
+        toml::to_string(&lock).unwrap()
+    );
+
+    fs::write(&lock_file, contents)
+        .map_err(|err| format!("cannot write '{}': {err}", lock_file.display()))
+}
+
+/// Check that the dependencies still match solang.lock, and that the archives are unpacked.
+/// Returns all the problems which were found. `base` is the directory of solang.toml.
+pub fn verify(dependencies: &BTreeMap<String, Dependency>, base: &Path) -> Result<(), Vec<String>> {
+    let lock_file = base.join(LOCK_FILE);
+    let lock: BTreeMap<String, Locked> = fs::read_to_string(&lock_file)
+        .map_err(|err| format!("cannot read '{}': {err}", lock_file.display()))
+        .and_then(|contents| toml::from_str(&contents).map_err(|err| format!("{LOCK_FILE}: {err}")))
+        .map_err(|err| vec![err])?;
+
+    let mut errors = Vec::new();
+
+    for (name, dependency) in dependencies {
+        if let Err(err) = dependency.check(name) {
+            errors.push(err);
+            continue;
+        }
+
+        let Some(locked) = lock.get(name) else {
+            errors.push(format!("dependency '{name}' is not in {LOCK_FILE}"));
+            continue;
+        };
+
+        if locked.source != dependency.source() {
+            errors.push(format!(
+                "dependency '{name}' is {} but {LOCK_FILE} has {}",
+                dependency.source(),
+                locked.source
+            ));
+            continue;
+        }
+
+        match dependency.hash(name, base) {
+            Ok(hash) if hash != locked.sha256 => errors.push(format!(
+                "dependency '{name}' has changed since {LOCK_FILE} was written"
+            )),
+            Ok(_) => (),
+            Err(err) => errors.push(err),
+        }
+
+        if dependency.archive.is_some() {
+            let dir = vendor_dir(name, base);
+
+            if !dir.is_dir() {
+                errors.push(format!("dependency '{name}' is not unpacked"));
+                continue;
+            }
+
+            match hash_dir(name, &dir) {
+                Ok(hash) if Some(&hash) != locked.unpacked_sha256.as_ref() => errors.push(format!(
+                    "unpacked dependency '{name}' has changed since {LOCK_FILE} was written"
+                )),
+                Ok(_) => (),
+                Err(err) => errors.push(err),
+            }
+        }
+    }
+
+    for name in lock.keys() {
+        if !dependencies.contains_key(name) {
+            errors.push(format!(
+                "{LOCK_FILE} has dependency '{name}' which is not in solang.toml"
+            ));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Unpack an uncompressed tar archive. Only regular files, directories and GNU long names are
+/// supported; any other entry (links, devices, pax headers) is rejected rather than skipped, so
+/// that an archive is never unpacked partially. Entries which would end up outside the directory
+/// are rejected too.
+fn unpack(archive: &Path, dir: &Path) -> Result<(), String> {
+    const BLOCK: usize = 512;
+
+    let data = fs::read(archive).map_err(|err| format!("{}: {err}", archive.display()))?;
+    let corrupt = || format!("{}: not a valid tar archive", archive.display());
+
+    if data.starts_with(&[0x1f, 0x8b]) {
+        return Err(format!(
+            "{}: compressed archives are not supported; decompress it with 'gunzip' first",
+            archive.display()
+        ));
+    }
+
+    let mut offset = 0;
+    // the name from a GNU long name entry, which applies to the next entry
+    let mut long_name = None;
+
+    while offset + BLOCK <= data.len() {
+        let header = &data[offset..offset + BLOCK];
+
+        // the archive ends with zero blocks
+        if header.iter().all(|b| *b == 0) {
+            return Ok(());
+        }
+
+        let size = octal(&header[124..136]).ok_or_else(corrupt)?;
+        let contents = data
+            .get(offset + BLOCK..offset + BLOCK + size)
+            .ok_or_else(corrupt)?;
+
+        let name = match long_name.take() {
+            Some(name) => name,
+            None if &header[257..262] == b"ustar" => {
+                let prefix = string(&header[345..500]);
+                let name = string(&header[0..100]);
+
+                if prefix.is_empty() {
+                    name
+                } else {
+                    format!("{prefix}/{name}")
+                }
+            }
+            None => string(&header[0..100]),
+        };
+
+        let relative = Path::new(&name);
+
+        if relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+        {
+            return Err(format!(
+                "{}: entry '{name}' is outside the archive",
+                archive.display()
+            ));
+        }
+
+        let path = dir.join(relative);
+
+        match header[156] {
+            b'0' | 0 => {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)
+                        .map_err(|err| format!("{}: {err}", parent.display()))?;
+                }
+
+                fs::write(&path, contents).map_err(|err| format!("{}: {err}", path.display()))?;
+            }
+            b'5' => {
+                fs::create_dir_all(&path).map_err(|err| format!("{}: {err}", path.display()))?;
+            }
+            b'L' => {
+                long_name = Some(string(contents));
+            }
+            b'1' | b'2' => {
+                return Err(format!(
+                    "{}: entry '{name}' is a link, which is not supported",
+                    archive.display()
+                ));
+            }
+            b'x' | b'g' => {
+                return Err(format!(
+                    "{}: pax headers are not supported; create the archive with 'tar --format=gnu'",
+                    archive.display()
+                ));
+            }
+            kind => {
+                return Err(format!(
+                    "{}: entry '{name}' has unsupported type '{}'",
+                    archive.display(),
+                    kind.escape_ascii()
+                ));
+            }
+        }
+
+        // the contents are padded to a whole number of blocks
+        offset += BLOCK + (size + BLOCK - 1) / BLOCK * BLOCK;
+    }
+
+    Err(corrupt())
+}
+
+/// A nul terminated string in a tar header
+fn string(field: &[u8]) -> String {
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+
+    String::from_utf8_lossy(&field[..end]).to_string()
+}
+
+/// An octal number in a tar header, which may be padded with spaces or nuls
+fn octal(field: &[u8]) -> Option<usize> {
+    let digits = string(field);
+
+    usize::from_str_radix(digits.trim(), 8).ok()
+}
@@ -105,6 +105,7 @@ impl SolangServer {
 
             // codegen all the contracts; some additional errors/warnings will be detected here
             codegen(&mut ns, &Default::default());
+            ns.suppress_warnings();
 
             diags.extend(ns.diagnostics.iter().filter_map(|diag| {
                 if diag.loc.file_no() != ns.top_file_no() {
@@ -145,6 +146,7 @@ impl SolangServer {
                     range,
                     message: diag.message.to_string(),
                     severity,
+                    code: Some(NumberOrString::String(diag.code.to_owned())),
                     related_information,
                     ..Default::default()
                 })
@@ -4,33 +4,63 @@ use clap::{Command, CommandFactory, FromArgMatches};
 
 use clap_complete::generate;
 use cli::PackageTrait;
+#[cfg(feature = "llvm")]
 use itertools::Itertools;
+#[cfg(feature = "llvm")]
+use rayon::prelude::*;
+#[cfg(feature = "llvm")]
 use solang::{
     abi,
-    codegen::{codegen, Options},
     emit::Generate,
-    file_resolver::FileResolver,
-    sema::{ast::Namespace, file::PathDisplay},
+    sema::{
+        ast::{Contract, Namespace},
+        file::PathDisplay,
+        lints::LintLevel,
+    },
     standard_json::{EwasmContract, JsonContract, JsonResult},
 };
+use solang::{
+    codegen::{codegen, Options},
+    file_resolver::FileResolver,
+    sarif::{SarifLog, SarifResult},
+    sema::codes,
+    standard_json::OutputJson,
+};
+#[cfg(feature = "llvm")]
 use std::{
     collections::{HashMap, HashSet},
-    ffi::{OsStr, OsString},
+    ffi::OsStr,
+};
+use std::{
+    ffi::OsString,
     fs::{self, create_dir, create_dir_all, File},
     io::prelude::*,
     path::{Path, PathBuf},
     process::exit,
 };
 
+#[cfg(feature = "llvm")]
+use crate::cache::Cache;
 use crate::cli::{
-    imports_arg, options_arg, target_arg, Cli, Commands, Compile, CompilerOutput, Doc, New,
-    ShellComplete,
+    config_dir, dependencies_arg, imports_arg, lints_arg, project_from_toml, target_arg, Check,
+    Cli, Commands, Compile, Deps, Doc, Explain, New, ShellComplete,
 };
+#[cfg(feature = "llvm")]
+use crate::cli::{options_arg, CompilerOutput};
 
+#[cfg(feature = "llvm")]
+mod cache;
 mod cli;
+#[cfg(feature = "llvm")]
+mod compute_units;
+mod deps;
 mod doc;
 mod idl;
 mod languageserver;
+#[cfg(feature = "llvm")]
+mod standard_json;
+#[cfg(feature = "llvm")]
+mod watch;
 
 fn main() {
     let matches = Cli::command().get_matches();
@@ -39,6 +69,8 @@ fn main() {
 
     match cli.command {
         Commands::Doc(doc_args) => doc(doc_args),
+        Commands::Check(check_args) => check(check_args),
+        Commands::Explain(explain_args) => explain(explain_args),
         Commands::Compile(compile_args) => {
             // Read config from configuration file. If extra args exist, only overwrite the fields that the user explicitly provides.
             let config = if let Some(conf_file) = &compile_args.configuration_file {
@@ -47,6 +79,7 @@ fn main() {
                     let debug = matches.subcommand_matches("compile").unwrap();
                     let mut compile = read_toml_config(conf_file);
                     compile.overwrite_with_matches(debug);
+                    compile.configuration_file = Some(conf_file.clone());
 
                     compile
                 } else {
@@ -61,6 +94,7 @@ fn main() {
         Commands::LanguageServer(server_args) => languageserver::start_server(&server_args),
         Commands::Idl(idl_args) => idl::idl(&idl_args),
         Commands::New(new_arg) => new_command(new_arg),
+        Commands::Deps(deps_args) => deps(deps_args),
     }
 }
 
@@ -153,9 +187,119 @@ fn doc(doc_args: Doc) {
     }
 }
 
+fn check(check_args: Check) {
+    let target = target_arg(&check_args.target);
+    let mut resolver = imports_arg(&check_args.package);
+    let project = project_from_toml(&check_args.configuration_file);
+
+    dependencies_arg(
+        &mut resolver,
+        &project.dependencies,
+        &check_args.configuration_file,
+    );
+
+    // the analysis passes run during codegen, so codegen is needed but llvm is not
+    let opt = Options::default();
+    let lints = lints_arg(&project.lints);
+
+    // sarif file uris are relative to the project root
+    let root = std::env::current_dir()
+        .and_then(|dir| dir.canonicalize())
+        .unwrap_or_default();
+
+    let mut errors = false;
+    let mut json: Vec<OutputJson> = Vec::new();
+    let mut sarif: Vec<SarifResult> = Vec::new();
+
+    for filename in check_args.package.get_input() {
+        let mut ns = solang::parse_and_resolve(filename.as_os_str(), &mut resolver, target);
+
+        codegen(&mut ns, &opt);
+        ns.apply_lints(&lints);
+
+        match check_args.format.as_str() {
+            "json" => json.append(&mut ns.diagnostics_as_json(&resolver)),
+            "sarif" => sarif.append(&mut ns.diagnostics_as_sarif(&root)),
+            _ => ns.print_diagnostics(&resolver, check_args.verbose),
+        }
+
+        if ns.diagnostics.any_errors() {
+            errors = true;
+        }
+    }
+
+    match check_args.format.as_str() {
+        "json" => println!("{}", serde_json::to_string(&json).unwrap()),
+        "sarif" => println!(
+            "{}",
+            serde_json::to_string_pretty(&SarifLog::new(&root, sarif)).unwrap()
+        ),
+        _ => (),
+    }
+
+    if errors {
+        exit(1);
+    }
+}
+
+fn deps(deps_args: Deps) {
+    let project = project_from_toml(&deps_args.configuration_file);
+    let base = config_dir(&deps_args.configuration_file);
+
+    if deps_args.verify {
+        if let Err(errors) = deps::verify(&project.dependencies, &base) {
+            for error in errors {
+                eprintln!("error: {error}");
+            }
+            exit(1);
+        }
+    } else if let Err(error) = deps::vendor(&project.dependencies, &base) {
+        eprintln!("error: {error}");
+        exit(1);
+    }
+}
+
+fn explain(explain_args: Explain) {
+    if let Some(code) = &explain_args.code {
+        if let Some(info) = codes::lookup(code) {
+            println!(
+                "{} {}: {}\n\n{}",
+                info.code, info.name, info.summary, info.explanation
+            );
+        } else {
+            eprintln!(
+                "error: unknown diagnostic code '{code}'; use 'solang explain' to list all codes"
+            );
+            exit(1);
+        }
+    } else {
+        for info in codes::CODES {
+            println!("{} {:<28} {}", info.code, info.name, info.summary);
+        }
+    }
+}
+
+#[cfg(not(feature = "llvm"))]
+fn compile(_compile_args: &Compile) {
+    eprintln!("error: solang was built without llvm, so contracts cannot be compiled; use 'solang check' to check them for errors");
+    exit(1);
+}
+
+#[cfg(feature = "llvm")]
 fn compile(compile_args: &Compile) {
+    // without input files, the sources are given as standard json input on stdin
+    if compile_args.compiler_output.std_json_output && compile_args.package.input.is_none() {
+        standard_json::compile(compile_args);
+        return;
+    }
+
     let target = target_arg(&compile_args.target_arg);
 
+    if compile_args.compiler_output.report_compute_units && target != solang::Target::Solana {
+        eprintln!("error: compute units can only be reported for the Solana target");
+        exit(1);
+    }
+
     let mut json = JsonResult {
         errors: Vec::new(),
         target: target.to_string(),
@@ -167,12 +311,80 @@ fn compile(compile_args: &Compile) {
         eprintln!("info: Solang version {}", env!("SOLANG_VERSION"));
     }
 
-    let mut resolver = imports_arg(&compile_args.package);
-
     let opt = options_arg(&compile_args.debug_features, &compile_args.optimizations);
+    let lints = lints_arg(&compile_args.lints);
+
+    let inputs = compile_args.package.get_input();
+
+    if compile_args.compiler_output.watch {
+        watch::watch(
+            inputs,
+            |inputs| process_files(inputs, target, compile_args, &opt, &lints),
+            |namespaces| write_results(namespaces, compile_args, &opt, &mut json),
+        );
+    }
+
+    let namespaces = process_files(inputs, target, compile_args, &opt, &lints);
 
-    let mut namespaces = Vec::new();
+    let errors = write_results(&namespaces, compile_args, &opt, &mut json);
 
+    if compile_args.compiler_output.std_json_output {
+        println!("{}", serde_json::to_string(&json).unwrap());
+        exit(0);
+    }
+
+    if errors {
+        exit(1);
+    }
+}
+
+/// Resolve and codegen the input files. Each file is resolved with its own file resolver, so
+/// that the files can be processed in parallel. The results are in the order of the input files.
+#[cfg(feature = "llvm")]
+fn process_files(
+    inputs: &[PathBuf],
+    target: solang::Target,
+    compile_args: &Compile,
+    opt: &Options,
+    lints: &HashMap<&'static str, LintLevel>,
+) -> Vec<(FileResolver, Namespace)> {
+    inputs
+        .par_iter()
+        .map(|filename| {
+            let mut resolver = imports_arg(&compile_args.package);
+
+            dependencies_arg(
+                &mut resolver,
+                &compile_args.dependencies,
+                compile_args
+                    .configuration_file
+                    .as_deref()
+                    .unwrap_or_default(),
+            );
+
+            let ns = process_file(
+                filename.as_os_str(),
+                &mut resolver,
+                target,
+                &compile_args.compiler_output,
+                opt,
+                lints,
+            );
+
+            (resolver, ns)
+        })
+        .collect()
+}
+
+/// Print the diagnostics, and build and write out the contracts if there are no errors.
+/// Returns true if there were errors.
+#[cfg(feature = "llvm")]
+fn write_results(
+    namespaces: &[(FileResolver, Namespace)],
+    compile_args: &Compile,
+    opt: &Options,
+    json: &mut JsonResult,
+) -> bool {
     let mut errors = false;
 
     // Build a map of requested contract names, and a flag specifying whether it was found or not
@@ -182,29 +394,16 @@ fn compile(compile_args: &Compile) {
         HashSet::new()
     };
 
-    for filename in compile_args.package.get_input() {
-        // TODO: this could be parallelized using e.g. rayon
-        let ns = process_file(
-            filename.as_os_str(),
-            &mut resolver,
-            target,
-            &compile_args.compiler_output,
-            &opt,
-        );
-
-        namespaces.push(ns);
-    }
-
     let mut json_contracts = HashMap::new();
 
     let std_json = compile_args.compiler_output.std_json_output;
 
-    for ns in &namespaces {
+    for (resolver, ns) in namespaces {
         if std_json {
-            let mut out = ns.diagnostics_as_json(&resolver);
+            let mut out = ns.diagnostics_as_json(resolver);
             json.errors.append(&mut out);
         } else {
-            ns.print_diagnostics(&resolver, compile_args.compiler_output.verbose);
+            ns.print_diagnostics(resolver, compile_args.compiler_output.verbose);
         }
 
         if ns.diagnostics.any_errors() {
@@ -217,7 +416,7 @@ fn compile(compile_args: &Compile) {
     }
 
     // Ensure we have at least one contract
-    if !errors && namespaces.iter().all(|ns| ns.contracts.is_empty()) {
+    if !errors && namespaces.iter().all(|(_, ns)| ns.contracts.is_empty()) {
         eprintln!("error: no contacts found");
         errors = true;
     }
@@ -228,7 +427,7 @@ fn compile(compile_args: &Compile) {
         .filter(|name| {
             !namespaces
                 .iter()
-                .flat_map(|ns| ns.contracts.iter())
+                .flat_map(|(_, ns)| ns.contracts.iter())
                 .any(|contract| **name == contract.name)
         })
         .collect();
@@ -239,30 +438,76 @@ fn compile(compile_args: &Compile) {
     }
 
     if !errors {
+        if compile_args.compiler_output.emit.is_none() {
+            // Building the code through llvm and linking is the slowest part, so build all
+            // contracts in parallel. The code is cached in the contract, so contract_results()
+            // below writes the results out in a deterministic order. In watch mode, the
+            // contracts of files which did not change are built already.
+            let contracts: Vec<(&FileResolver, &Namespace, &Contract)> = namespaces
+                .iter()
+                .flat_map(|(resolver, ns)| {
+                    ns.contracts
+                        .iter()
+                        .filter(|contract| {
+                            contract.instantiable
+                                && ns.top_file_no() == contract.loc.file_no()
+                                && contract.code.get().is_none()
+                        })
+                        .map(move |contract| (resolver, ns, contract))
+                })
+                .collect();
+
+            let cache = if compile_args.compiler_output.cache {
+                Some(Cache::new(
+                    compile_args
+                        .compiler_output
+                        .cache_dir
+                        .as_deref()
+                        .unwrap_or(".solang/cache"),
+                ))
+            } else {
+                None
+            };
+
+            contracts.par_iter().for_each(|(resolver, ns, contract)| {
+                if let Some(cache) = &cache {
+                    let key = Cache::key(contract, ns, resolver, opt);
+
+                    if let Some(code) = cache.get(&key) {
+                        // a contract which creates this one may have built it already
+                        let _ = contract.code.set(code);
+                    } else {
+                        cache.put(&key, &contract.emit(ns, opt));
+                    }
+                } else {
+                    contract.emit(ns, opt);
+                }
+            });
+        }
+
         let mut seen_contracts = HashMap::new();
 
-        for ns in namespaces.iter_mut() {
+        'results: for (_, ns) in namespaces {
             for contract_no in 0..ns.contracts.len() {
-                contract_results(
+                if let Err(err) = contract_results(
                     contract_no,
                     &compile_args.compiler_output,
                     ns,
                     &mut json_contracts,
                     &mut seen_contracts,
-                    &opt,
-                );
+                    opt,
+                ) {
+                    eprintln!("error: {err}");
+                    errors = true;
+                    break 'results;
+                }
             }
         }
     }
 
-    if std_json {
-        println!("{}", serde_json::to_string(&json).unwrap());
-        exit(0);
-    }
+    json.contracts = json_contracts;
 
-    if errors {
-        exit(1);
-    }
+    errors
 }
 
 fn shell_complete(mut app: Command, args: ShellComplete) {
@@ -270,6 +515,7 @@ fn shell_complete(mut app: Command, args: ShellComplete) {
     generate(args.shell_complete, &mut app, name, &mut std::io::stdout());
 }
 
+#[cfg(feature = "llvm")]
 fn output_file(compiler_output: &CompilerOutput, stem: &str, ext: &str, meta: bool) -> PathBuf {
     let dir = if meta {
         compiler_output
@@ -282,12 +528,14 @@ fn output_file(compiler_output: &CompilerOutput, stem: &str, ext: &str, meta: bo
     Path::new(&dir.unwrap_or(&String::from("."))).join(format!("{stem}.{ext}"))
 }
 
+#[cfg(feature = "llvm")]
 fn process_file(
     filename: &OsStr,
     resolver: &mut FileResolver,
     target: solang::Target,
     compiler_output: &CompilerOutput,
     opt: &Options,
+    lints: &HashMap<&'static str, LintLevel>,
 ) -> Namespace {
     let verbose = compiler_output.verbose;
 
@@ -297,6 +545,9 @@ fn process_file(
     // codegen all the contracts; some additional errors/warnings will be detected here
     codegen(&mut ns, opt);
 
+    // warnings may be raised to errors, so this must be done before checking for errors
+    ns.apply_lints(lints);
+
     if let Some("ast-dot") = compiler_output.emit.as_deref() {
         let filepath = PathBuf::from(filename);
         let stem = filepath.file_stem().unwrap().to_string_lossy();
@@ -319,21 +570,24 @@ fn process_file(
     ns
 }
 
+/// Write the results for a contract. Returns an error if another contract with the same name
+/// was written already, since their output files would overwrite each other.
+#[cfg(feature = "llvm")]
 fn contract_results(
     contract_no: usize,
     compiler_output: &CompilerOutput,
-    ns: &mut Namespace,
+    ns: &Namespace,
     json_contracts: &mut HashMap<String, JsonContract>,
     seen_contracts: &mut HashMap<String, String>,
     opt: &Options,
-) {
+) -> Result<(), String> {
     let verbose = compiler_output.verbose;
     let std_json = compiler_output.std_json_output;
 
     let resolved_contract = &ns.contracts[contract_no];
 
     if !resolved_contract.instantiable {
-        return;
+        return Ok(());
     }
 
     if ns.top_file_no() != resolved_contract.loc.file_no() {
@@ -341,24 +595,23 @@ fn contract_results(
         // a.sol which imports b.sol, and b.sol defines contract B, then:
         // solang compile a.sol
         // should not write the results for contract B
-        return;
+        return Ok(());
     }
 
     let loc = ns.loc_to_string(PathDisplay::FullPath, &resolved_contract.loc);
 
     if let Some(other_loc) = seen_contracts.get(&resolved_contract.name) {
-        eprintln!(
-            "error: contract {} defined at {other_loc} and {}",
+        return Err(format!(
+            "contract {} defined at {other_loc} and {}",
             resolved_contract.name, loc
-        );
-        exit(1);
+        ));
     }
 
     seen_contracts.insert(resolved_contract.name.to_string(), loc);
 
     if let Some("cfg") = compiler_output.emit.as_deref() {
         println!("{}", resolved_contract.print_cfg(ns));
-        return;
+        return Ok(());
     }
 
     if verbose {
@@ -375,15 +628,22 @@ fn contract_results(
         );
     }
 
-    let context = inkwell::context::Context::create();
+    let code = if compiler_output.emit.is_none() {
+        // this was built by compile() already
+        resolved_contract.emit(ns, opt)
+    } else {
+        let context = inkwell::context::Context::create();
 
-    let binary = resolved_contract.binary(ns, &context, opt);
+        let binary = resolved_contract.binary(ns, &context, opt);
 
-    if save_intermediates(&binary, compiler_output) {
-        return;
-    }
+        if save_intermediates(&binary, compiler_output) {
+            return Ok(());
+        }
 
-    let code = binary.code(Generate::Linked).expect("llvm build");
+        binary.code(Generate::Linked).expect("llvm build")
+    };
+
+    let name = resolved_contract.name.clone();
 
     #[cfg(feature = "wasm_opt")]
     if let Some(level) = opt.wasm_opt.filter(|_| ns.target.is_substrate() && verbose) {
@@ -395,28 +655,24 @@ fn contract_results(
 
     if std_json {
         json_contracts.insert(
-            binary.name,
+            name,
             JsonContract {
-                abi: abi::ethereum::gen_abi(contract_no, ns),
+                abi: Some(abi::ethereum::gen_abi(contract_no, ns)),
                 ewasm: Some(EwasmContract {
                     wasm: hex::encode_upper(code),
                 }),
+                evm: None,
                 minimum_space: None,
             },
         );
     } else {
-        let bin_filename = output_file(
-            compiler_output,
-            &binary.name,
-            ns.target.file_extension(),
-            false,
-        );
+        let bin_filename = output_file(compiler_output, &name, ns.target.file_extension(), false);
 
         if verbose {
             eprintln!(
                 "info: Saving binary {} for contract {}",
                 bin_filename.display(),
-                binary.name
+                name
             );
         }
 
@@ -425,21 +681,35 @@ fn contract_results(
         file.write_all(&code).unwrap();
 
         let (metadata, meta_ext) = abi::generate_abi(contract_no, ns, &code, verbose);
-        let meta_filename = output_file(compiler_output, &binary.name, meta_ext, true);
+        let meta_filename = output_file(compiler_output, &name, meta_ext, true);
 
         if verbose {
             eprintln!(
                 "info: Saving metadata {} for contract {}",
                 meta_filename.display(),
-                binary.name
+                name
             );
         }
 
         let mut file = create_file(&meta_filename);
         file.write_all(metadata.as_bytes()).unwrap();
+
+        if compiler_output.report_compute_units {
+            let args = compiler_output
+                .compute_units_args
+                .iter()
+                .flatten()
+                .cloned()
+                .collect();
+
+            compute_units::report(contract_no, ns, &code, &args);
+        }
     }
+
+    Ok(())
 }
 
+#[cfg(feature = "llvm")]
 fn save_intermediates(
     binary: &solang::emit::binary::Binary,
     compiler_output: &CompilerOutput,
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli::{options_arg, target_arg, Compile, CompileTargetArg};
+use solang::{
+    abi,
+    codegen::{codegen, OptimizationLevel},
+    emit::Generate,
+    file_resolver::{FileResolver, Remapping},
+    sema::lints::resolve_lints,
+    standard_json::{
+        BytecodeJson, EvmContract, EwasmContract, JsonContract, JsonInput, JsonInputResult,
+        OutputJson,
+    },
+};
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsStr,
+    fs,
+    io::{stdin, Read},
+};
+
+/// Compile from solc style standard json input on stdin. Like solc, problems with the input
+/// itself are reported as errors of type `JSONError` in the output rather than on stderr, so
+/// that build tools only ever have to parse the json.
+pub fn compile(compile_args: &Compile) {
+    let mut json = JsonInputResult {
+        errors: Vec::new(),
+        target: String::new(),
+        contracts: HashMap::new(),
+    };
+
+    if let Err(message) = compile_input(compile_args, &mut json) {
+        json.errors.push(OutputJson {
+            sourceLocation: None,
+            ty: "JSONError".to_owned(),
+            component: "general".to_owned(),
+            severity: "error".to_owned(),
+            errorCode: None,
+            formattedMessage: format!("JSONError: {message}"),
+            message,
+        });
+    }
+
+    println!("{}", serde_json::to_string(&json).unwrap());
+}
+
+fn compile_input(compile_args: &Compile, json: &mut JsonInputResult) -> Result<(), String> {
+    let mut input = String::new();
+
+    if let Err(err) = stdin().read_to_string(&mut input) {
+        return Err(format!("cannot read standard json input: {err}"));
+    }
+
+    let input: JsonInput = match serde_json::from_str(&input) {
+        Ok(input) => input,
+        Err(err) => return Err(format!("invalid standard json input: {err}")),
+    };
+
+    if input.language != "Solidity" {
+        return Err(format!(
+            "only Solidity is supported as a language, not '{}'",
+            input.language
+        ));
+    }
+
+    let settings = &input.settings;
+
+    // the target in the settings overrides the command line, like evmVersion does in solc
+    let name = match settings
+        .target
+        .as_ref()
+        .or(compile_args.target_arg.name.as_ref())
+    {
+        Some(name) if ["solana", "substrate", "evm"].contains(&name.as_str()) => name.clone(),
+        Some(name) => return Err(format!("unknown target '{name}'")),
+        None => return Err("no target specified".to_owned()),
+    };
+
+    let target = target_arg(&CompileTargetArg {
+        name: Some(name),
+        address_length: settings
+            .address_length
+            .or(compile_args.target_arg.address_length),
+        value_length: settings
+            .value_length
+            .or(compile_args.target_arg.value_length),
+        substrate_api_version: compile_args.target_arg.substrate_api_version,
+    });
+
+    json.target = target.to_string();
+
+    let mut resolver = FileResolver::new();
+
+    for remapping in &settings.remappings {
+        resolver.add_remapping(remapping.parse::<Remapping>()?);
+    }
+
+    for (name, source) in &input.sources {
+        let contents = if let Some(content) = &source.content {
+            content.clone()
+        } else if let Some(contents) = source
+            .urls
+            .iter()
+            .find_map(|url| fs::read_to_string(url).ok())
+        {
+            contents
+        } else {
+            return Err(format!("cannot read source '{name}'"));
+        };
+
+        resolver.set_file_contents(name, contents);
+    }
+
+    let mut opt = options_arg(&compile_args.debug_features, &compile_args.optimizations);
+
+    if let Some(false) = settings.optimizer.enabled {
+        opt.opt_level = OptimizationLevel::None;
+    }
+
+    let lints = resolve_lints(&compile_args.lints)?;
+
+    let mut namespaces = Vec::new();
+    // A source unit which is imported by other source units is resolved again for each of
+    // them, so its diagnostics are only reported the first time
+    let mut reported = HashSet::new();
+
+    for name in input.sources.keys() {
+        let mut ns = solang::parse_and_resolve(OsStr::new(name), &mut resolver, target);
+
+        codegen(&mut ns, &opt);
+        ns.apply_lints(&lints);
+
+        json.errors.extend(
+            ns.diagnostics_as_json(&resolver)
+                .into_iter()
+                .filter(|error| reported.insert(error.formattedMessage.clone())),
+        );
+
+        namespaces.push((name, ns));
+    }
+
+    if namespaces.iter().any(|(_, ns)| ns.diagnostics.any_errors()) {
+        return Ok(());
+    }
+
+    for (file, ns) in &namespaces {
+        for (contract_no, contract) in ns.contracts.iter().enumerate() {
+            // contracts from imported files are reported under their own source unit
+            if !contract.instantiable || ns.top_file_no() != contract.loc.file_no() {
+                continue;
+            }
+
+            let selected = |output| settings.output_selected(file, &contract.name, output);
+
+            let bytecode = selected("evm.bytecode.object");
+            let wasm = selected("ewasm.wasm");
+
+            let code = if bytecode || wasm {
+                let context = inkwell::context::Context::create();
+
+                let binary = contract.binary(ns, &context, &opt);
+
+                binary.code(Generate::Linked).expect("llvm build")
+            } else {
+                Vec::new()
+            };
+
+            json.contracts.entry(file.to_string()).or_default().insert(
+                contract.name.clone(),
+                JsonContract {
+                    abi: if selected("abi") {
+                        Some(abi::ethereum::gen_abi(contract_no, ns))
+                    } else {
+                        None
+                    },
+                    ewasm: if wasm {
+                        Some(EwasmContract {
+                            wasm: hex::encode_upper(&code),
+                        })
+                    } else {
+                        None
+                    },
+                    evm: if bytecode {
+                        Some(EvmContract {
+                            bytecode: BytecodeJson {
+                                object: hex::encode(&code),
+                            },
+                        })
+                    } else {
+                        None
+                    },
+                    minimum_space: None,
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
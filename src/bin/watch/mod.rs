@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use solang::{
+    file_resolver::FileResolver,
+    sema::ast::{Level, Namespace},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the source files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Clear the terminal and move the cursor to the top left
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Process the input files, write the results, and then keep watching the source files which
+/// were used. When any of them change, only the input files which import them (directly or
+/// indirectly) are processed again, and the results are written again. The source files are
+/// polled for changes to their modification time, so this works on any file system. `write`
+/// returns true if there were errors which are not in the diagnostics.
+pub fn watch(
+    inputs: &[PathBuf],
+    mut process: impl FnMut(&[PathBuf]) -> Vec<(FileResolver, Namespace)>,
+    mut write: impl FnMut(&[(FileResolver, Namespace)]) -> bool,
+) -> ! {
+    let mut namespaces = process(inputs);
+
+    loop {
+        eprint!("{CLEAR_SCREEN}");
+
+        let failed = write(&namespaces);
+
+        let sources: Vec<HashSet<PathBuf>> = inputs
+            .iter()
+            .zip(&namespaces)
+            .map(|(input, (resolver, ns))| sources(input, resolver, ns))
+            .collect();
+
+        let mut times: HashMap<&PathBuf, Option<SystemTime>> = sources
+            .iter()
+            .flatten()
+            .map(|path| (path, modified(path)))
+            .collect();
+
+        eprintln!("{}", summary(&namespaces, failed, times.len()));
+
+        let changed = loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let changed: HashSet<&PathBuf> = times
+                .iter_mut()
+                .filter_map(|(path, time)| {
+                    let now = modified(path);
+
+                    if now != *time {
+                        *time = now;
+                        Some(*path)
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+
+        let affected: Vec<usize> = sources
+            .iter()
+            .enumerate()
+            .filter(|(_, sources)| sources.iter().any(|path| changed.contains(path)))
+            .map(|(input_no, _)| input_no)
+            .collect();
+
+        let rebuild: Vec<PathBuf> = affected
+            .iter()
+            .map(|input_no| inputs[*input_no].clone())
+            .collect();
+
+        for (input_no, result) in affected.into_iter().zip(process(&rebuild)) {
+            namespaces[input_no] = result;
+        }
+    }
+}
+
+/// The source files which were used to process an input file, including the file itself. This
+/// is the import graph of the input, as resolved by the file resolver. For imports which could
+/// not be found, the closest existing directory of each path which was tried is included, since
+/// its modification time changes when the missing file (or a directory leading to it) is created.
+fn sources(input: &Path, resolver: &FileResolver, ns: &Namespace) -> HashSet<PathBuf> {
+    // the input itself may not exist yet or may not be resolved
+    let mut sources = HashSet::from([input.canonicalize().unwrap_or_else(|_| input.to_path_buf())]);
+
+    sources.extend(
+        ns.files
+            .iter()
+            .filter(|file| file.cache_no.is_some())
+            .map(|file| file.path.clone()),
+    );
+
+    sources.extend(resolver.not_found().iter().filter_map(|path| {
+        path.ancestors()
+            .skip(1)
+            .find(|dir| dir.is_dir())
+            .map(Path::to_path_buf)
+    }));
+
+    sources
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// The last line of each build, so that it is easy to see when a build finished
+fn summary(namespaces: &[(FileResolver, Namespace)], failed: bool, watched: usize) -> String {
+    let (errors, warnings) = namespaces
+        .iter()
+        .flat_map(|(_, ns)| ns.diagnostics.iter())
+        .fold((0, 0), |(errors, warnings), diag| match diag.level {
+            Level::Error => (errors + 1, warnings),
+            Level::Warning => (errors, warnings + 1),
+            _ => (errors, warnings),
+        });
+
+    // errors which are not diagnostics, like duplicate contract names, are printed by write
+    let errors = if failed && errors == 0 { 1 } else { errors };
+
+    format!(
+        "watch: {errors} {}, {warnings} {}; watching {watched} files for changes",
+        if errors == 1 { "error" } else { "errors" },
+        if warnings == 1 { "warning" } else { "warnings" },
+    )
+}
@@ -73,12 +73,34 @@ pub enum Instr {
     },
     /// Clear storage at slot for ty (might span multiple slots)
     ClearStorage { ty: Type, storage: Expression },
+    /// Load storage at slot and clear it in one go. Only used for types stored in a single slot
+    TakeStorage {
+        res: usize,
+        ty: Type,
+        storage: Expression,
+    },
+    /// Check whether storage at slot holds a value, without loading it
+    ContainsStorage { res: usize, storage: Expression },
     /// Set storage value at slot
     SetStorage {
         ty: Type,
         value: Expression,
         storage: Expression,
     },
+    /// Load transient storage. Transient storage is discarded at the end of the transaction
+    LoadTransientStorage {
+        res: usize,
+        ty: Type,
+        storage: Expression,
+    },
+    /// Clear transient storage at slot for ty
+    ClearTransientStorage { ty: Type, storage: Expression },
+    /// Set transient storage value at slot
+    SetTransientStorage {
+        ty: Type,
+        value: Expression,
+        storage: Expression,
+    },
     /// In storage slot, set the value at the offset
     SetStorageBytes {
         value: Expression,
@@ -113,6 +135,9 @@ pub enum Instr {
         array: usize,
         loc: Loc,
     },
+    /// Set the length of a memory array. Codegen must ensure that the new length
+    /// does not exceed the current length of the array.
+    SetMemoryLength { array: usize, length: Expression },
     /// Create contract and call constructor. If creating the contract fails,
     /// either store the result in success or abort success.
     Constructor {
@@ -202,6 +227,9 @@ pub enum ReturnCode {
     InvalidDataError,
     AccountDataTooSmall,
     InvalidProgramId,
+    InvalidAccountData,
+    MissingRequiredSignature,
+    NotEnoughAccountKeys,
 }
 
 impl Instr {
@@ -214,6 +242,10 @@ impl Instr {
             Instr::BranchCond { cond: expr, .. }
             | Instr::LoadStorage { storage: expr, .. }
             | Instr::ClearStorage { storage: expr, .. }
+            | Instr::TakeStorage { storage: expr, .. }
+            | Instr::ContainsStorage { storage: expr, .. }
+            | Instr::LoadTransientStorage { storage: expr, .. }
+            | Instr::ClearTransientStorage { storage: expr, .. }
             | Instr::Print { expr }
             | Instr::AssertFailure {
                 encoded_args: Some(expr),
@@ -228,11 +260,20 @@ impl Instr {
                 expr.recurse(cx, f);
             }
 
+            Instr::SetMemoryLength { length: expr, .. } => {
+                expr.recurse(cx, f);
+            }
+
             Instr::SetStorage {
                 value: item_1,
                 storage: item_2,
                 ..
             }
+            | Instr::SetTransientStorage {
+                value: item_1,
+                storage: item_2,
+                ..
+            }
             | Instr::Store {
                 dest: item_1,
                 data: item_2,
@@ -1030,12 +1071,40 @@ impl ControlFlowGraph {
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
             ),
+            Instr::TakeStorage { ty, res, storage } => format!(
+                "%{} = take storage slot({}) ty:{}",
+                self.vars[res].id.name,
+                self.expr_to_string(contract, ns, storage),
+                ty.to_string(ns),
+            ),
+            Instr::ContainsStorage { res, storage } => format!(
+                "%{} = contains storage slot({})",
+                self.vars[res].id.name,
+                self.expr_to_string(contract, ns, storage),
+            ),
             Instr::SetStorage { ty, value, storage } => format!(
                 "store storage slot({}) ty:{} = {}",
                 self.expr_to_string(contract, ns, storage),
                 ty.to_string(ns),
                 self.expr_to_string(contract, ns, value),
             ),
+            Instr::LoadTransientStorage { ty, res, storage } => format!(
+                "%{} = load transient storage slot({}) ty:{}",
+                self.vars[res].id.name,
+                self.expr_to_string(contract, ns, storage),
+                ty.to_string(ns),
+            ),
+            Instr::ClearTransientStorage { ty, storage } => format!(
+                "clear transient storage slot({}) ty:{}",
+                self.expr_to_string(contract, ns, storage),
+                ty.to_string(ns),
+            ),
+            Instr::SetTransientStorage { ty, value, storage } => format!(
+                "store transient storage slot({}) ty:{} = {}",
+                self.expr_to_string(contract, ns, storage),
+                ty.to_string(ns),
+                self.expr_to_string(contract, ns, value),
+            ),
             Instr::SetStorageBytes {
                 value,
                 storage,
@@ -1105,6 +1174,11 @@ impl ControlFlowGraph {
                 self.vars[array].id.name,
                 ty.to_string(ns),
             ),
+            Instr::SetMemoryLength { array, length } => format!(
+                "%{} = set array length:{}",
+                self.vars[array].id.name,
+                self.expr_to_string(contract, ns, length),
+            ),
             Instr::AssertFailure { encoded_args: None } => "assert-failure".to_string(),
             Instr::AssertFailure { encoded_args: Some(expr) } => {
                 format!("assert-failure: buffer: {}",
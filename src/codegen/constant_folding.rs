@@ -128,6 +128,48 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, ns: &mut Namespace) {
                         res: *res,
                     };
                 }
+                Instr::TakeStorage { ty, storage, res } => {
+                    let (storage, _) = expression(storage, Some(&vars), cfg, ns);
+
+                    cfg.blocks[block_no].instr[instr_no] = Instr::TakeStorage {
+                        ty: ty.clone(),
+                        storage,
+                        res: *res,
+                    };
+                }
+                Instr::ContainsStorage { storage, res } => {
+                    let (storage, _) = expression(storage, Some(&vars), cfg, ns);
+
+                    cfg.blocks[block_no].instr[instr_no] =
+                        Instr::ContainsStorage { storage, res: *res };
+                }
+                Instr::ClearTransientStorage { ty, storage } => {
+                    let (storage, _) = expression(storage, Some(&vars), cfg, ns);
+
+                    cfg.blocks[block_no].instr[instr_no] = Instr::ClearTransientStorage {
+                        ty: ty.clone(),
+                        storage,
+                    };
+                }
+                Instr::SetTransientStorage { ty, storage, value } => {
+                    let (storage, _) = expression(storage, Some(&vars), cfg, ns);
+                    let (value, _) = expression(value, Some(&vars), cfg, ns);
+
+                    cfg.blocks[block_no].instr[instr_no] = Instr::SetTransientStorage {
+                        ty: ty.clone(),
+                        storage,
+                        value,
+                    };
+                }
+                Instr::LoadTransientStorage { ty, storage, res } => {
+                    let (storage, _) = expression(storage, Some(&vars), cfg, ns);
+
+                    cfg.blocks[block_no].instr[instr_no] = Instr::LoadTransientStorage {
+                        ty: ty.clone(),
+                        storage,
+                        res: *res,
+                    };
+                }
                 Instr::SetStorageBytes {
                     storage,
                     value,
@@ -185,6 +227,14 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, ns: &mut Namespace) {
                         value: Box::new(value),
                     };
                 }
+                Instr::SetMemoryLength { array, length } => {
+                    let (length, _) = expression(length, Some(&vars), cfg, ns);
+
+                    cfg.blocks[block_no].instr[instr_no] = Instr::SetMemoryLength {
+                        array: *array,
+                        length,
+                    };
+                }
                 Instr::Constructor {
                     success,
                     res,
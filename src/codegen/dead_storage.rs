@@ -229,14 +229,16 @@ fn instr_transfers(block_no: usize, block: &BasicBlock) -> Vec<Vec<Transfer>> {
 
                 v
             }
-            Instr::LoadStorage { res, .. } => set_var(&[*res]),
+            Instr::LoadStorage { res, .. }
+            | Instr::ContainsStorage { res, .. }
+            | Instr::LoadTransientStorage { res, .. } => set_var(&[*res]),
             Instr::PushMemory { array, res, .. } => {
                 let mut v = set_var(&[*res]);
                 v.push(Transfer::Kill { var_no: *array });
 
                 v
             }
-            Instr::PopMemory { array, .. } => {
+            Instr::PopMemory { array, .. } | Instr::SetMemoryLength { array, .. } => {
                 vec![Transfer::Kill { var_no: *array }]
             }
             Instr::ExternalCall {
@@ -288,6 +290,7 @@ fn instr_transfers(block_no: usize, block: &BasicBlock) -> Vec<Vec<Transfer>> {
                 storage,
                 ..
             }
+            | Instr::TakeStorage { res, storage, .. }
             | Instr::PushStorage { res, storage, .. } => {
                 vec![
                     Transfer::Kill { var_no: *res },
@@ -544,7 +547,10 @@ pub fn dead_storage(cfg: &mut ControlFlowGraph, _ns: &mut Namespace) {
                         }
                     }
                 }
-                Instr::PushStorage { storage, .. } | Instr::PopStorage { storage, .. } => {
+                Instr::PushStorage { storage, .. }
+                | Instr::PopStorage { storage, .. }
+                | Instr::TakeStorage { storage, .. }
+                | Instr::ContainsStorage { storage, .. } => {
                     for (def, expr) in &vars.stores {
                         let def_vars = get_vars_at(def, &block_vars);
 
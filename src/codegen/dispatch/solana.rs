@@ -2,12 +2,13 @@
 
 use crate::codegen::{
     cfg::{ASTFunction, ControlFlowGraph, Instr, InternalCallTy, ReturnCode},
+    expression::log_runtime_error,
     solana_deploy::solana_deploy,
     vartable::Vartable,
     Builtin, Expression, Options,
 };
 use crate::{
-    sema::ast::{Namespace, StructType, Type},
+    sema::ast::{ArrayLength, ConstructorAnnotation, Function, Namespace, StructType, Type},
     Target,
 };
 use num_bigint::{BigInt, Sign};
@@ -16,6 +17,9 @@ use solang_parser::{pt, pt::Loc};
 
 use crate::codegen::encoding::{abi_decode, abi_encode};
 
+/// Anchor's tag for the instruction a program invokes itself with to emit an event
+const EVENT_IX_TAG: u64 = 0x1d9a_cb51_2ea5_45e4;
+
 /// Create the dispatch for the Solana target
 pub(crate) fn function_dispatch(
     contract_no: usize,
@@ -179,6 +183,7 @@ pub(crate) fn function_dispatch(
                 ns,
                 &mut vartab,
                 &mut cfg,
+                opt,
             )
         } else if func_cfg.ty == pt::FunctionTy::Constructor {
             add_constructor_dispatch_case(
@@ -206,6 +211,17 @@ pub(crate) fn function_dispatch(
         ));
     }
 
+    if ns.contracts[contract_no].emit_cpi {
+        cases.push((
+            Expression::NumberLiteral {
+                loc: Loc::Codegen,
+                ty: Type::Uint(64),
+                value: BigInt::from(EVENT_IX_TAG),
+            },
+            add_event_cpi_dispatch_case(&mut vartab, &mut cfg),
+        ));
+    }
+
     cfg.set_basic_block(switch_block);
 
     cfg.add(
@@ -276,6 +292,38 @@ pub(crate) fn function_dispatch(
     cfg
 }
 
+/// Events emitted through a self-invocation need no further processing, but the invocation
+/// must be signed by the event authority, else anyone could forge events
+fn add_event_cpi_dispatch_case(vartab: &mut Vartable, cfg: &mut ControlFlowGraph) -> usize {
+    let entry = cfg.new_basic_block("event_cpi".to_string());
+    cfg.set_basic_block(entry);
+
+    let res = vartab.temp_name("event_authority_check", &Type::Uint(64));
+
+    cfg.add(
+        vartab,
+        Instr::Set {
+            loc: Loc::Codegen,
+            res,
+            expr: Expression::Builtin {
+                loc: Loc::Codegen,
+                tys: vec![Type::Uint(64)],
+                kind: Builtin::EventAuthorityCheck,
+                args: vec![],
+            },
+        },
+    );
+
+    cfg.add(
+        vartab,
+        Instr::ReturnCode {
+            code: ReturnCode::Success,
+        },
+    );
+
+    entry
+}
+
 /// Add the dispatch for function given a matched selector
 fn add_function_dispatch_case(
     cfg_no: usize,
@@ -287,6 +335,7 @@ fn add_function_dispatch_case(
     ns: &Namespace,
     vartab: &mut Vartable,
     cfg: &mut ControlFlowGraph,
+    opt: &Options,
 ) -> usize {
     let entry = cfg.new_basic_block(format!("function_cfg_{cfg_no}"));
     cfg.set_basic_block(entry);
@@ -337,6 +386,11 @@ fn add_function_dispatch_case(
         cfg.set_basic_block(magic_ok);
     }
 
+    if let ASTFunction::SolidityFunction(func_no) = func_cfg.function_no {
+        check_declared_accounts(&ns.functions[func_no], ns, vartab, cfg, opt);
+        set_data_account_payer(&ns.functions[func_no], vartab, cfg);
+    }
+
     let truncated_len = Expression::Trunc {
         loc: Loc::Codegen,
         ty: Type::Uint(32),
@@ -429,6 +483,212 @@ fn add_function_dispatch_case(
     entry
 }
 
+/// Check that the accounts declared with annotations were passed to the function, and that they
+/// are signers or writable when required.
+fn check_declared_accounts(
+    func: &Function,
+    ns: &Namespace,
+    vartab: &mut Vartable,
+    cfg: &mut ControlFlowGraph,
+    opt: &Options,
+) {
+    if !func.has_declared_accounts() {
+        return;
+    }
+
+    let accounts_ty = Type::Array(
+        Box::new(Type::Struct(StructType::AccountInfo)),
+        vec![ArrayLength::Dynamic],
+    );
+
+    let accounts = Expression::Builtin {
+        loc: Loc::Codegen,
+        tys: vec![accounts_ty.clone()],
+        kind: Builtin::Accounts,
+        args: vec![],
+    };
+
+    let declared: Vec<(usize, pt::Loc, bool, bool)> = func
+        .solana_accounts
+        .read()
+        .unwrap()
+        .iter()
+        .filter(|(_, account)| !account.generated)
+        .map(|(name, account)| {
+            (
+                func.declared_account_index(name).unwrap(),
+                account.loc,
+                account.is_signer,
+                account.is_writer,
+            )
+        })
+        .collect();
+
+    // The declared accounts are the first ones, so we only need to check for the last one
+    let last_index = declared.iter().map(|(index, ..)| *index).max().unwrap();
+
+    let enough_accounts = cfg.new_basic_block("enough_accounts".into());
+    let not_enough_accounts = cfg.new_basic_block("not_enough_accounts".into());
+
+    cfg.add(
+        vartab,
+        Instr::BranchCond {
+            cond: Expression::More {
+                loc: Loc::Codegen,
+                signed: false,
+                left: Expression::Builtin {
+                    loc: Loc::Codegen,
+                    tys: vec![Type::Uint(32)],
+                    kind: Builtin::ArrayLength,
+                    args: vec![accounts.clone()],
+                }
+                .into(),
+                right: Expression::NumberLiteral {
+                    loc: Loc::Codegen,
+                    ty: Type::Uint(32),
+                    value: last_index.into(),
+                }
+                .into(),
+            },
+            true_block: enough_accounts,
+            false_block: not_enough_accounts,
+        },
+    );
+
+    cfg.set_basic_block(not_enough_accounts);
+    log_runtime_error(
+        opt.log_runtime_errors,
+        "not enough accounts for function",
+        func.loc,
+        cfg,
+        vartab,
+        ns,
+    );
+    cfg.add(
+        vartab,
+        Instr::ReturnCode {
+            code: ReturnCode::NotEnoughAccountKeys,
+        },
+    );
+
+    cfg.set_basic_block(enough_accounts);
+
+    for (index, loc, is_signer, is_writer) in declared {
+        let account_info = Expression::Subscript {
+            loc: Loc::Codegen,
+            ty: Type::Ref(Box::new(Type::Struct(StructType::AccountInfo))),
+            array_ty: accounts_ty.clone(),
+            expr: accounts.clone().into(),
+            index: Expression::NumberLiteral {
+                loc: Loc::Codegen,
+                ty: Type::Uint(32),
+                value: index.into(),
+            }
+            .into(),
+        };
+
+        // AccountInfo members 5 and 6 are is_signer and is_writable
+        let checks = [
+            (
+                is_signer,
+                5,
+                "account is not a signer",
+                ReturnCode::MissingRequiredSignature,
+            ),
+            (
+                is_writer,
+                6,
+                "account is not writable",
+                ReturnCode::InvalidAccountData,
+            ),
+        ];
+
+        for (required, member, reason, code) in checks {
+            if !required {
+                continue;
+            }
+
+            let ok = cfg.new_basic_block("account_ok".into());
+            let bad = cfg.new_basic_block("account_bad".into());
+
+            cfg.add(
+                vartab,
+                Instr::BranchCond {
+                    cond: Expression::Load {
+                        loc: Loc::Codegen,
+                        ty: Type::Bool,
+                        expr: Expression::StructMember {
+                            loc: Loc::Codegen,
+                            ty: Type::Ref(Box::new(Type::Bool)),
+                            expr: account_info.clone().into(),
+                            member,
+                        }
+                        .into(),
+                    },
+                    true_block: ok,
+                    false_block: bad,
+                },
+            );
+
+            cfg.set_basic_block(bad);
+            log_runtime_error(opt.log_runtime_errors, reason, loc, cfg, vartab, ns);
+            cfg.add(vartab, Instr::ReturnCode { code });
+
+            cfg.set_basic_block(ok);
+        }
+    }
+}
+
+/// With a @payer annotation on a function, the data account grows when storage does not fit,
+/// and the payer tops up the rent. The runtime finds the payer through the parameters.
+fn set_data_account_payer(func: &Function, vartab: &mut Vartable, cfg: &mut ControlFlowGraph) {
+    let payer = if let Some(payer) = func.annotations.iter().find_map(|note| match note {
+        ConstructorAnnotation::Payer(_, name) => Some(name),
+        _ => None,
+    }) {
+        payer
+    } else {
+        return;
+    };
+
+    let accounts_ty = Type::Array(
+        Box::new(Type::Struct(StructType::AccountInfo)),
+        vec![ArrayLength::Dynamic],
+    );
+
+    cfg.add(
+        vartab,
+        Instr::Store {
+            dest: Expression::Builtin {
+                loc: Loc::Codegen,
+                tys: vec![Type::Ref(Box::new(Type::Ref(Box::new(Type::Struct(
+                    StructType::AccountInfo,
+                )))))],
+                kind: Builtin::DataAccountPayer,
+                args: vec![],
+            },
+            data: Expression::Subscript {
+                loc: Loc::Codegen,
+                ty: Type::Ref(Box::new(Type::Struct(StructType::AccountInfo))),
+                array_ty: accounts_ty.clone(),
+                expr: Expression::Builtin {
+                    loc: Loc::Codegen,
+                    tys: vec![accounts_ty],
+                    kind: Builtin::Accounts,
+                    args: vec![],
+                }
+                .into(),
+                index: Expression::NumberLiteral {
+                    loc: Loc::Codegen,
+                    ty: Type::Uint(32),
+                    value: func.declared_account_index(payer).unwrap().into(),
+                }
+                .into(),
+            },
+        },
+    );
+}
+
 /// Create the dispatch for a contract constructor. This case creates a new function in
 /// the CFG because we want to use the abi decoding implementation from codegen.
 fn add_constructor_dispatch_case(
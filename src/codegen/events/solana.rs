@@ -5,7 +5,7 @@ use crate::codegen::encoding::abi_encode;
 use crate::codegen::events::EventEmitter;
 use crate::codegen::expression::expression;
 use crate::codegen::vartable::Vartable;
-use crate::codegen::{Expression, Options};
+use crate::codegen::{Builtin, Expression, Options};
 use crate::sema::ast;
 use crate::sema::ast::{Function, Namespace, Type};
 use sha2::{Digest, Sha256};
@@ -50,13 +50,31 @@ impl EventEmitter for SolanaEventEmitter<'_> {
         to_be_encoded.append(&mut codegen_args);
         let data = abi_encode(&self.loc, to_be_encoded, self.ns, vartab, cfg, false).0;
 
-        cfg.add(
-            vartab,
-            Instr::EmitEvent {
-                event_no: self.event_no,
-                data,
-                topics: vec![],
-            },
-        );
+        if self.ns.contracts[contract_no].emit_cpi {
+            let res = vartab.temp_name("emit_cpi", &Type::Uint(64));
+
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: Loc::Codegen,
+                    res,
+                    expr: Expression::Builtin {
+                        loc: self.loc,
+                        tys: vec![Type::Uint(64)],
+                        kind: Builtin::EmitCpiEvent,
+                        args: vec![data],
+                    },
+                },
+            );
+        } else {
+            cfg.add(
+                vartab,
+                Instr::EmitEvent {
+                    event_no: self.event_no,
+                    data,
+                    topics: vec![],
+                },
+            );
+        }
     }
 }
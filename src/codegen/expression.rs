@@ -2,7 +2,8 @@
 
 use super::encoding::{abi_decode, abi_encode};
 use super::storage::{
-    array_offset, array_pop, array_push, storage_slots_array_pop, storage_slots_array_push,
+    array_offset, array_pop, array_push, load_storage_variable, set_storage_variable,
+    storage_slots_array_pop, storage_slots_array_push,
 };
 use super::Options;
 use super::{
@@ -55,7 +56,7 @@ pub fn expression(
         ast::Expression::StorageLoad { loc, ty, expr } => {
             let storage = expression(expr, cfg, contract_no, func, ns, vartab, opt);
 
-            load_storage(loc, ty, storage, cfg, vartab)
+            load_storage_variable(expr, loc, ty, storage, cfg, vartab, ns)
         }
         ast::Expression::Add {
             loc,
@@ -841,6 +842,47 @@ pub fn expression(
                 )
             }
         }
+        ast::Expression::Builtin {
+            loc,
+            tys: ty,
+            kind: ast::Builtin::TakeStorage,
+            args,
+        } => {
+            let storage = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
+            let res = vartab.temp_anonymous(&ty[0]);
+
+            cfg.add(
+                vartab,
+                Instr::TakeStorage {
+                    res,
+                    ty: ty[0].clone(),
+                    storage,
+                },
+            );
+
+            Expression::Variable {
+                loc: *loc,
+                ty: ty[0].clone(),
+                var_no: res,
+            }
+        }
+        ast::Expression::Builtin {
+            loc,
+            kind: ast::Builtin::ContainsStorage,
+            args,
+            ..
+        } => {
+            let storage = expression(&args[0], cfg, contract_no, func, ns, vartab, opt);
+            let res = vartab.temp_anonymous(&Type::Bool);
+
+            cfg.add(vartab, Instr::ContainsStorage { res, storage });
+
+            Expression::Variable {
+                loc: *loc,
+                ty: Type::Bool,
+                var_no: res,
+            }
+        }
         ast::Expression::Builtin {
             loc,
             tys: ty,
@@ -1161,7 +1203,9 @@ fn post_incdec(
             ty: ty.as_ref().clone(),
             expr: Box::new(v),
         },
-        Type::StorageRef(_, ty) => load_storage(&var.loc(), ty.as_ref(), v, cfg, vartab),
+        Type::StorageRef(_, ty) => {
+            load_storage_variable(var, &var.loc(), ty.as_ref(), v, cfg, vartab, ns)
+        }
         _ => v,
     };
     cfg.add(
@@ -1227,17 +1271,18 @@ fn post_incdec(
 
             match var.ty() {
                 Type::StorageRef(..) => {
-                    cfg.add(
-                        vartab,
-                        Instr::SetStorage {
-                            value: Expression::Variable {
-                                loc: *loc,
-                                ty: ty.clone(),
-                                var_no: res,
-                            },
+                    set_storage_variable(
+                        var,
+                        ty.clone(),
+                        Expression::Variable {
+                            loc: *loc,
                             ty: ty.clone(),
-                            storage: dest,
+                            var_no: res,
                         },
+                        dest,
+                        cfg,
+                        vartab,
+                        ns,
                     );
                 }
                 Type::Ref(_) => {
@@ -1285,7 +1330,9 @@ fn pre_incdec(
             ty: ty.as_ref().clone(),
             expr: Box::new(v),
         },
-        Type::StorageRef(_, ty) => load_storage(&var.loc(), ty.as_ref(), v, cfg, vartab),
+        Type::StorageRef(_, ty) => {
+            load_storage_variable(var, &var.loc(), ty.as_ref(), v, cfg, vartab, ns)
+        }
         _ => v,
     };
     let one = Box::new(Expression::NumberLiteral {
@@ -1338,17 +1385,18 @@ fn pre_incdec(
 
             match var.ty() {
                 Type::StorageRef(..) => {
-                    cfg.add(
-                        vartab,
-                        Instr::SetStorage {
-                            value: Expression::Variable {
-                                loc: *loc,
-                                ty: ty.clone(),
-                                var_no: res,
-                            },
+                    set_storage_variable(
+                        var,
+                        ty.clone(),
+                        Expression::Variable {
+                            loc: *loc,
                             ty: ty.clone(),
-                            storage: dest,
+                            var_no: res,
                         },
+                        dest,
+                        cfg,
+                        vartab,
+                        ns,
                     );
                 }
                 Type::Ref(_) => {
@@ -2212,6 +2260,32 @@ fn expr_builtin(
                 expr: Box::new(codegen_expr),
             }
         }
+        ast::Builtin::AccountRealloc => {
+            let arguments: Vec<Expression> = args
+                .iter()
+                .map(|v| expression(v, cfg, contract_no, func, ns, vartab, opt))
+                .collect();
+
+            // The realloc has no return value, so it needs an instruction. Emit returns from the
+            // function if the realloc fails.
+            let res = vartab.temp_name("realloc", &Type::Uint(64));
+
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: *loc,
+                    res,
+                    expr: Expression::Builtin {
+                        loc: *loc,
+                        tys: vec![Type::Uint(64)],
+                        kind: Builtin::AccountRealloc,
+                        args: arguments,
+                    },
+                },
+            );
+
+            Expression::Poison
+        }
         _ => {
             let arguments: Vec<Expression> = args
                 .iter()
@@ -2606,17 +2680,18 @@ pub fn assign_single(
                     }
                 }
                 Type::StorageRef(..) => {
-                    cfg.add(
-                        vartab,
-                        Instr::SetStorage {
-                            value: Expression::Variable {
-                                loc: left.loc(),
-                                ty: ty.clone(),
-                                var_no: pos,
-                            },
-                            ty: ty.deref_any().clone(),
-                            storage: dest,
+                    set_storage_variable(
+                        left,
+                        ty.deref_any().clone(),
+                        Expression::Variable {
+                            loc: left.loc(),
+                            ty: ty.clone(),
+                            var_no: pos,
                         },
+                        dest,
+                        cfg,
+                        vartab,
+                        ns,
                     );
                 }
                 Type::Ref(_) => {
@@ -300,10 +300,34 @@ fn layout(contract_no: usize, ns: &mut Namespace) {
     } else {
         BigInt::zero()
     };
+    let mut transient_slot = BigInt::zero();
 
     for base_contract_no in ns.contract_bases(contract_no) {
         for var_no in 0..ns.contracts[base_contract_no].variables.len() {
-            if !ns.contracts[base_contract_no].variables[var_no].constant {
+            if ns.contracts[base_contract_no].variables[var_no].transient {
+                let ty = ns.contracts[base_contract_no].variables[var_no].ty.clone();
+
+                if ns.target == Target::Solana {
+                    // transient storage is a buffer on the heap, so it needs aligning too
+                    let alignment = ty.align_of(ns);
+
+                    let offset = transient_slot.clone() % alignment;
+
+                    if offset > BigInt::zero() {
+                        transient_slot += alignment - offset;
+                    }
+                }
+
+                ns.contracts[contract_no].layout.push(Layout {
+                    slot: transient_slot.clone(),
+                    contract_no: base_contract_no,
+                    var_no,
+                    ty: ty.clone(),
+                    transient: true,
+                });
+
+                transient_slot += ty.storage_slots(ns);
+            } else if !ns.contracts[base_contract_no].variables[var_no].constant {
                 let ty = ns.contracts[base_contract_no].variables[var_no].ty.clone();
 
                 if ns.target == Target::Solana {
@@ -322,6 +346,7 @@ fn layout(contract_no: usize, ns: &mut Namespace) {
                     contract_no: base_contract_no,
                     var_no,
                     ty: ty.clone(),
+                    transient: false,
                 });
 
                 slot += ty.storage_slots(ns);
@@ -1718,6 +1743,7 @@ impl Expression {
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum Builtin {
     Accounts,
+    AccountRealloc,
     AddMod,
     ArrayLength,
     Balance,
@@ -1729,6 +1755,14 @@ pub enum Builtin {
     BlockNumber,
     Calldata,
     ChainId,
+    /// Pointer to the account which pays the rent when the data account grows (Solana only).
+    /// This is set by the function dispatch.
+    DataAccountPayer,
+    /// Emit an event through a self-invocation signed by the event authority, like Anchor's
+    /// `emit_cpi!` (Solana only)
+    EmitCpiEvent,
+    /// Check that the event self-invocation was signed by the event authority (Solana only)
+    EventAuthorityCheck,
     Gasleft,
     GasLimit,
     Gasprice,
@@ -1738,12 +1772,17 @@ pub enum Builtin {
     /// scratch buffer, to which many syscall write. We strongly recommend loading the pointer
     /// before using on Substrate. This is not the case for Solana, though.
     GetAddress,
+    GetClock,
+    GetEpochSchedule,
     ExtCodeSize,
+    InstructionsCurrentIndex,
+    InstructionsLoad,
     MinimumBalance,
     MulMod,
     Keccak256,
     Origin,
     ReadFromBuffer,
+    RentMinimumBalance,
     Ripemd160,
     Sender,
     Slot,
@@ -1772,6 +1811,7 @@ impl From<&ast::Builtin> for Builtin {
     fn from(ast_builtin: &ast::Builtin) -> Self {
         match ast_builtin {
             ast::Builtin::Accounts => Builtin::Accounts,
+            ast::Builtin::AccountRealloc => Builtin::AccountRealloc,
             ast::Builtin::AddMod => Builtin::AddMod,
             ast::Builtin::ArrayLength => Builtin::ArrayLength,
             ast::Builtin::Balance => Builtin::Balance,
@@ -1786,6 +1826,10 @@ impl From<&ast::Builtin> for Builtin {
             ast::Builtin::GasLimit => Builtin::GasLimit,
             ast::Builtin::Gasprice => Builtin::Gasprice,
             ast::Builtin::GetAddress => Builtin::GetAddress,
+            ast::Builtin::GetClock => Builtin::GetClock,
+            ast::Builtin::GetEpochSchedule => Builtin::GetEpochSchedule,
+            ast::Builtin::InstructionsCurrentIndex => Builtin::InstructionsCurrentIndex,
+            ast::Builtin::InstructionsLoad => Builtin::InstructionsLoad,
             ast::Builtin::MinimumBalance => Builtin::MinimumBalance,
             ast::Builtin::MulMod => Builtin::MulMod,
             ast::Builtin::Keccak256 => Builtin::Keccak256,
@@ -1802,6 +1846,7 @@ impl From<&ast::Builtin> for Builtin {
             | ast::Builtin::ReadUint64LE
             | ast::Builtin::ReadUint128LE
             | ast::Builtin::ReadUint256LE => Builtin::ReadFromBuffer,
+            ast::Builtin::RentMinimumBalance => Builtin::RentMinimumBalance,
             ast::Builtin::Ripemd160 => Builtin::Ripemd160,
             ast::Builtin::Sender => Builtin::Sender,
             ast::Builtin::Slot => Builtin::Slot,
@@ -134,16 +134,18 @@ fn instr_transfers(block_no: usize, block: &BasicBlock) -> Vec<Vec<Transfer>> {
             }
             Instr::Set { res, .. } => set_var(&[*res]),
             Instr::Call { res, .. } => set_var(res),
-            Instr::LoadStorage { res, .. } | Instr::PopStorage { res: Some(res), .. } => {
-                set_var(&[*res])
-            }
+            Instr::LoadStorage { res, .. }
+            | Instr::TakeStorage { res, .. }
+            | Instr::ContainsStorage { res, .. }
+            | Instr::LoadTransientStorage { res, .. }
+            | Instr::PopStorage { res: Some(res), .. } => set_var(&[*res]),
             Instr::PushMemory { array, res, .. } => {
                 let mut v = set_var(&[*res]);
                 v.push(Transfer::Mod { var_no: *array });
 
                 v
             }
-            Instr::PopMemory { array, .. } => {
+            Instr::PopMemory { array, .. } | Instr::SetMemoryLength { array, .. } => {
                 vec![Transfer::Mod { var_no: *array }]
             }
             Instr::ExternalCall {
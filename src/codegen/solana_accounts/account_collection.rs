@@ -36,7 +36,8 @@ impl RecurseData<'_> {
     fn add_account(&mut self, account_name: String, account: SolanaAccount) {
         if self.functions[self.ast_no]
             .solana_accounts
-            .borrow_mut()
+            .write()
+            .unwrap()
             .insert(
                 account_name,
                 SolanaAccount {
@@ -82,7 +83,7 @@ pub(crate) fn collect_accounts_from_contract(contract_no: usize, ns: &Namespace)
             match &func.mutability {
                 Mutability::Pure(_) => (),
                 Mutability::View(_) => {
-                    let (idx, _) = func.solana_accounts.borrow_mut().insert_full(
+                    let (idx, _) = func.solana_accounts.write().unwrap().insert_full(
                         BuiltinAccounts::DataAccount.to_string(),
                         SolanaAccount {
                             loc: Loc::Codegen,
@@ -91,10 +92,10 @@ pub(crate) fn collect_accounts_from_contract(contract_no: usize, ns: &Namespace)
                             generated: true,
                         },
                     );
-                    func.solana_accounts.borrow_mut().move_index(idx, 0);
+                    func.solana_accounts.write().unwrap().move_index(idx, 0);
                 }
                 _ => {
-                    let (idx, _) = func.solana_accounts.borrow_mut().insert_full(
+                    let (idx, _) = func.solana_accounts.write().unwrap().insert_full(
                         BuiltinAccounts::DataAccount.to_string(),
                         SolanaAccount {
                             loc: Loc::Codegen,
@@ -106,16 +107,20 @@ pub(crate) fn collect_accounts_from_contract(contract_no: usize, ns: &Namespace)
                             /// However, if a @seed is also provided, the program can sign for the account
                             /// with the seed using program derived address (pda) when SystemProgram.CreateAccount is called,
                             /// so no signer is required from the client.
-                            is_signer: func.has_payer_annotation() && !func.has_seed_annotation(),
+                            is_signer: func.is_constructor()
+                                && func.has_payer_annotation()
+                                && !func.has_seed_annotation(),
                             generated: true,
                         },
                     );
 
-                    func.solana_accounts.borrow_mut().move_index(idx, 0);
+                    func.solana_accounts.write().unwrap().move_index(idx, 0);
                 }
             }
-            if func.is_constructor() && func.has_payer_annotation() {
-                func.solana_accounts.borrow_mut().insert(
+            // The system program creates the data account in a constructor, or transfers the
+            // rent when a function grows the data account
+            if func.has_payer_annotation() {
+                func.solana_accounts.write().unwrap().insert(
                     BuiltinAccounts::SystemAccount.to_string(),
                     SolanaAccount {
                         loc: Loc::Codegen,
@@ -209,6 +214,10 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
         Instr::Print { expr }
         | Instr::LoadStorage { storage: expr, .. }
         | Instr::ClearStorage { storage: expr, .. }
+        | Instr::TakeStorage { storage: expr, .. }
+        | Instr::ContainsStorage { storage: expr, .. }
+        | Instr::LoadTransientStorage { storage: expr, .. }
+        | Instr::ClearTransientStorage { storage: expr, .. }
         | Instr::BranchCond { cond: expr, .. }
         | Instr::PopStorage { storage: expr, .. }
         | Instr::SelfDestruct { recipient: expr }
@@ -224,8 +233,13 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
                 data.next_queue.insert((data.contract_no, data.cfg_func_no));
                 match &data.contracts[data.contract_no].cfg[*cfg_no].function_no {
                     ASTFunction::SolidityFunction(ast_no) | ASTFunction::YulFunction(ast_no) => {
-                        let accounts_to_add =
-                            data.functions[*ast_no].solana_accounts.borrow().clone();
+                        check_declared_accounts_call(*ast_no, data);
+
+                        let accounts_to_add = data.functions[*ast_no]
+                            .solana_accounts
+                            .read()
+                            .unwrap()
+                            .clone();
                         for (account_name, account) in accounts_to_add {
                             data.add_account(account_name, account);
                         }
@@ -280,6 +294,11 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
             value: expr1,
             storage: expr2,
             ..
+        }
+        | Instr::SetTransientStorage {
+            value: expr1,
+            storage: expr2,
+            ..
         } => {
             expr1.recurse(data, check_expression);
             expr2.recurse(data, check_expression);
@@ -316,6 +335,9 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
         Instr::PushMemory { value, .. } => {
             value.recurse(data, check_expression);
         }
+        Instr::SetMemoryLength { length, .. } => {
+            length.recurse(data, check_expression);
+        }
         Instr::Constructor {
             encoded_args,
             value,
@@ -349,7 +371,8 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
                 if let Some(constructor_no) = constructor_no {
                     let accounts_to_add = data.functions[*constructor_no]
                         .solana_accounts
-                        .borrow()
+                        .read()
+                        .unwrap()
                         .clone();
                     for (name, account) in accounts_to_add {
                         if name == BuiltinAccounts::DataAccount {
@@ -358,7 +381,8 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
 
                         if let Some(other_account) = data.functions[data.ast_no]
                             .solana_accounts
-                            .borrow()
+                            .read()
+                            .unwrap()
                             .get(&name)
                         {
                             // If the compiler did not generate this account entry, we have a name
@@ -425,7 +449,8 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
                 let cfg_no = data.contracts[*contract_no].all_functions[function_no];
                 let accounts_to_add = data.functions[*function_no]
                     .solana_accounts
-                    .borrow()
+                    .read()
+                    .unwrap()
                     .clone();
                 for (account_name, account) in accounts_to_add {
                     data.add_account(account_name, account);
@@ -454,6 +479,39 @@ fn check_instruction(instr: &Instr, data: &mut RecurseData) {
     }
 }
 
+/// Declared accounts are accessed by their position in tx.accounts, which is only correct when
+/// the function is called by the dispatcher. Modifiers, which call the function body, are allowed.
+fn check_declared_accounts_call(callee_no: usize, data: &mut RecurseData) {
+    let caller = &data.contracts[data.contract_no].cfg[data.cfg_func_no];
+
+    let caller_no = match caller.function_no {
+        ASTFunction::SolidityFunction(ast_no) | ASTFunction::YulFunction(ast_no) => ast_no,
+        ASTFunction::None => return,
+    };
+
+    if caller_no == callee_no
+        || data.functions[caller_no].ty == FunctionTy::Modifier
+        || !data.functions[callee_no].has_declared_accounts()
+    {
+        return;
+    }
+
+    let diagnostic = Diagnostic::error_with_note(
+        data.functions[caller_no].loc,
+        format!(
+            "function '{}' declares accounts with annotations, so it cannot be called internally",
+            data.functions[callee_no].name
+        ),
+        data.functions[callee_no].loc,
+        "definition of function".to_string(),
+    );
+
+    // The same function can be visited many times until the accounts converge
+    if !data.diagnostics.iter().any(|diag| *diag == diagnostic) {
+        data.diagnostics.push(diagnostic);
+    }
+}
+
 /// Collect accounts from this expression
 fn check_expression(expr: &Expression, data: &mut RecurseData) -> bool {
     match expr {
@@ -472,7 +530,8 @@ fn check_expression(expr: &Expression, data: &mut RecurseData) -> bool {
             );
         }
         Expression::Builtin {
-            kind: Builtin::SignatureVerify,
+            kind:
+                Builtin::SignatureVerify | Builtin::InstructionsCurrentIndex | Builtin::InstructionsLoad,
             ..
         } => {
             data.add_account(
@@ -491,6 +550,22 @@ fn check_expression(expr: &Expression, data: &mut RecurseData) -> bool {
         } => {
             data.add_system_account();
         }
+        Expression::Builtin {
+            kind: Builtin::EmitCpiEvent,
+            ..
+        } => {
+            for account in [BuiltinAccounts::EventAuthority, BuiltinAccounts::Program] {
+                data.add_account(
+                    account.to_string(),
+                    SolanaAccount {
+                        loc: Loc::Codegen,
+                        is_writer: false,
+                        is_signer: false,
+                        generated: true,
+                    },
+                );
+            }
+        }
 
         _ => (),
     }
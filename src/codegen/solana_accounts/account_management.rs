@@ -69,7 +69,7 @@ fn process_instruction(instr: &mut Instr, functions: &[Function], ast_no: usize)
 
         let mut account_metas: Vec<Expression> = Vec::new();
         let constructor_func = &functions[constructor_no.unwrap()];
-        for (name, account) in constructor_func.solana_accounts.borrow().iter() {
+        for (name, account) in constructor_func.solana_accounts.read().unwrap().iter() {
             if name == BuiltinAccounts::DataAccount {
                 let address_ref = Expression::GetRef {
                     loc: Loc::Codegen,
@@ -95,7 +95,8 @@ fn process_instruction(instr: &mut Instr, functions: &[Function], ast_no: usize)
             } else {
                 let account_index = functions[ast_no]
                     .solana_accounts
-                    .borrow()
+                    .read()
+                    .unwrap()
                     .get_index_of(name)
                     .unwrap();
                 let ptr_to_address = index_accounts_vector(account_index);
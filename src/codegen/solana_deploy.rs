@@ -248,14 +248,7 @@ pub(super) fn solana_deploy(
 
         let metas = vartab.temp_name("metas", &metas_ty);
 
-        // FIXME: The +1 accounts for the not yet added data account, which is always the first one.
-        // We need to fix a chicken and egg problem to solve this. The proper indexes are only
-        // calculated after that the deploy code is generated.
-        // Alternatively, we can conceive an Expression::NamedIndex to index the tx.account by
-        // account name and exchange it by a normal Expression::Subscript at the AccountManagement
-        // pass.
-        // THAT IS WORK FOR ANOTHER PR!
-        let payer_index = func.solana_accounts.borrow().get_index_of(name).unwrap() + 1;
+        let payer_index = func.declared_account_index(name).unwrap();
         let ptr_to_address = index_accounts_vector(payer_index);
 
         cfg.add(
@@ -13,6 +13,7 @@ use super::{
 };
 use crate::codegen::constructor::call_constructor;
 use crate::codegen::events::new_event_emitter;
+use crate::codegen::storage::is_transient;
 use crate::codegen::unused_variable::{
     should_remove_assignment, should_remove_variable, SideEffectsCheckParameters,
 };
@@ -209,13 +210,23 @@ pub(crate) fn statement(
         Statement::Delete(_, ty, expr) => {
             let var_expr = expression(expr, cfg, contract_no, Some(func), ns, vartab, opt);
 
-            cfg.add(
-                vartab,
-                Instr::ClearStorage {
-                    ty: ty.clone(),
-                    storage: var_expr,
-                },
-            );
+            if is_transient(expr, ns) {
+                cfg.add(
+                    vartab,
+                    Instr::ClearTransientStorage {
+                        ty: ty.clone(),
+                        storage: var_expr,
+                    },
+                );
+            } else {
+                cfg.add(
+                    vartab,
+                    Instr::ClearStorage {
+                        ty: ty.clone(),
+                        storage: var_expr,
+                    },
+                );
+            }
         }
         Statement::Break(_) => {
             cfg.add(
@@ -1198,8 +1209,8 @@ fn try_catch(
                     }
                 }
             } else {
-                // dynamic dispatch
-                unimplemented!();
+                // an external function call always has an external function type
+                unreachable!();
             }
         }
         ast::Expression::Constructor {
@@ -1582,6 +1593,7 @@ pub fn process_side_effects_expressions(
             ast::Builtin::PayableSend
             | ast::Builtin::ArrayPush
             | ast::Builtin::ArrayPop
+            | ast::Builtin::TakeStorage
             // PayableTransfer, Revert, Require and SelfDestruct do not occur inside an expression
             // for they return no value. They should not bother the unused variable elimination.
             | ast::Builtin::PayableTransfer
@@ -17,6 +17,62 @@ use crate::codegen::expression::assert_failure;
 use crate::sema::ast::{Function, Namespace, RetrieveType, Type};
 use solang_parser::pt;
 
+/// Is this expression a transient storage variable? Transient variables can only be value types,
+/// so they are always accessed directly rather than through a reference.
+pub fn is_transient(var: &ast::Expression, ns: &Namespace) -> bool {
+    matches!(var, ast::Expression::StorageVariable { contract_no, var_no, .. }
+        if ns.contracts[*contract_no].variables[*var_no].transient)
+}
+
+/// Load the value of the storage variable `var`, from either contract storage or transient storage
+pub fn load_storage_variable(
+    var: &ast::Expression,
+    loc: &pt::Loc,
+    ty: &Type,
+    storage: Expression,
+    cfg: &mut ControlFlowGraph,
+    vartab: &mut Vartable,
+    ns: &Namespace,
+) -> Expression {
+    if !is_transient(var, ns) {
+        return load_storage(loc, ty, storage, cfg, vartab);
+    }
+
+    let res = vartab.temp_anonymous(ty);
+
+    cfg.add(
+        vartab,
+        Instr::LoadTransientStorage {
+            res,
+            ty: ty.clone(),
+            storage,
+        },
+    );
+
+    Expression::Variable {
+        loc: *loc,
+        ty: ty.clone(),
+        var_no: res,
+    }
+}
+
+/// Set the value of the storage variable `var`, in either contract storage or transient storage
+pub fn set_storage_variable(
+    var: &ast::Expression,
+    ty: Type,
+    value: Expression,
+    storage: Expression,
+    cfg: &mut ControlFlowGraph,
+    vartab: &mut Vartable,
+    ns: &Namespace,
+) {
+    if is_transient(var, ns) {
+        cfg.add(vartab, Instr::SetTransientStorage { ty, value, storage });
+    } else {
+        cfg.add(vartab, Instr::SetStorage { ty, value, storage });
+    }
+}
+
 /// Given a storage slot which is the start of the array, calculate the
 /// offset of the array element. This function exists to avoid doing
 /// 256 bit multiply if possible.
@@ -308,51 +364,75 @@ pub fn storage_slots_array_pop(
         },
     );
 
-    let val = if *return_ty != Type::Void {
-        let res_pos = vartab.temp_anonymous(&elem_ty);
+    let entry = Expression::Variable {
+        loc: *loc,
+        ty: slot_ty.clone(),
+        var_no: entry_pos,
+    };
 
-        let expr = load_storage(
-            loc,
-            &elem_ty,
-            Expression::Variable {
-                loc: *loc,
-                ty: elem_ty.clone(),
-                var_no: entry_pos,
-            },
-            cfg,
-            vartab,
-        );
+    let val = if *return_ty != Type::Void && ns.target.is_substrate() && elem_ty.is_primitive() {
+        // Substrate can read and clear a value in one go
+        let res_pos = vartab.temp_anonymous(&elem_ty);
 
         cfg.add(
             vartab,
-            Instr::Set {
-                loc: *loc,
+            Instr::TakeStorage {
                 res: res_pos,
-                expr,
+                ty: elem_ty.clone(),
+                storage: entry,
             },
         );
+
         Expression::Variable {
             loc: *loc,
-            ty: elem_ty.clone(),
+            ty: elem_ty,
             var_no: res_pos,
         }
     } else {
-        Expression::Undefined {
-            ty: elem_ty.clone(),
-        }
-    };
+        let val = if *return_ty != Type::Void {
+            let res_pos = vartab.temp_anonymous(&elem_ty);
 
-    cfg.add(
-        vartab,
-        Instr::ClearStorage {
-            ty: elem_ty,
-            storage: Expression::Variable {
+            let expr = load_storage(
+                loc,
+                &elem_ty,
+                Expression::Variable {
+                    loc: *loc,
+                    ty: elem_ty.clone(),
+                    var_no: entry_pos,
+                },
+                cfg,
+                vartab,
+            );
+
+            cfg.add(
+                vartab,
+                Instr::Set {
+                    loc: *loc,
+                    res: res_pos,
+                    expr,
+                },
+            );
+            Expression::Variable {
                 loc: *loc,
-                ty: slot_ty.clone(),
-                var_no: entry_pos,
+                ty: elem_ty.clone(),
+                var_no: res_pos,
+            }
+        } else {
+            Expression::Undefined {
+                ty: elem_ty.clone(),
+            }
+        };
+
+        cfg.add(
+            vartab,
+            Instr::ClearStorage {
+                ty: elem_ty,
+                storage: entry,
             },
-        },
-    );
+        );
+
+        val
+    };
 
     // set decrease length
     cfg.add(
@@ -130,10 +130,14 @@ fn block_reduce(
             Instr::Print { expr } => {
                 *expr = expression_reduce(expr, &vars, ns);
             }
-            Instr::ClearStorage { storage, .. } => {
+            Instr::ClearStorage { storage, .. }
+            | Instr::TakeStorage { storage, .. }
+            | Instr::ContainsStorage { storage, .. }
+            | Instr::ClearTransientStorage { storage, .. } => {
                 *storage = expression_reduce(storage, &vars, ns);
             }
-            Instr::SetStorage { storage, value, .. } => {
+            Instr::SetStorage { storage, value, .. }
+            | Instr::SetTransientStorage { storage, value, .. } => {
                 *value = expression_reduce(value, &vars, ns);
                 *storage = expression_reduce(storage, &vars, ns);
             }
@@ -159,6 +163,9 @@ fn block_reduce(
             Instr::PushMemory { value, .. } => {
                 *value = Box::new(expression_reduce(value, &vars, ns));
             }
+            Instr::SetMemoryLength { length, .. } => {
+                *length = expression_reduce(length, &vars, ns);
+            }
             Instr::Constructor {
                 encoded_args,
                 value,
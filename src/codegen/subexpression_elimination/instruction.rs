@@ -18,6 +18,10 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
             Instr::BranchCond { cond: expr, .. }
             | Instr::LoadStorage { storage: expr, .. }
             | Instr::ClearStorage { storage: expr, .. }
+            | Instr::TakeStorage { storage: expr, .. }
+            | Instr::ContainsStorage { storage: expr, .. }
+            | Instr::LoadTransientStorage { storage: expr, .. }
+            | Instr::ClearTransientStorage { storage: expr, .. }
             | Instr::Print { expr }
             | Instr::AssertFailure {
                 encoded_args: Some(expr),
@@ -63,11 +67,20 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 let _ = self.gen_expression(expr, ave, cst);
             }
 
+            Instr::SetMemoryLength { length: expr, .. } => {
+                let _ = self.gen_expression(expr, ave, cst);
+            }
+
             Instr::SetStorage {
                 value: item_1,
                 storage: item_2,
                 ..
             }
+            | Instr::SetTransientStorage {
+                value: item_1,
+                storage: item_2,
+                ..
+            }
             | Instr::ReturnData {
                 data: item_1,
                 data_len: item_2,
@@ -276,12 +289,40 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 storage: self.regenerate_expression(storage, ave, cst).1,
             },
 
+            Instr::TakeStorage { res, ty, storage } => Instr::TakeStorage {
+                res: *res,
+                ty: ty.clone(),
+                storage: self.regenerate_expression(storage, ave, cst).1,
+            },
+
+            Instr::ContainsStorage { res, storage } => Instr::ContainsStorage {
+                res: *res,
+                storage: self.regenerate_expression(storage, ave, cst).1,
+            },
+
             Instr::SetStorage { ty, value, storage } => Instr::SetStorage {
                 ty: ty.clone(),
                 value: self.regenerate_expression(value, ave, cst).1,
                 storage: self.regenerate_expression(storage, ave, cst).1,
             },
 
+            Instr::LoadTransientStorage { res, ty, storage } => Instr::LoadTransientStorage {
+                res: *res,
+                ty: ty.clone(),
+                storage: self.regenerate_expression(storage, ave, cst).1,
+            },
+
+            Instr::ClearTransientStorage { ty, storage } => Instr::ClearTransientStorage {
+                ty: ty.clone(),
+                storage: self.regenerate_expression(storage, ave, cst).1,
+            },
+
+            Instr::SetTransientStorage { ty, value, storage } => Instr::SetTransientStorage {
+                ty: ty.clone(),
+                value: self.regenerate_expression(value, ave, cst).1,
+                storage: self.regenerate_expression(storage, ave, cst).1,
+            },
+
             Instr::SetStorageBytes {
                 value,
                 storage,
@@ -324,6 +365,11 @@ impl<'a, 'b: 'a> AvailableExpressionSet<'a> {
                 value: Box::new(self.regenerate_expression(value, ave, cst).1),
             },
 
+            Instr::SetMemoryLength { array, length } => Instr::SetMemoryLength {
+                array: *array,
+                length: self.regenerate_expression(length, ave, cst).1,
+            },
+
             Instr::Constructor {
                 success,
                 res,
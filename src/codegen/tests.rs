@@ -7,6 +7,7 @@ use crate::{codegen, sema::ast};
 fn test_builtin_conversion() {
     let input: Vec<ast::Builtin> = vec![
         ast::Builtin::Accounts,
+        ast::Builtin::AccountRealloc,
         ast::Builtin::AddMod,
         ast::Builtin::ArrayLength,
         ast::Builtin::Balance,
@@ -21,6 +22,10 @@ fn test_builtin_conversion() {
         ast::Builtin::GasLimit,
         ast::Builtin::Gasprice,
         ast::Builtin::GetAddress,
+        ast::Builtin::GetClock,
+        ast::Builtin::GetEpochSchedule,
+        ast::Builtin::InstructionsCurrentIndex,
+        ast::Builtin::InstructionsLoad,
         ast::Builtin::MinimumBalance,
         ast::Builtin::MulMod,
         ast::Builtin::Keccak256,
@@ -37,6 +42,7 @@ fn test_builtin_conversion() {
         ast::Builtin::ReadUint64LE,
         ast::Builtin::ReadUint128LE,
         ast::Builtin::ReadUint256LE,
+        ast::Builtin::RentMinimumBalance,
         ast::Builtin::Ripemd160,
         ast::Builtin::Sender,
         ast::Builtin::Slot,
@@ -63,6 +69,7 @@ fn test_builtin_conversion() {
 
     let output: Vec<codegen::Builtin> = vec![
         codegen::Builtin::Accounts,
+        codegen::Builtin::AccountRealloc,
         codegen::Builtin::AddMod,
         codegen::Builtin::ArrayLength,
         codegen::Builtin::Balance,
@@ -77,6 +84,10 @@ fn test_builtin_conversion() {
         codegen::Builtin::GasLimit,
         codegen::Builtin::Gasprice,
         codegen::Builtin::GetAddress,
+        codegen::Builtin::GetClock,
+        codegen::Builtin::GetEpochSchedule,
+        codegen::Builtin::InstructionsCurrentIndex,
+        codegen::Builtin::InstructionsLoad,
         codegen::Builtin::MinimumBalance,
         codegen::Builtin::MulMod,
         codegen::Builtin::Keccak256,
@@ -93,6 +104,7 @@ fn test_builtin_conversion() {
         codegen::Builtin::ReadFromBuffer,
         codegen::Builtin::ReadFromBuffer,
         codegen::Builtin::ReadFromBuffer,
+        codegen::Builtin::RentMinimumBalance,
         codegen::Builtin::Ripemd160,
         codegen::Builtin::Sender,
         codegen::Builtin::Slot,
@@ -4,6 +4,7 @@ use crate::codegen::cfg::{ASTFunction, ControlFlowGraph, Instr};
 use crate::codegen::reaching_definitions::{apply_transfers, VarDefs};
 use crate::codegen::{Builtin, Expression};
 use crate::sema::ast::{Diagnostic, ErrorType, Level, Namespace, Note, Type};
+use crate::sema::codes;
 use crate::sema::symtable;
 use solang_parser::pt::CodeLocation;
 use solang_parser::pt::{Loc, StorageLocation};
@@ -136,6 +137,7 @@ fn add_diagnostic(
     diagnostics.entry(var_no).or_insert(Diagnostic {
         level: Level::Error,
         ty: ErrorType::TypeError,
+        code: codes::UNDEFINED_VARIABLE,
         loc: var.id.loc,
         message: format!("Variable '{}' is undefined", var.id.name),
         notes: vec![],
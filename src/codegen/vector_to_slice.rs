@@ -104,9 +104,15 @@ fn find_writable_vectors(
             | Instr::BranchCond { .. }
             | Instr::Switch { .. }
             | Instr::PopMemory { .. }
+            | Instr::SetMemoryLength { .. }
             | Instr::LoadStorage { .. }
             | Instr::SetStorage { .. }
             | Instr::ClearStorage { .. }
+            | Instr::TakeStorage { .. }
+            | Instr::ContainsStorage { .. }
+            | Instr::LoadTransientStorage { .. }
+            | Instr::SetTransientStorage { .. }
+            | Instr::ClearTransientStorage { .. }
             | Instr::SetStorageBytes { .. }
             | Instr::PushStorage { .. }
             | Instr::PopStorage { .. }
@@ -146,6 +146,29 @@ pub(crate) fn process_builtin(
 
             // Sema will only allow this for EVM. This is a placeholder until correct codegen is in place
             cfg.add(vartab, Instr::Unimplemented { reachable: !matches!(builtin_ty, YulBuiltInFunction::Return | YulBuiltInFunction::Revert | YulBuiltInFunction::Stop) });
+
+            // The returned value must have a type, since it may be assigned to a variable
+            if builtin_ty.get_prototype_info().no_returns == 0 {
+                Expression::Poison
+            } else {
+                Expression::Undefined { ty: Type::Uint(256) }
+            }
+        }
+
+        // Transient storage is accessed the same way as transient state variables are
+        YulBuiltInFunction::TLoad => {
+            let storage = expression(&args[0], contract_no, ns, vartab, cfg, opt).cast(&ns.storage_type(), ns);
+            let res = vartab.temp_anonymous(&Type::Uint(256));
+            cfg.add(vartab, Instr::LoadTransientStorage { res, ty: Type::Uint(256), storage });
+
+            Expression::Variable { loc: *loc, ty: Type::Uint(256), var_no: res }
+        }
+
+        YulBuiltInFunction::TStore => {
+            let storage = expression(&args[0], contract_no, ns, vartab, cfg, opt).cast(&ns.storage_type(), ns);
+            let value = expression(&args[1], contract_no, ns, vartab, cfg, opt).cast(&Type::Uint(256), ns);
+            cfg.add(vartab, Instr::SetTransientStorage { ty: Type::Uint(256), value, storage });
+
             Expression::Poison
         }
 
@@ -2,6 +2,7 @@
 
 use crate::codegen;
 use crate::codegen::cfg::{ControlFlowGraph, Instr, InternalCallTy};
+use crate::codegen::expression::load_storage;
 use crate::codegen::vartable::Vartable;
 use crate::codegen::yul::builtin::process_builtin;
 use crate::codegen::{Builtin, Expression, Options};
@@ -81,6 +82,18 @@ pub(crate) fn expression(
             vartab,
             opt,
         ),
+        ast::YulExpression::ImmutableVariable(loc, ty, var_contract_no, var_no) => {
+            // immutable variables live in contract storage, so read them from their slot
+            let storage = ns.contracts[contract_no].get_storage_slot(
+                *loc,
+                *var_contract_no,
+                *var_no,
+                ns,
+                None,
+            );
+
+            load_storage(loc, ty, storage, cfg, vartab)
+        }
         ast::YulExpression::StorageVariable(..)
         | ast::YulExpression::SolidityLocalVariable(_, _, Some(StorageLocation::Storage(_)), ..) => {
             panic!("Storage variables cannot be accessed without suffixed in yul");
@@ -180,24 +193,37 @@ fn process_suffix_access(
             _ => (),
         },
 
-        YulSuffix::Length => {
-            if let ast::YulExpression::SolidityLocalVariable(
+        YulSuffix::Length => match expr {
+            ast::YulExpression::SolidityLocalVariable(
                 _,
                 Type::Array(_, ref dims),
                 Some(StorageLocation::Calldata(_)),
                 _,
-            ) = expr
-            {
-                if dims.last() == Some(&ArrayLength::Dynamic) {
-                    return Expression::Builtin {
-                        loc: *loc,
-                        tys: vec![Type::Uint(32)],
-                        kind: Builtin::ArrayLength,
-                        args: vec![expression(expr, contract_no, ns, vartab, cfg, opt)],
-                    };
-                }
+            ) if dims.last() == Some(&ArrayLength::Dynamic) => {
+                return Expression::Builtin {
+                    loc: *loc,
+                    tys: vec![Type::Uint(32)],
+                    kind: Builtin::ArrayLength,
+                    args: vec![expression(expr, contract_no, ns, vartab, cfg, opt)],
+                };
             }
-        }
+
+            ast::YulExpression::SolidityLocalVariable(
+                _,
+                ty,
+                Some(StorageLocation::Memory(_)),
+                _,
+            ) if ty.is_dynamic_memory() => {
+                return Expression::Builtin {
+                    loc: *loc,
+                    tys: vec![Type::Uint(32)],
+                    kind: Builtin::ArrayLength,
+                    args: vec![expression(expr, contract_no, ns, vartab, cfg, opt)],
+                };
+            }
+
+            _ => (),
+        },
 
         YulSuffix::Address => {
             if let ast::YulExpression::SolidityLocalVariable(_, Type::ExternalFunction { .. }, ..) =
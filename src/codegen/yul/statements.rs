@@ -1,11 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::codegen::cfg::{ControlFlowGraph, Instr};
+use crate::codegen::expression::{assert_failure, log_runtime_error};
 use crate::codegen::statements::LoopScopes;
 use crate::codegen::vartable::Vartable;
 use crate::codegen::yul::builtin::process_builtin;
 use crate::codegen::yul::expression::{expression, process_function_call};
-use crate::codegen::{Expression, Options};
+use crate::codegen::{Builtin, Expression, Options};
 use crate::sema::ast::{Namespace, RetrieveType, Type};
 use crate::sema::yul::ast;
 use crate::sema::yul::ast::{CaseBlock, YulBlock, YulExpression, YulStatement, YulSuffix};
@@ -193,13 +194,13 @@ fn process_assignment(
         };
 
         for (lhs_no, lhs_item) in lhs.iter().enumerate() {
-            cfg_single_assigment(loc, lhs_item, returns[lhs_no].clone(), ns, cfg, vartab);
+            cfg_single_assigment(loc, lhs_item, returns[lhs_no].clone(), ns, cfg, vartab, opt);
         }
         return;
     }
 
     let codegen_rhs = expression(rhs, contract_no, ns, vartab, cfg, opt);
-    cfg_single_assigment(loc, &lhs[0], codegen_rhs, ns, cfg, vartab);
+    cfg_single_assigment(loc, &lhs[0], codegen_rhs, ns, cfg, vartab, opt);
 }
 
 /// As YUL assignments may contain multiple variables, this function treats one assignment at a time.
@@ -210,6 +211,7 @@ fn cfg_single_assigment(
     ns: &Namespace,
     cfg: &mut ControlFlowGraph,
     vartab: &mut Vartable,
+    opt: &Options,
 ) {
     match lhs {
         ast::YulExpression::YulLocalVariable(_, ty, var_no)
@@ -264,11 +266,20 @@ fn cfg_single_assigment(
                         );
                     }
                     YulSuffix::Length => {
-                        unimplemented!("Assignment to calldata array suffix is not implemented");
+                        set_memory_array_length(loc, *var_no, rhs, ns, cfg, vartab, opt);
                     }
 
                     _ => unreachable!(),
                 },
+                ast::YulExpression::SolidityLocalVariable(
+                    _,
+                    _,
+                    Some(StorageLocation::Memory(_)),
+                    var_no,
+                ) => {
+                    assert_eq!(*suffix, YulSuffix::Length);
+                    set_memory_array_length(loc, *var_no, rhs, ns, cfg, vartab, opt);
+                }
                 ast::YulExpression::SolidityLocalVariable(
                     _,
                     ty @ Type::ExternalFunction { .. },
@@ -336,6 +347,7 @@ fn cfg_single_assigment(
         | ast::YulExpression::StringLiteral(..)
         | ast::YulExpression::SolidityLocalVariable(..)
         | ast::YulExpression::StorageVariable(..)
+        | ast::YulExpression::ImmutableVariable(..)
         | ast::YulExpression::BuiltInCall(..)
         | ast::YulExpression::FunctionCall(..)
         | ast::YulExpression::ConstantVariable(..) => {
@@ -344,6 +356,97 @@ fn cfg_single_assigment(
     }
 }
 
+/// Shrink a memory array by assigning to its length. Growing the array would expose
+/// memory which was never allocated for it, so this aborts if the new length is larger
+/// than the current one.
+fn set_memory_array_length(
+    loc: &pt::Loc,
+    array: usize,
+    length: Expression,
+    ns: &Namespace,
+    cfg: &mut ControlFlowGraph,
+    vartab: &mut Vartable,
+    opt: &Options,
+) {
+    let length_var = vartab.temp_name("length", &Type::Uint(256));
+    let length = length.cast(&Type::Uint(256), ns);
+    cfg.add(
+        vartab,
+        Instr::Set {
+            loc: *loc,
+            res: length_var,
+            expr: length,
+        },
+    );
+    let new_length = Expression::Variable {
+        loc: *loc,
+        ty: Type::Uint(256),
+        var_no: length_var,
+    };
+
+    let array_var = Expression::Variable {
+        loc: *loc,
+        ty: vartab.vars[&array].ty.clone(),
+        var_no: array,
+    };
+    let current_length = Expression::Builtin {
+        loc: *loc,
+        tys: vec![Type::Uint(32)],
+        kind: Builtin::ArrayLength,
+        args: vec![array_var],
+    };
+
+    let too_long = cfg.new_basic_block("length_too_long".to_string());
+    let shrink = cfg.new_basic_block("length_shrink".to_string());
+
+    cfg.add(
+        vartab,
+        Instr::BranchCond {
+            cond: Expression::More {
+                loc: *loc,
+                signed: false,
+                left: Box::new(new_length.clone()),
+                right: Box::new(current_length.cast(&Type::Uint(256), ns)),
+            },
+            true_block: too_long,
+            false_block: shrink,
+        },
+    );
+
+    cfg.set_basic_block(too_long);
+    log_runtime_error(
+        opt.log_runtime_errors,
+        "new array length exceeds current length",
+        *loc,
+        cfg,
+        vartab,
+        ns,
+    );
+    assert_failure(loc, None, ns, cfg, vartab);
+
+    cfg.set_basic_block(shrink);
+    vartab.set_dirty(array);
+    cfg.add(
+        vartab,
+        Instr::SetMemoryLength {
+            array,
+            length: new_length.cast(&Type::Uint(32), ns),
+        },
+    );
+
+    // keep any cached length of this array in sync
+    if let Some(cached_length) = cfg.array_lengths_temps.get(&array).copied() {
+        cfg.add(
+            vartab,
+            Instr::Set {
+                loc: *loc,
+                res: cached_length,
+                expr: new_length.cast(&Type::Uint(32), ns),
+            },
+        );
+    }
+}
+
 /// Add an if statement to the CFG
 fn process_if_block(
     cond: &ast::YulExpression,
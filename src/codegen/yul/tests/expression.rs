@@ -12,7 +12,7 @@ use crate::sema::yul::ast;
 use crate::sema::yul::ast::YulSuffix;
 use crate::{sema, Target};
 use num_bigint::{BigInt, Sign};
-use once_cell::unsync::OnceCell;
+use once_cell::sync::OnceCell;
 use solang_parser::pt::{ContractTy, Loc, StorageLocation, Visibility};
 
 #[test]
@@ -126,6 +126,7 @@ fn contract_constant_variable() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: Some(sema::ast::Expression::NumberLiteral {
             loc,
             ty: Type::Uint(64),
@@ -158,6 +159,7 @@ fn contract_constant_variable() {
         instantiable: true,
         dispatch_no: 0,
         program_id: None,
+        emit_cpi: false,
     };
     ns.contracts.push(contract);
 
@@ -189,6 +191,7 @@ fn global_constant_variable() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: Some(sema::ast::Expression::NumberLiteral {
             loc,
             ty: Type::Uint(64),
@@ -270,6 +273,7 @@ fn slot_suffix() {
         contract_no: 0,
         var_no: 0,
         ty: Type::Uint(256),
+        transient: false,
     };
     let contract = Contract {
         tags: vec![],
@@ -294,6 +298,7 @@ fn slot_suffix() {
         instantiable: true,
         dispatch_no: 0,
         program_id: None,
+        emit_cpi: false,
     };
     ns.contracts.push(contract);
 
@@ -76,7 +76,8 @@ impl<'a> Binary<'a> {
                 substrate::SubstrateTarget::build(context, &std_lib, contract, ns, opt)
             }
             Target::Solana => solana::SolanaTarget::build(context, &std_lib, contract, ns, opt),
-            Target::EVM => unimplemented!(),
+            // Contract::emit() returns early for EVM
+            Target::EVM => unreachable!(),
         }
     }
 
@@ -101,6 +101,18 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             w.vars.get_mut(res).unwrap().value =
                 target.storage_load(bin, ty, &mut slot, function, ns);
         }
+        Instr::TakeStorage { res, ty, storage } => {
+            let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
+
+            w.vars.get_mut(res).unwrap().value =
+                target.storage_take(bin, ty, &mut slot, function, ns);
+        }
+        Instr::ContainsStorage { res, storage } => {
+            let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
+
+            w.vars.get_mut(res).unwrap().value =
+                target.storage_contains(bin, slot, function).into();
+        }
         Instr::ClearStorage { ty, storage } => {
             let mut slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
 
@@ -113,6 +125,24 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
 
             target.storage_store(bin, ty, true, &mut slot, value, function, ns);
         }
+        Instr::LoadTransientStorage { res, ty, storage } => {
+            let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
+
+            w.vars.get_mut(res).unwrap().value =
+                target.transient_storage_load(bin, ty, slot, function, ns);
+        }
+        Instr::ClearTransientStorage { ty, storage } => {
+            let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
+
+            target.transient_storage_delete(bin, ty, slot, function, ns);
+        }
+        Instr::SetTransientStorage { ty, value, storage } => {
+            let value = expression(target, bin, value, &w.vars, function, ns);
+
+            let slot = expression(target, bin, storage, &w.vars, function, ns).into_int_value();
+
+            target.transient_storage_store(bin, ty, slot, value, function, ns);
+        }
         Instr::SetStorageBytes {
             storage,
             value,
@@ -403,6 +433,50 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             };
             bin.builder.build_store(size_ptr, new_len);
         }
+        Instr::SetMemoryLength { array, length } => {
+            let a = w.vars[array].value.into_pointer_value();
+            let new_len = expression(target, bin, length, &w.vars, function, ns).into_int_value();
+
+            // An empty vector may be a null pointer; codegen ensures its length stays zero
+            let is_null = bin.builder.build_is_null(a, "vector_is_null");
+            let set_length = bin.context.append_basic_block(function, "set_length");
+            let done = bin.context.append_basic_block(function, "set_length_done");
+            bin.builder
+                .build_conditional_branch(is_null, done, set_length);
+
+            bin.builder.position_at_end(set_length);
+            let vector_ty = bin.module.get_struct_type("struct.vector").unwrap();
+
+            // Update the len and size field of the vector struct
+            let len_ptr = unsafe {
+                bin.builder.build_gep(
+                    vector_ty,
+                    a,
+                    &[
+                        bin.context.i32_type().const_zero(),
+                        bin.context.i32_type().const_zero(),
+                    ],
+                    "len",
+                )
+            };
+            bin.builder.build_store(len_ptr, new_len);
+
+            let size_ptr = unsafe {
+                bin.builder.build_gep(
+                    vector_ty,
+                    a,
+                    &[
+                        bin.context.i32_type().const_zero(),
+                        bin.context.i32_type().const_int(1, false),
+                    ],
+                    "size",
+                )
+            };
+            bin.builder.build_store(size_ptr, new_len);
+            bin.builder.build_unconditional_branch(done);
+
+            bin.builder.position_at_end(done);
+        }
         Instr::AssertFailure { encoded_args: None } => {
             target.assert_failure(
                 bin,
@@ -528,7 +602,7 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
                 }
             }
 
-            let first_arg_type = bin.llvm_type(&args[0].ty(), ns);
+            let first_arg_type = args.first().map(|arg| bin.llvm_type(&arg.ty(), ns));
             if let Some(ret) =
                 target.builtin_function(bin, function, callee, &parms, first_arg_type, ns)
             {
@@ -591,7 +665,7 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             {
                 (bin.function_type(params, returns, ns), returns)
             } else {
-                panic!("should be Type::InternalFunction type");
+                unreachable!("should be Type::InternalFunction type");
             };
 
             let mut parms = args
@@ -1102,7 +1176,8 @@ pub(super) fn process_instruction<'a, T: TargetRuntime<'a> + ?Sized>(
             target.return_code(bin, bin.return_values[code]);
         }
 
-        Instr::Unimplemented { .. } => unimplemented!(),
+        // Only generated for EVM, which has no emitter
+        Instr::Unimplemented { .. } => unreachable!(),
     }
 }
 
@@ -106,6 +106,62 @@ pub trait TargetRuntime<'a> {
         ns: &Namespace,
     );
 
+    /// Load a value type from storage and clear it. The default implementation loads and
+    /// then deletes the value
+    fn storage_take(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: &mut IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a> {
+        let mut delete_slot = *slot;
+        let value = self.storage_load(bin, ty, slot, function, ns);
+        self.storage_delete(bin, ty, &mut delete_slot, function, ns);
+
+        value
+    }
+
+    /// Check whether storage at the slot holds a value, without loading it
+    fn storage_contains(
+        &self,
+        bin: &Binary<'a>,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+    ) -> IntValue<'a>;
+
+    /// Load a value type from transient storage
+    fn transient_storage_load(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a>;
+
+    /// Store a value type to transient storage
+    fn transient_storage_store(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        dest: BasicValueEnum<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    );
+
+    /// Clear a slot in transient storage
+    fn transient_storage_delete(
+        &self,
+        bin: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    );
+
     // Bytes and string have special storage layout
     fn set_storage_string(
         &self,
@@ -239,7 +295,7 @@ pub trait TargetRuntime<'a> {
         function: FunctionValue<'a>,
         builtin_func: &Function,
         args: &[BasicMetadataValueEnum<'a>],
-        first_arg_type: BasicTypeEnum,
+        first_arg_type: Option<BasicTypeEnum>,
         ns: &Namespace,
     ) -> Option<BasicValueEnum<'a>>;
 
@@ -21,7 +21,10 @@ use crate::emit::functions::emit_functions;
 use crate::emit::loop_builder::LoopBuilder;
 use crate::emit::{Binary, ContractArgs, TargetRuntime};
 
-pub struct SolanaTarget();
+pub struct SolanaTarget {
+    /// The size of the transient storage of the contract
+    transient_size: u64,
+}
 
 // Implement the Solana target which uses BPF
 impl SolanaTarget {
@@ -32,7 +35,17 @@ impl SolanaTarget {
         ns: &'a ast::Namespace,
         opt: &'a Options,
     ) -> Binary<'a> {
-        let mut target = SolanaTarget();
+        // transient variables are laid out one after the other, so the last one ends the
+        // transient storage
+        let transient_size = contract
+            .layout
+            .iter()
+            .filter(|layout| layout.transient)
+            .map(|layout| layout.slot.clone() + layout.ty.storage_slots(ns))
+            .max()
+            .map_or(0, |size| size.to_u64().unwrap());
+
+        let mut target = SolanaTarget { transient_size };
         let filename = ns.files[contract.loc.file_no()].file_name();
         let mut binary = Binary::new(
             context,
@@ -67,6 +80,18 @@ impl SolanaTarget {
             ReturnCode::AccountDataTooSmall,
             context.i64_type().const_int(5u64 << 32, false),
         );
+        binary.return_values.insert(
+            ReturnCode::InvalidAccountData,
+            context.i64_type().const_int(4u64 << 32, false),
+        );
+        binary.return_values.insert(
+            ReturnCode::MissingRequiredSignature,
+            context.i64_type().const_int(8u64 << 32, false),
+        );
+        binary.return_values.insert(
+            ReturnCode::NotEnoughAccountKeys,
+            context.i64_type().const_int(11u64 << 32, false),
+        );
         // externals
         target.declare_externals(&mut binary, ns);
 
@@ -85,6 +110,9 @@ impl SolanaTarget {
             "sol_sha256",
             "sol_keccak256",
             "sol_log_data",
+            "sol_get_clock_sysvar",
+            "sol_get_rent_sysvar",
+            "sol_get_epoch_schedule_sysvar",
         ]);
 
         binary
@@ -188,6 +216,20 @@ impl SolanaTarget {
             .as_global_value()
             .set_unnamed_address(UnnamedAddress::Local);
 
+        for name in [
+            "sol_get_clock_sysvar",
+            "sol_get_rent_sysvar",
+            "sol_get_epoch_schedule_sysvar",
+        ] {
+            let function =
+                binary
+                    .module
+                    .add_function(name, u64_ty.fn_type(&[u8_ptr.into()], false), None);
+            function
+                .as_global_value()
+                .set_unnamed_address(UnnamedAddress::Local);
+        }
+
         let function = binary.module.add_function(
             "sol_create_program_address",
             u64_ty.fn_type(
@@ -282,6 +324,96 @@ impl SolanaTarget {
             .into_pointer_value()
     }
 
+    /// Returns the transient storage of the executing binary, which is allocated on the heap
+    /// when it is first used
+    fn transient_storage_data<'b>(&self, binary: &Binary<'b>) -> PointerValue<'b> {
+        let parameters = self.sol_parameters(binary);
+
+        binary
+            .builder
+            .build_call(
+                binary.module.get_function("transient_storage").unwrap(),
+                &[
+                    parameters.into(),
+                    binary
+                        .context
+                        .i32_type()
+                        .const_int(self.transient_size, false)
+                        .into(),
+                ],
+                "transient",
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_pointer_value()
+    }
+
+    /// Returns a pointer to a value of type `ty` at `slot` in transient storage. tload() and
+    /// tstore() in inline assembly can use any slot, so it is checked against the size of the
+    /// transient storage
+    fn transient_storage_member<'b>(
+        &self,
+        binary: &Binary<'b>,
+        function: FunctionValue<'b>,
+        ty: BasicTypeEnum<'b>,
+        slot: IntValue<'b>,
+        ns: &ast::Namespace,
+    ) -> PointerValue<'b> {
+        let end = binary.builder.build_int_add(
+            binary
+                .builder
+                .build_int_z_extend(slot, binary.context.i64_type(), "slot"),
+            ty.size_of().unwrap(),
+            "end",
+        );
+
+        let in_range = binary.builder.build_int_compare(
+            IntPredicate::ULE,
+            end,
+            binary
+                .context
+                .i64_type()
+                .const_int(self.transient_size, false),
+            "in_range",
+        );
+
+        let in_range_block = binary.context.append_basic_block(function, "in_range");
+        let bang_block = binary.context.append_basic_block(function, "bang_block");
+
+        binary
+            .builder
+            .build_conditional_branch(in_range, in_range_block, bang_block);
+
+        binary.builder.position_at_end(bang_block);
+
+        self.log_runtime_error(
+            binary,
+            "transient storage slot out of bounds".to_string(),
+            None,
+            ns,
+        );
+        self.assert_failure(
+            binary,
+            binary
+                .context
+                .i8_type()
+                .ptr_type(AddressSpace::default())
+                .const_null(),
+            binary.context.i32_type().const_zero(),
+        );
+
+        binary.builder.position_at_end(in_range_block);
+
+        let data = self.transient_storage_data(binary);
+
+        unsafe {
+            binary
+                .builder
+                .build_gep(binary.context.i8_type(), data, &[slot], "transient")
+        }
+    }
+
     /// Free binary storage and zero out
     fn storage_free<'b>(
         &self,
@@ -741,14 +873,14 @@ impl SolanaTarget {
             .unwrap()
             .const_cast(binary.context.i32_type(), false);
 
-        let account = self.contract_storage_account(binary);
+        let parameters = self.sol_parameters(binary);
 
         // account_data_alloc will return offset = 0 if the string is length 0
         let rc = binary
             .builder
             .build_call(
                 binary.module.get_function("account_data_alloc").unwrap(),
-                &[account.into(), entry_length.into(), offset_ptr.into()],
+                &[parameters.into(), entry_length.into(), offset_ptr.into()],
                 "rc",
             )
             .try_as_basic_value()
@@ -818,7 +950,11 @@ impl SolanaTarget {
                 .builder
                 .build_call(
                     binary.module.get_function("account_data_alloc").unwrap(),
-                    &[account.into(), new_string_length.into(), offset_ptr.into()],
+                    &[
+                        parameters.into(),
+                        new_string_length.into(),
+                        offset_ptr.into(),
+                    ],
                     "alloc",
                 )
                 .try_as_basic_value()
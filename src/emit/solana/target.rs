@@ -35,6 +35,58 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         self.storage_free(binary, ty, data, *slot, function, true, ns);
     }
 
+    fn storage_contains(
+        &self,
+        _binary: &Binary<'a>,
+        _slot: IntValue<'a>,
+        _function: FunctionValue<'a>,
+    ) -> IntValue<'a> {
+        // contains_storage is only available on Substrate
+        unreachable!();
+    }
+
+    fn transient_storage_load(
+        &self,
+        binary: &Binary<'a>,
+        ty: &ast::Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &ast::Namespace,
+    ) -> BasicValueEnum<'a> {
+        let llvm_ty = binary.llvm_var_ty(ty, ns);
+        let member = self.transient_storage_member(binary, function, llvm_ty, slot, ns);
+
+        binary.builder.build_load(llvm_ty, member, "")
+    }
+
+    fn transient_storage_store(
+        &self,
+        binary: &Binary<'a>,
+        ty: &ast::Type,
+        slot: IntValue<'a>,
+        dest: BasicValueEnum<'a>,
+        function: FunctionValue<'a>,
+        ns: &ast::Namespace,
+    ) {
+        let member =
+            self.transient_storage_member(binary, function, binary.llvm_var_ty(ty, ns), slot, ns);
+
+        binary.builder.build_store(member, dest);
+    }
+
+    fn transient_storage_delete(
+        &self,
+        binary: &Binary<'a>,
+        ty: &ast::Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &ast::Namespace,
+    ) {
+        let data = self.transient_storage_data(binary);
+
+        self.storage_free(binary, ty, data, slot, function, true, ns);
+    }
+
     fn set_storage_extfunc(
         &self,
         _binary: &Binary,
@@ -43,7 +95,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         _dest: PointerValue,
         _dest_ty: BasicTypeEnum,
     ) {
-        unimplemented!();
+        // unused, external functions are stored by storage_store()
+        unreachable!();
     }
     fn get_storage_extfunc(
         &self,
@@ -52,7 +105,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         _slot: PointerValue<'a>,
         _ns: &ast::Namespace,
     ) -> PointerValue<'a> {
-        unimplemented!();
+        // unused, external functions are loaded by storage_load()
+        unreachable!();
     }
 
     fn set_storage_string(
@@ -319,7 +373,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         ns: &ast::Namespace,
     ) -> BasicValueEnum<'a> {
         let data = self.contract_storage_data(binary);
-        let account = self.contract_storage_account(binary);
+        let parameters = self.sol_parameters(binary);
 
         let member = unsafe {
             binary
@@ -357,7 +411,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
             .build_call(
                 binary.module.get_function("account_data_realloc").unwrap(),
                 &[
-                    account.into(),
+                    parameters.into(),
                     offset.into(),
                     new_length.into(),
                     member.into(),
@@ -425,7 +479,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         loc: Loc,
     ) -> Option<BasicValueEnum<'a>> {
         let data = self.contract_storage_data(binary);
-        let account = self.contract_storage_account(binary);
+        let parameters = self.sol_parameters(binary);
 
         let member = unsafe {
             binary
@@ -508,7 +562,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         binary.builder.build_call(
             binary.module.get_function("account_data_realloc").unwrap(),
             &[
-                account.into(),
+                parameters.into(),
                 offset.into(),
                 new_length.into(),
                 member.into(),
@@ -799,6 +853,66 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
 
                 dest.into()
             }
+            ast::Type::ExternalFunction { .. } => {
+                // The selector is followed by the program id in storage
+                let llvm_ty = binary.llvm_type(ty, ns);
+                // LLVMSizeOf() produces an i64
+                let size = binary.builder.build_int_truncate(
+                    llvm_ty.size_of().unwrap(),
+                    binary.context.i32_type(),
+                    "size_of",
+                );
+
+                let new = binary
+                    .builder
+                    .build_call(
+                        binary.module.get_function("__malloc").unwrap(),
+                        &[size.into()],
+                        "",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_pointer_value();
+
+                let selector_ty = binary.llvm_type(&ast::Type::FunctionSelector, ns);
+                let selector = binary.builder.build_load(selector_ty, member, "selector");
+
+                let address_offset = binary.builder.build_int_add(
+                    *slot,
+                    binary
+                        .context
+                        .i32_type()
+                        .const_int(ns.target.selector_length() as u64, false),
+                    "address_offset",
+                );
+                let address_member = unsafe {
+                    binary.builder.build_gep(
+                        binary.context.i8_type(),
+                        data,
+                        &[address_offset],
+                        "address_member",
+                    )
+                };
+                let address_ty = binary.llvm_type(&ast::Type::Address(false), ns);
+                let address = binary
+                    .builder
+                    .build_load(address_ty, address_member, "address");
+
+                let selector_ptr = binary
+                    .builder
+                    .build_struct_gep(llvm_ty, new, 0, "selector_ptr")
+                    .unwrap();
+                binary.builder.build_store(selector_ptr, selector);
+
+                let address_ptr = binary
+                    .builder
+                    .build_struct_gep(llvm_ty, new, 1, "address_ptr")
+                    .unwrap();
+                binary.builder.build_store(address_ptr, address);
+
+                new.into()
+            }
             _ => binary
                 .builder
                 .build_load(binary.llvm_var_ty(ty, ns), member, ""),
@@ -816,7 +930,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         ns: &ast::Namespace,
     ) {
         let data = self.contract_storage_data(binary);
-        let account = self.contract_storage_account(binary);
+        let parameters = self.sol_parameters(binary);
 
         // the slot is simply the offset after the magic
         let member = unsafe {
@@ -878,7 +992,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     .builder
                     .build_call(
                         binary.module.get_function("account_data_alloc").unwrap(),
-                        &[account.into(), new_string_length.into(), member.into()],
+                        &[parameters.into(), new_string_length.into(), member.into()],
                         "alloc",
                     )
                     .try_as_basic_value()
@@ -931,7 +1045,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     .builder
                     .build_call(
                         binary.module.get_function("account_data_alloc").unwrap(),
-                        &[account.into(), new_string_length.into(), member.into()],
+                        &[parameters.into(), new_string_length.into(), member.into()],
                         "alloc",
                     )
                     .try_as_basic_value()
@@ -1022,7 +1136,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     .build_call(
                         binary.module.get_function("account_data_realloc").unwrap(),
                         &[
-                            account.into(),
+                            parameters.into(),
                             offset.into(),
                             new_length.into(),
                             member.into(),
@@ -1167,6 +1281,46 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     ns,
                 );
             }
+        } else if let ast::Type::ExternalFunction { .. } = ty {
+            // Store the selector followed by the program id
+            let llvm_ty = binary.llvm_type(ty, ns);
+
+            let selector_ptr = binary
+                .builder
+                .build_struct_gep(llvm_ty, val.into_pointer_value(), 0, "selector_ptr")
+                .unwrap();
+            let selector_ty = binary.llvm_type(&ast::Type::FunctionSelector, ns);
+            let selector = binary
+                .builder
+                .build_load(selector_ty, selector_ptr, "selector");
+            binary.builder.build_store(member, selector);
+
+            let address_ptr = binary
+                .builder
+                .build_struct_gep(llvm_ty, val.into_pointer_value(), 1, "address_ptr")
+                .unwrap();
+            let address_ty = binary.llvm_type(&ast::Type::Address(false), ns);
+            let address = binary
+                .builder
+                .build_load(address_ty, address_ptr, "address");
+
+            let address_offset = binary.builder.build_int_add(
+                *offset,
+                binary
+                    .context
+                    .i32_type()
+                    .const_int(ns.target.selector_length() as u64, false),
+                "address_offset",
+            );
+            let address_member = unsafe {
+                binary.builder.build_gep(
+                    binary.context.i8_type(),
+                    data,
+                    &[address_offset],
+                    "address_member",
+                )
+            };
+            binary.builder.build_store(address_member, address);
         } else {
             binary.builder.build_store(member, val);
         }
@@ -1278,7 +1432,7 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
         function: FunctionValue<'a>,
         builtin_func: &ast::Function,
         args: &[BasicMetadataValueEnum<'a>],
-        first_arg_type: BasicTypeEnum,
+        first_arg_type: Option<BasicTypeEnum>,
         ns: &ast::Namespace,
     ) -> Option<BasicValueEnum<'a>> {
         if builtin_func.name == "create_program_address" {
@@ -1287,10 +1441,10 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                 .get_function("sol_create_program_address")
                 .unwrap();
 
-            let seed_count = binary
-                .context
-                .i64_type()
-                .const_int(first_arg_type.into_array_type().len() as u64, false);
+            let seed_count = binary.context.i64_type().const_int(
+                first_arg_type.unwrap().into_array_type().len() as u64,
+                false,
+            );
 
             // address
             let address = binary.build_alloca(function, binary.address_type(ns), "address");
@@ -1321,10 +1475,10 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                 .get_function("sol_try_find_program_address")
                 .unwrap();
 
-            let seed_count = binary
-                .context
-                .i64_type()
-                .const_int(first_arg_type.into_array_type().len() as u64, false);
+            let seed_count = binary.context.i64_type().const_int(
+                first_arg_type.unwrap().into_array_type().len() as u64,
+                false,
+            );
 
             // address
             let address = binary.build_alloca(function, binary.address_type(ns), "address");
@@ -1536,7 +1690,8 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
 
     /// Terminate execution, destroy binary and send remaining funds to addr
     fn selfdestruct<'b>(&self, _binary: &Binary<'b>, _addr: ArrayValue<'b>, _ns: &ast::Namespace) {
-        unimplemented!();
+        // sema does not allow selfdestruct on Solana
+        unreachable!();
     }
 
     /// Emit event
@@ -1901,6 +2056,128 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     )
                     .into()
             }
+            codegen::Expression::Builtin {
+                kind: kind @ (codegen::Builtin::GetClock | codegen::Builtin::GetEpochSchedule),
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 0);
+
+                let (struct_ty, get_sysvar) = if *kind == codegen::Builtin::GetClock {
+                    (ast::StructType::Clock, "sol_get_clock_sysvar")
+                } else {
+                    (
+                        ast::StructType::EpochSchedule,
+                        "sol_get_epoch_schedule_sysvar",
+                    )
+                };
+
+                let llvm_ty = binary.llvm_type(&ast::Type::Struct(struct_ty), ns);
+
+                let sysvar = binary
+                    .builder
+                    .build_call(
+                        binary.module.get_function("__malloc").unwrap(),
+                        &[llvm_ty
+                            .size_of()
+                            .unwrap()
+                            .const_cast(binary.context.i32_type(), false)
+                            .into()],
+                        "sysvar",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_pointer_value();
+
+                // The sysvar syscalls copy the #[repr(C)] struct, which matches our layout
+                binary.builder.build_call(
+                    binary.module.get_function(get_sysvar).unwrap(),
+                    &[sysvar.into()],
+                    "",
+                );
+
+                sysvar.into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::RentMinimumBalance,
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 1);
+
+                let space = expression(self, binary, &args[0], vartab, function, ns);
+
+                binary
+                    .builder
+                    .build_call(
+                        binary
+                            .module
+                            .get_function("sol_rent_minimum_balance")
+                            .unwrap(),
+                        &[space.into()],
+                        "minimum_balance",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::InstructionsCurrentIndex,
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 0);
+
+                let parameters = self.sol_parameters(binary);
+
+                let index = binary
+                    .builder
+                    .build_call(
+                        binary
+                            .module
+                            .get_function("sol_instructions_current_index")
+                            .unwrap(),
+                        &[parameters.into()],
+                        "index",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                binary
+                    .builder
+                    .build_int_truncate(index, binary.context.i16_type(), "index")
+                    .into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::InstructionsLoad,
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 1);
+
+                let index =
+                    expression(self, binary, &args[0], vartab, function, ns).into_int_value();
+                let index =
+                    binary
+                        .builder
+                        .build_int_z_extend(index, binary.context.i64_type(), "index");
+
+                let parameters = self.sol_parameters(binary);
+
+                binary
+                    .builder
+                    .build_call(
+                        binary.module.get_function("sol_instructions_load").unwrap(),
+                        &[index.into(), parameters.into()],
+                        "instruction",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+            }
             codegen::Expression::Builtin {
                 kind: codegen::Builtin::Balance,
                 args,
@@ -1989,13 +2266,144 @@ impl<'a> TargetRuntime<'a> for SolanaTarget {
                     .build_int_truncate(ka_num, binary.context.i32_type(), "ka_num_32bits")
                     .into()
             }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::AccountRealloc,
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 2);
+
+                let account_info =
+                    expression(self, binary, &args[0], vartab, function, ns).into_pointer_value();
+                let new_len = expression(self, binary, &args[1], vartab, function, ns);
+
+                let parameters = self.sol_parameters(binary);
+
+                let rc = binary
+                    .builder
+                    .build_call(
+                        binary.module.get_function("sol_account_realloc").unwrap(),
+                        &[account_info.into(), new_len.into(), parameters.into()],
+                        "rc",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                let is_rc_zero = binary.builder.build_int_compare(
+                    IntPredicate::EQ,
+                    rc,
+                    binary.context.i64_type().const_zero(),
+                    "is_rc_zero",
+                );
+
+                let rc_not_zero = binary.context.append_basic_block(function, "rc_not_zero");
+                let rc_zero = binary.context.append_basic_block(function, "rc_zero");
+
+                binary
+                    .builder
+                    .build_conditional_branch(is_rc_zero, rc_zero, rc_not_zero);
+
+                binary.builder.position_at_end(rc_not_zero);
+
+                self.return_code(binary, rc);
+
+                binary.builder.position_at_end(rc_zero);
+
+                rc.into()
+            }
+            codegen::Expression::Builtin {
+                kind: codegen::Builtin::DataAccountPayer,
+                args,
+                ..
+            } => {
+                assert_eq!(args.len(), 0);
+
+                let parameters = self.sol_parameters(binary);
+
+                binary
+                    .builder
+                    .build_struct_gep(
+                        binary
+                            .module
+                            .get_struct_type("struct.SolParameters")
+                            .unwrap(),
+                        parameters,
+                        7,
+                        "ka_payer",
+                    )
+                    .unwrap()
+                    .into()
+            }
+            codegen::Expression::Builtin {
+                kind:
+                    kind @ (codegen::Builtin::EmitCpiEvent | codegen::Builtin::EventAuthorityCheck),
+                args,
+                ..
+            } => {
+                let parameters = self.sol_parameters(binary);
+
+                let rc = if *kind == codegen::Builtin::EmitCpiEvent {
+                    assert_eq!(args.len(), 1);
+
+                    let data = expression(self, binary, &args[0], vartab, function, ns);
+
+                    binary.builder.build_call(
+                        binary.module.get_function("sol_emit_cpi_event").unwrap(),
+                        &[
+                            binary.vector_bytes(data).into(),
+                            binary.vector_len(data).into(),
+                            parameters.into(),
+                        ],
+                        "rc",
+                    )
+                } else {
+                    assert_eq!(args.len(), 0);
+
+                    binary.builder.build_call(
+                        binary
+                            .module
+                            .get_function("sol_event_authority_check")
+                            .unwrap(),
+                        &[parameters.into()],
+                        "rc",
+                    )
+                }
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+                let is_rc_zero = binary.builder.build_int_compare(
+                    IntPredicate::EQ,
+                    rc,
+                    binary.context.i64_type().const_zero(),
+                    "is_rc_zero",
+                );
+
+                let rc_not_zero = binary.context.append_basic_block(function, "rc_not_zero");
+                let rc_zero = binary.context.append_basic_block(function, "rc_zero");
+
+                binary
+                    .builder
+                    .build_conditional_branch(is_rc_zero, rc_zero, rc_not_zero);
+
+                binary.builder.position_at_end(rc_not_zero);
+
+                self.return_code(binary, rc);
+
+                binary.builder.position_at_end(rc_zero);
+
+                rc.into()
+            }
             codegen::Expression::StructMember { expr, member, .. } => {
                 let account_info =
                     expression(self, binary, expr, vartab, function, ns).into_pointer_value();
 
                 self.account_info_member(binary, function, account_info, *member, ns)
             }
-            _ => unimplemented!(),
+            _ => unreachable!("{:?} is not available on Solana", expr),
         }
     }
 
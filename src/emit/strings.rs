@@ -87,7 +87,8 @@ pub(super) fn format_string<'a, T: TargetRuntime<'a> + ?Sized>(
                     .context
                     .i32_type()
                     .const_int(ns.enums[enum_no].ty.bits(ns) as u64 / 3, false),
-                _ => unimplemented!(),
+                // sema rejects any other type as format argument
+                _ => unreachable!(),
             }
         };
 
@@ -518,7 +519,7 @@ pub(super) fn format_string<'a, T: TargetRuntime<'a> + ?Sized>(
                             .into_pointer_value();
                     }
                 }
-                _ => unimplemented!(),
+                _ => unreachable!(),
             }
         }
     }
@@ -2,10 +2,11 @@
 
 use crate::codegen::Options;
 use crate::sema::ast::{Contract, Namespace};
+use crate::{SubstrateApiVersion, Target};
 use inkwell::context::Context;
 use inkwell::module::{Linkage, Module};
 use inkwell::values::{BasicMetadataValueEnum, FunctionValue, IntValue, PointerValue};
-use inkwell::AddressSpace;
+use inkwell::{AddressSpace, IntPredicate};
 
 use crate::emit::functions::{emit_functions, emit_initializer};
 use crate::emit::{Binary, TargetRuntime};
@@ -139,7 +140,7 @@ impl SubstrateTarget {
 
         let mut target = SubstrateTarget;
 
-        target.declare_externals(&binary);
+        target.declare_externals(&binary, ns);
 
         emit_functions(&mut target, &mut binary, contract, ns);
 
@@ -155,6 +156,11 @@ impl SubstrateTarget {
             "set_storage",
             "get_storage",
             "clear_storage",
+            "contains_storage",
+            "take_storage",
+            "set_transient_storage",
+            "get_transient_storage",
+            "clear_transient_storage",
             "hash_keccak_256",
             "hash_sha2_256",
             "hash_blake2_128",
@@ -177,6 +183,19 @@ impl SubstrateTarget {
             "deposit_event",
             "transfer",
             "is_contract",
+            "code_hash",
+            "delegate_call",
+            "set_code_hash",
+            "ecdsa_recover",
+            "ecdsa_to_eth_address",
+            "sr25519_verify",
+            "call_runtime",
+            "xcm_execute",
+            "xcm_send",
+            "caller_is_origin",
+            "caller_is_root",
+            "reentrance_count",
+            "account_reentrance_count",
         ]);
 
         binary
@@ -228,7 +247,7 @@ impl SubstrateTarget {
         (scratch_buf, args_length.into_int_value())
     }
 
-    fn declare_externals(&self, binary: &Binary) {
+    fn declare_externals(&self, binary: &Binary, ns: &Namespace) {
         let ctx = binary.context;
         let u8_ptr = ctx.i8_type().ptr_type(AddressSpace::default()).into();
         let u32_val = ctx.i32_type().into();
@@ -263,37 +282,94 @@ impl SubstrateTarget {
         external!("set_storage", i32_type, u8_ptr, u32_val, u8_ptr, u32_val);
         external!("debug_message", i32_type, u8_ptr, u32_val);
         external!("clear_storage", i32_type, u8_ptr, u32_val);
+        external!("contains_storage", i32_type, u8_ptr, u32_val);
+        external!("take_storage", i32_type, u8_ptr, u32_val, u8_ptr, u32_ptr);
         external!("get_storage", i32_type, u8_ptr, u32_val, u8_ptr, u32_ptr);
-        external!("seal_return", void_type, u32_val, u8_ptr, u32_val);
         external!(
-            "instantiate",
+            "set_transient_storage",
             i32_type,
             u8_ptr,
-            u64_val,
-            u8_ptr,
-            u8_ptr,
             u32_val,
             u8_ptr,
-            u32_ptr,
-            u8_ptr,
-            u32_ptr,
-            u8_ptr,
             u32_val
         );
-        // We still use prefixed seal_call because it would collide with the exported call function.
-        // TODO: Refactor emit to use a dedicated module for the externals to avoid any collisions.
+        external!("clear_transient_storage", i32_type, u8_ptr, u32_val);
         external!(
-            "seal_call",
+            "get_transient_storage",
             i32_type,
-            u32_val,
-            u8_ptr,
-            u64_val,
-            u8_ptr,
             u8_ptr,
             u32_val,
             u8_ptr,
             u32_ptr
         );
+        external!("seal_return", void_type, u32_val, u8_ptr, u32_val);
+        // We still use prefixed seal_call because it would collide with the exported call function.
+        // The linker imports it under the name of the selected API version.
+        // TODO: Refactor emit to use a dedicated module for the externals to avoid any collisions.
+        match api_version(ns) {
+            SubstrateApiVersion::V1 => {
+                external!(
+                    "instantiate",
+                    i32_type,
+                    u8_ptr,
+                    u64_val,
+                    u8_ptr,
+                    u8_ptr,
+                    u32_val,
+                    u8_ptr,
+                    u32_ptr,
+                    u8_ptr,
+                    u32_ptr,
+                    u8_ptr,
+                    u32_val
+                );
+                external!(
+                    "seal_call",
+                    i32_type,
+                    u32_val,
+                    u8_ptr,
+                    u64_val,
+                    u8_ptr,
+                    u8_ptr,
+                    u32_val,
+                    u8_ptr,
+                    u32_ptr
+                );
+            }
+            SubstrateApiVersion::V2 => {
+                external!(
+                    "instantiate",
+                    i32_type,
+                    u8_ptr,
+                    u64_val,
+                    u64_val,
+                    u8_ptr,
+                    u8_ptr,
+                    u8_ptr,
+                    u32_val,
+                    u8_ptr,
+                    u32_ptr,
+                    u8_ptr,
+                    u32_ptr,
+                    u8_ptr,
+                    u32_val
+                );
+                external!(
+                    "seal_call",
+                    i32_type,
+                    u32_val,
+                    u8_ptr,
+                    u64_val,
+                    u64_val,
+                    u8_ptr,
+                    u8_ptr,
+                    u8_ptr,
+                    u32_val,
+                    u8_ptr,
+                    u32_ptr
+                );
+            }
+        }
         external!("transfer", i32_type, u8_ptr, u32_val, u8_ptr, u32_val);
         external!("value_transferred", void_type, u8_ptr, u32_ptr);
         external!("address", void_type, u8_ptr, u32_ptr);
@@ -307,6 +383,28 @@ impl SubstrateTarget {
         external!("terminate", void_type, u8_ptr);
         external!("deposit_event", void_type, u8_ptr, u32_val, u8_ptr, u32_val);
         external!("is_contract", i32_type, u8_ptr);
+        external!("code_hash", i32_type, u8_ptr, u8_ptr, u32_ptr);
+        external!(
+            "delegate_call",
+            i32_type,
+            u32_val,
+            u8_ptr,
+            u8_ptr,
+            u32_val,
+            u8_ptr,
+            u32_ptr
+        );
+        external!("set_code_hash", i32_type, u8_ptr);
+        external!("ecdsa_recover", i32_type, u8_ptr, u8_ptr, u8_ptr);
+        external!("ecdsa_to_eth_address", i32_type, u8_ptr, u8_ptr);
+        external!("sr25519_verify", i32_type, u8_ptr, u8_ptr, u32_val, u8_ptr);
+        external!("call_runtime", i32_type, u8_ptr, u32_val);
+        external!("xcm_execute", i32_type, u8_ptr, u32_val);
+        external!("xcm_send", i32_type, u8_ptr, u8_ptr, u32_val, u8_ptr);
+        external!("caller_is_origin", i32_type,);
+        external!("caller_is_root", i32_type,);
+        external!("reentrance_count", i32_type,);
+        external!("account_reentrance_count", i32_type, u8_ptr);
     }
 
     /// Emits the "deploy" function if `init` is `Some`, otherwise emits the "call" function.
@@ -328,6 +426,78 @@ impl SubstrateTarget {
         bin.builder.build_call(func, &args, "substrate_dispatch");
         bin.builder.build_unreachable();
     }
+
+    /// Revert with the given `reason` unless the `bytes` vector is exactly `length` bytes long.
+    fn assert_vector_length<'a>(
+        &self,
+        binary: &Binary<'a>,
+        function: FunctionValue<'a>,
+        vector: PointerValue<'a>,
+        length: u64,
+        reason: &str,
+        ns: &Namespace,
+    ) {
+        emit_context!(binary);
+
+        let is_valid = binary.builder.build_int_compare(
+            IntPredicate::EQ,
+            binary.vector_len(vector.into()),
+            i32_const!(length),
+            "is_valid_length",
+        );
+        let valid_block = binary.context.append_basic_block(function, "valid_length");
+        let invalid_block = binary
+            .context
+            .append_basic_block(function, "invalid_length");
+        binary
+            .builder
+            .build_conditional_branch(is_valid, valid_block, invalid_block);
+
+        binary.builder.position_at_end(invalid_block);
+        self.log_runtime_error(binary, reason.to_string(), None, ns);
+        self.assert_failure(binary, byte_ptr!().const_null(), i32_zero!());
+
+        binary.builder.position_at_end(valid_block);
+    }
+
+    /// Write a `bytesN` value to memory in big endian byte order, which is how the host functions
+    /// expect hashes and keys. Returns the pointer to the written bytes.
+    fn bytes_to_big_endian<'a>(
+        &self,
+        binary: &Binary<'a>,
+        value: IntValue<'a>,
+    ) -> PointerValue<'a> {
+        emit_context!(binary);
+
+        let len = value.get_type().get_bit_width() / 8;
+        let le = binary.builder.build_alloca(value.get_type(), "le");
+        binary.builder.build_store(le, value);
+        let be = binary.builder.build_alloca(value.get_type(), "be");
+        call!(
+            "__leNtobeN",
+            &[le.into(), be.into(), i32_const!(len as u64).into()]
+        );
+
+        be
+    }
+}
+
+/// The version of the call and instantiate host functions to use.
+fn api_version(ns: &Namespace) -> SubstrateApiVersion {
+    match ns.target {
+        Target::Substrate { api_version, .. } => api_version,
+        _ => unreachable!(),
+    }
+}
+
+/// Pointer to pass as the storage deposit limit, meaning no specific limit.
+fn no_deposit_limit<'a>(binary: &Binary<'a>) -> PointerValue<'a> {
+    // In pallet contracts, u32::MAX is the "none sentinel"
+    binary.builder.build_int_to_ptr(
+        binary.context.i32_type().const_all_ones(),
+        binary.context.i8_type().ptr_type(AddressSpace::default()),
+        "no_deposit_limit",
+    )
 }
 
 /// Print the return code of API calls to the debug buffer.
@@ -5,11 +5,13 @@ use crate::codegen::error_msg_with_loc;
 use crate::emit::binary::Binary;
 use crate::emit::expression::{expression, string_to_basic_value};
 use crate::emit::storage::StorageSlot;
-use crate::emit::substrate::{log_return_code, SubstrateTarget, SCRATCH_SIZE};
+use crate::emit::substrate::{
+    api_version, log_return_code, no_deposit_limit, SubstrateTarget, SCRATCH_SIZE,
+};
 use crate::emit::{ContractArgs, TargetRuntime, Variable};
 use crate::sema::ast;
 use crate::sema::ast::{Function, Namespace, Type};
-use crate::{codegen, emit_context};
+use crate::{codegen, emit_context, SubstrateApiVersion};
 use inkwell::types::{BasicType, BasicTypeEnum, IntType};
 use inkwell::values::BasicValue;
 use inkwell::values::{
@@ -680,38 +682,29 @@ impl<'a> TargetRuntime<'a> for SubstrateTarget {
         let slot_ptr = binary.builder.build_alloca(slot.get_type(), "slot");
         binary.builder.build_store(slot_ptr, slot);
 
-        let (scratch_buf, scratch_len) = scratch_buf!();
-
-        binary
-            .builder
-            .build_store(scratch_len, i32_const!(SCRATCH_SIZE as u64));
-
-        let exists = seal_get_storage!(
-            slot_ptr.into(),
-            i32_const!(32).into(),
-            scratch_buf.into(),
-            scratch_len.into()
-        );
+        // contains_storage returns the size of the value, so there is no need to read it
+        let size = call!(
+            "contains_storage",
+            &[slot_ptr.into(), i32_const!(32).into()]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
 
-        log_return_code(binary, "seal_get_storage", exists);
+        log_return_code(binary, "seal_contains_storage", size);
 
+        // In pallet contracts, u32::MAX is the "none sentinel"
         let exists = binary.builder.build_int_compare(
-            IntPredicate::EQ,
-            exists,
-            i32_zero!(),
+            IntPredicate::NE,
+            size,
+            binary.context.i32_type().const_all_ones(),
             "storage_exists",
         );
 
         binary
             .builder
-            .build_select(
-                exists,
-                binary
-                    .builder
-                    .build_load(binary.context.i32_type(), scratch_len, "string_len"),
-                i32_zero!().into(),
-                "string_length",
-            )
+            .build_select(exists, size, i32_zero!(), "string_length")
             .into_int_value()
     }
 
@@ -896,22 +889,44 @@ impl<'a> TargetRuntime<'a> for SubstrateTarget {
             .builder
             .build_store(scratch_len, i32_const!(SCRATCH_SIZE as u64 * 32));
 
-        let ret = call!(
-            "instantiate",
-            &[
-                codehash.into(),
-                contract_args.gas.unwrap().into(),
-                value_ptr.into(),
-                encoded_args.into(),
-                encoded_args_len.into(),
-                address.into(),
-                address_len_ptr.into(),
-                scratch_buf.into(),
-                scratch_len.into(),
-                salt_buf.into(),
-                salt_len.into(),
-            ]
-        )
+        let gas = contract_args.gas.unwrap();
+        let ret = match api_version(ns) {
+            SubstrateApiVersion::V1 => call!(
+                "instantiate",
+                &[
+                    codehash.into(),
+                    gas.into(),
+                    value_ptr.into(),
+                    encoded_args.into(),
+                    encoded_args_len.into(),
+                    address.into(),
+                    address_len_ptr.into(),
+                    scratch_buf.into(),
+                    scratch_len.into(),
+                    salt_buf.into(),
+                    salt_len.into(),
+                ]
+            ),
+            // gas is the ref_time limit; a proof_size limit of 0 means no limit
+            SubstrateApiVersion::V2 => call!(
+                "instantiate",
+                &[
+                    codehash.into(),
+                    gas.into(),
+                    binary.context.i64_type().const_zero().into(),
+                    no_deposit_limit(binary).into(),
+                    value_ptr.into(),
+                    encoded_args.into(),
+                    encoded_args_len.into(),
+                    address.into(),
+                    address_len_ptr.into(),
+                    scratch_buf.into(),
+                    scratch_len.into(),
+                    salt_buf.into(),
+                    salt_len.into(),
+                ]
+            ),
+        }
         .try_as_basic_value()
         .left()
         .unwrap()
@@ -967,46 +982,118 @@ impl<'a> TargetRuntime<'a> for SubstrateTarget {
         payload_len: IntValue<'b>,
         address: Option<PointerValue<'b>>,
         contract_args: ContractArgs<'b>,
-        _ty: ast::CallTy,
+        ty: ast::CallTy,
         ns: &ast::Namespace,
         loc: Loc,
     ) {
         emit_context!(binary);
 
-        // balance is a u128
-        let value_ptr = binary
-            .builder
-            .build_alloca(binary.value_type(ns), "balance");
-        binary
-            .builder
-            .build_store(value_ptr, contract_args.value.unwrap());
-
         let (scratch_buf, scratch_len) = scratch_buf!();
 
         binary
             .builder
             .build_store(scratch_len, i32_const!(SCRATCH_SIZE as u64));
 
-        // do the actual call
-        let ret = call!(
-            "seal_call",
-            &[
-                contract_args.flags.unwrap_or(i32_zero!()).into(),
-                address.unwrap().into(),
-                contract_args.gas.unwrap().into(),
-                value_ptr.into(),
-                payload.into(),
-                payload_len.into(),
-                scratch_buf.into(),
-                scratch_len.into(),
-            ]
-        )
-        .try_as_basic_value()
-        .left()
-        .unwrap()
-        .into_int_value();
+        let flags = contract_args.flags.unwrap_or(i32_zero!());
+
+        let ret = if ty == ast::CallTy::Delegate {
+            // delegate_call runs code by its hash, so first look up the code hash of the callee.
+            // If the callee is not a contract, the hash stays zero and delegate_call fails.
+            let hash_ty = binary.llvm_type(&ast::Type::Bytes(32), ns);
+            let code_hash = binary.builder.build_alloca(hash_ty, "code_hash");
+            binary
+                .builder
+                .build_store(code_hash, hash_ty.into_int_type().const_zero());
+            let code_hash_len = binary
+                .builder
+                .build_alloca(binary.context.i32_type(), "code_hash_len");
+            binary.builder.build_store(code_hash_len, i32_const!(32));
+
+            let ret = call!(
+                "code_hash",
+                &[
+                    address.unwrap().into(),
+                    code_hash.into(),
+                    code_hash_len.into()
+                ]
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+            log_return_code(binary, "seal_code_hash", ret);
+
+            let ret = call!(
+                "delegate_call",
+                &[
+                    flags.into(),
+                    code_hash.into(),
+                    payload.into(),
+                    payload_len.into(),
+                    scratch_buf.into(),
+                    scratch_len.into(),
+                ]
+            )
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
+
+            log_return_code(binary, "seal_delegate_call", ret);
+
+            ret
+        } else {
+            // balance is a u128
+            let value_ptr = binary
+                .builder
+                .build_alloca(binary.value_type(ns), "balance");
+            binary
+                .builder
+                .build_store(value_ptr, contract_args.value.unwrap());
+
+            // do the actual call
+            let gas = contract_args.gas.unwrap();
+            let ret = match api_version(ns) {
+                SubstrateApiVersion::V1 => call!(
+                    "seal_call",
+                    &[
+                        flags.into(),
+                        address.unwrap().into(),
+                        gas.into(),
+                        value_ptr.into(),
+                        payload.into(),
+                        payload_len.into(),
+                        scratch_buf.into(),
+                        scratch_len.into(),
+                    ]
+                ),
+                // gas is the ref_time limit; a proof_size limit of 0 means no limit
+                SubstrateApiVersion::V2 => call!(
+                    "seal_call",
+                    &[
+                        flags.into(),
+                        address.unwrap().into(),
+                        gas.into(),
+                        binary.context.i64_type().const_zero().into(),
+                        no_deposit_limit(binary).into(),
+                        value_ptr.into(),
+                        payload.into(),
+                        payload_len.into(),
+                        scratch_buf.into(),
+                        scratch_len.into(),
+                    ]
+                ),
+            }
+            .try_as_basic_value()
+            .left()
+            .unwrap()
+            .into_int_value();
 
-        log_return_code(binary, "seal_call", ret);
+            log_return_code(binary, "seal_call", ret);
+
+            ret
+        };
 
         let is_success =
             binary
@@ -1527,7 +1614,7 @@ impl<'a> TargetRuntime<'a> for SubstrateTarget {
                     .builder
                     .build_load(binary.value_type(ns), scratch_buf, "balance")
             }
-            _ => unreachable!("{:?}", expr),
+            _ => unreachable!("{:?} is not available on Substrate", expr),
         }
     }
 
@@ -1574,13 +1661,227 @@ impl<'a> TargetRuntime<'a> for SubstrateTarget {
         self.storage_delete_slot(bin, ty, slot, slot_ptr, function, ns);
     }
 
-    fn builtin_function(
+    fn storage_take(
+        &self,
+        binary: &Binary<'a>,
+        ty: &Type,
+        slot: &mut IntValue<'a>,
+        _function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a> {
+        emit_context!(binary);
+
+        let slot_ptr = binary.builder.build_alloca(slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, *slot);
+
+        let llvm_ty = binary.llvm_type(ty, ns);
+        let (scratch_buf, scratch_len) = scratch_buf!();
+        let ty_len = binary.builder.build_int_truncate(
+            llvm_ty.size_of().unwrap(),
+            binary.context.i32_type(),
+            "size_of",
+        );
+        binary.builder.build_store(scratch_len, ty_len);
+
+        let ret = call!(
+            "take_storage",
+            &[
+                slot_ptr.into(),
+                i32_const!(32).into(),
+                scratch_buf.into(),
+                scratch_len.into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        log_return_code(binary, "seal_take_storage", ret);
+
+        let exists =
+            binary
+                .builder
+                .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "storage_exists");
+
+        let zero: BasicValueEnum = if llvm_ty.is_array_type() {
+            llvm_ty.into_array_type().const_zero().into()
+        } else {
+            llvm_ty.into_int_type().const_zero().into()
+        };
+
+        binary.builder.build_select(
+            exists,
+            binary.builder.build_load(llvm_ty, scratch_buf, "taken"),
+            zero,
+            "taken_value",
+        )
+    }
+
+    fn storage_contains(
         &self,
         binary: &Binary<'a>,
+        slot: IntValue<'a>,
         _function: FunctionValue<'a>,
+    ) -> IntValue<'a> {
+        emit_context!(binary);
+
+        let slot_ptr = binary.builder.build_alloca(slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, slot);
+
+        let size = call!(
+            "contains_storage",
+            &[slot_ptr.into(), i32_const!(32).into()]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        log_return_code(binary, "seal_contains_storage", size);
+
+        // In pallet contracts, u32::MAX is the "none sentinel"
+        binary.builder.build_int_compare(
+            IntPredicate::NE,
+            size,
+            binary.context.i32_type().const_all_ones(),
+            "storage_exists",
+        )
+    }
+
+    fn transient_storage_load(
+        &self,
+        binary: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) -> BasicValueEnum<'a> {
+        emit_context!(binary);
+
+        let slot_ptr = binary.build_alloca(function, slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, slot);
+
+        let llvm_ty = binary.llvm_type(ty, ns);
+        let (scratch_buf, scratch_len) = scratch_buf!();
+        let ty_len = llvm_ty
+            .size_of()
+            .unwrap()
+            .const_cast(binary.context.i32_type(), false);
+        binary.builder.build_store(scratch_len, ty_len);
+
+        // tload() in inline assembly may read a shorter value stored by a transient variable, so
+        // the remaining bytes must be zero
+        let zero: BasicValueEnum = if llvm_ty.is_array_type() {
+            llvm_ty.into_array_type().const_zero().into()
+        } else {
+            llvm_ty.into_int_type().const_zero().into()
+        };
+        binary.builder.build_store(scratch_buf, zero);
+
+        let ret = call!(
+            "get_transient_storage",
+            &[
+                slot_ptr.into(),
+                i32_const!(32).into(),
+                scratch_buf.into(),
+                scratch_len.into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        log_return_code(binary, "seal_get_transient_storage", ret);
+
+        let exists = binary.builder.build_int_compare(
+            IntPredicate::EQ,
+            ret,
+            i32_zero!(),
+            "transient_exists",
+        );
+
+        let value = binary.builder.build_load(llvm_ty, scratch_buf, "transient");
+
+        // a slot which was never set in this transaction reads as zero
+        binary
+            .builder
+            .build_select(exists, value, zero, "transient_value")
+    }
+
+    fn transient_storage_store(
+        &self,
+        binary: &Binary<'a>,
+        ty: &Type,
+        slot: IntValue<'a>,
+        dest: BasicValueEnum<'a>,
+        function: FunctionValue<'a>,
+        ns: &Namespace,
+    ) {
+        emit_context!(binary);
+
+        let slot_ptr = binary.build_alloca(function, slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, slot);
+
+        let llvm_ty = binary.llvm_type(ty, ns);
+        let value_ptr = binary.build_alloca(function, llvm_ty, "value");
+        binary.builder.build_store(value_ptr, dest);
+
+        let value_len = llvm_ty
+            .size_of()
+            .unwrap()
+            .const_cast(binary.context.i32_type(), false);
+
+        let ret = call!(
+            "set_transient_storage",
+            &[
+                slot_ptr.into(),
+                i32_const!(32).into(),
+                value_ptr.into(),
+                value_len.into()
+            ]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        log_return_code(binary, "seal_set_transient_storage", ret);
+    }
+
+    fn transient_storage_delete(
+        &self,
+        binary: &Binary<'a>,
+        _ty: &Type,
+        slot: IntValue<'a>,
+        function: FunctionValue<'a>,
+        _ns: &Namespace,
+    ) {
+        emit_context!(binary);
+
+        let slot_ptr = binary.build_alloca(function, slot.get_type(), "slot");
+        binary.builder.build_store(slot_ptr, slot);
+
+        let ret = call!(
+            "clear_transient_storage",
+            &[slot_ptr.into(), i32_const!(32).into()]
+        )
+        .try_as_basic_value()
+        .left()
+        .unwrap()
+        .into_int_value();
+
+        log_return_code(binary, "seal_clear_transient_storage", ret);
+    }
+
+    fn builtin_function(
+        &self,
+        binary: &Binary<'a>,
+        function: FunctionValue<'a>,
         builtin_func: &Function,
         args: &[BasicMetadataValueEnum<'a>],
-        _first_arg_type: BasicTypeEnum,
+        _first_arg_type: Option<BasicTypeEnum>,
         ns: &Namespace,
     ) -> Option<BasicValueEnum<'a>> {
         emit_context!(binary);
@@ -1646,7 +1947,311 @@ impl<'a> TargetRuntime<'a> for SubstrateTarget {
                     .build_store(args[1].into_pointer_value(), is_contract);
                 None
             }
-            _ => unimplemented!(),
+            "set_code_hash" => {
+                let code_hash = self.bytes_to_big_endian(binary, args[0].into_int_value());
+
+                let ret = call!("set_code_hash", &[code_hash.into()], "seal_set_code_hash")
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                log_return_code(binary, "seal_set_code_hash", ret);
+
+                let is_success =
+                    binary
+                        .builder
+                        .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "success");
+                let success_block = binary.context.append_basic_block(function, "success");
+                let bail_block = binary.context.append_basic_block(function, "bail");
+                binary
+                    .builder
+                    .build_conditional_branch(is_success, success_block, bail_block);
+
+                binary.builder.position_at_end(bail_block);
+                self.log_runtime_error(binary, "set_code_hash failed".to_string(), None, ns);
+                self.assert_failure(binary, byte_ptr!().const_null(), i32_zero!());
+
+                binary.builder.position_at_end(success_block);
+                None
+            }
+            "ecdsa_recover" => {
+                let signature = args[0].into_pointer_value();
+                self.assert_vector_length(
+                    binary,
+                    function,
+                    signature,
+                    65,
+                    "ecdsa signature must be 65 bytes",
+                    ns,
+                );
+                let message_hash = self.bytes_to_big_endian(binary, args[1].into_int_value());
+                let public_key = binary.vector_new(i32_const!(33), i32_const!(1), None);
+
+                let ret = call!(
+                    "ecdsa_recover",
+                    &[
+                        binary.vector_bytes(signature.into()).into(),
+                        message_hash.into(),
+                        binary.vector_bytes(public_key.into()).into()
+                    ],
+                    "seal_ecdsa_recover"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+                log_return_code(binary, "seal_ecdsa_recover", ret);
+
+                let success =
+                    binary
+                        .builder
+                        .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "success");
+                binary
+                    .builder
+                    .build_store(args[2].into_pointer_value(), success);
+                binary
+                    .builder
+                    .build_store(args[3].into_pointer_value(), public_key);
+                None
+            }
+            "ecdsa_to_eth_address" => {
+                let public_key = args[0].into_pointer_value();
+                self.assert_vector_length(
+                    binary,
+                    function,
+                    public_key,
+                    33,
+                    "ecdsa public key must be 33 bytes",
+                    ns,
+                );
+                let eth_address_ty = binary.llvm_type(&ast::Type::Bytes(20), ns);
+                let eth_address = binary.builder.build_alloca(eth_address_ty, "eth_address");
+                binary
+                    .builder
+                    .build_store(eth_address, eth_address_ty.into_int_type().const_zero());
+
+                let ret = call!(
+                    "ecdsa_to_eth_address",
+                    &[
+                        binary.vector_bytes(public_key.into()).into(),
+                        eth_address.into()
+                    ],
+                    "seal_ecdsa_to_eth_address"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+                log_return_code(binary, "seal_ecdsa_to_eth_address", ret);
+
+                // bytes20 needs to reverse bytes
+                let temp = binary
+                    .builder
+                    .build_alloca(eth_address_ty, "eth_address_le");
+                call!(
+                    "__beNtoleN",
+                    &[eth_address.into(), temp.into(), i32_const!(20).into()]
+                );
+
+                let success =
+                    binary
+                        .builder
+                        .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "success");
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), success);
+                binary.builder.build_store(
+                    args[2].into_pointer_value(),
+                    binary
+                        .builder
+                        .build_load(eth_address_ty, temp, "eth_address"),
+                );
+                None
+            }
+            "sr25519_verify" => {
+                let public_key = binary
+                    .builder
+                    .build_alloca(binary.address_type(ns), "public_key");
+                binary
+                    .builder
+                    .build_store(public_key, args[0].into_array_value());
+                let message = args[1].into_pointer_value();
+                let signature = args[2].into_pointer_value();
+                self.assert_vector_length(
+                    binary,
+                    function,
+                    signature,
+                    64,
+                    "sr25519 signature must be 64 bytes",
+                    ns,
+                );
+
+                let ret = call!(
+                    "sr25519_verify",
+                    &[
+                        binary.vector_bytes(signature.into()).into(),
+                        public_key.into(),
+                        binary.vector_len(message.into()).into(),
+                        binary.vector_bytes(message.into()).into()
+                    ],
+                    "seal_sr25519_verify"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+                log_return_code(binary, "seal_sr25519_verify", ret);
+
+                let valid =
+                    binary
+                        .builder
+                        .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "valid");
+                binary
+                    .builder
+                    .build_store(args[3].into_pointer_value(), valid);
+                None
+            }
+            "call_runtime" | "xcm_execute" => {
+                let input = args[0].into_pointer_value();
+                let (api, call_name) = if builtin_func.name == "call_runtime" {
+                    ("call_runtime", "seal_call_runtime")
+                } else {
+                    ("xcm_execute", "seal_xcm_execute")
+                };
+
+                let ret = binary
+                    .builder
+                    .build_call(
+                        binary.module.get_function(api).unwrap(),
+                        &[
+                            binary.vector_bytes(input.into()).into(),
+                            binary.vector_len(input.into()).into(),
+                        ],
+                        call_name,
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                log_return_code(binary, call_name, ret);
+
+                let success =
+                    binary
+                        .builder
+                        .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "success");
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), success);
+                None
+            }
+            "xcm_send" => {
+                let destination = args[0].into_pointer_value();
+                let message = args[1].into_pointer_value();
+                let hash_ty = binary.llvm_type(&ast::Type::Bytes(32), ns);
+                let message_hash = binary.builder.build_alloca(hash_ty, "message_hash");
+                binary
+                    .builder
+                    .build_store(message_hash, hash_ty.into_int_type().const_zero());
+
+                let ret = call!(
+                    "xcm_send",
+                    &[
+                        binary.vector_bytes(destination.into()).into(),
+                        binary.vector_bytes(message.into()).into(),
+                        binary.vector_len(message.into()).into(),
+                        message_hash.into()
+                    ],
+                    "seal_xcm_send"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+
+                log_return_code(binary, "seal_xcm_send", ret);
+
+                // bytes32 needs to reverse bytes
+                let temp = binary.builder.build_alloca(hash_ty, "message_hash_le");
+                call!(
+                    "__beNtoleN",
+                    &[message_hash.into(), temp.into(), i32_const!(32).into()]
+                );
+
+                let success =
+                    binary
+                        .builder
+                        .build_int_compare(IntPredicate::EQ, ret, i32_zero!(), "success");
+                binary
+                    .builder
+                    .build_store(args[2].into_pointer_value(), success);
+                binary.builder.build_store(
+                    args[3].into_pointer_value(),
+                    binary.builder.build_load(hash_ty, temp, "message_hash"),
+                );
+                None
+            }
+            "caller_is_origin" | "caller_is_root" => {
+                let ret = binary
+                    .builder
+                    .build_call(
+                        binary.module.get_function(&builtin_func.name).unwrap(),
+                        &[],
+                        "seal_caller_is",
+                    )
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+
+                // the host function returns 1 for true and 0 for false
+                let is = binary.builder.build_int_compare(
+                    IntPredicate::NE,
+                    ret,
+                    i32_zero!(),
+                    &builtin_func.name,
+                );
+                binary.builder.build_store(args[0].into_pointer_value(), is);
+                None
+            }
+            "reentrance_count" => {
+                let count = call!("reentrance_count", &[], "seal_reentrance_count")
+                    .try_as_basic_value()
+                    .left()
+                    .unwrap()
+                    .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[0].into_pointer_value(), count);
+                None
+            }
+            "account_reentrance_count" => {
+                let account = binary
+                    .builder
+                    .build_alloca(binary.address_type(ns), "account");
+                binary
+                    .builder
+                    .build_store(account, args[0].into_array_value());
+                let count = call!(
+                    "account_reentrance_count",
+                    &[account.into()],
+                    "seal_account_reentrance_count"
+                )
+                .try_as_basic_value()
+                .left()
+                .unwrap()
+                .into_int_value();
+                binary
+                    .builder
+                    .build_store(args[1].into_pointer_value(), count);
+                None
+            }
+            // sema only declares the builtins listed above
+            _ => unreachable!("unknown builtin function '{}'", builtin_func.name),
         }
     }
 
@@ -1660,7 +2265,7 @@ impl<'a> TargetRuntime<'a> for SubstrateTarget {
         _ns: &Namespace,
     ) -> IntValue<'a> {
         // not needed for slot-based storage chains
-        unimplemented!()
+        unreachable!()
     }
 
     fn log_runtime_error(
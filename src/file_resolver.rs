@@ -1,22 +1,81 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast;
+use itertools::Itertools;
 use solang_parser::pt::Loc;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
 use std::io;
 use std::io::{prelude::*, Error, ErrorKind};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
 
 pub struct FileResolver {
     /// Set of import paths search for imports
     import_paths: Vec<(Option<OsString>, PathBuf)>,
+    /// Prefixes of import paths which should be rewritten before searching
+    remappings: Vec<Remapping>,
     /// List file by import path
     cached_paths: HashMap<PathBuf, usize>,
     /// The actual file contents
     files: Vec<Arc<str>>,
+    /// The paths which were tried for imports which could not be found
+    not_found: Vec<PathBuf>,
+}
+
+/// A solc style remapping, in the form `context:prefix=target`. An import which starts with
+/// `prefix` has that part replaced with `target`. If a context is given, the remapping only
+/// applies to imports in files whose path starts with the context.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Remapping {
+    pub context: Option<String>,
+    pub prefix: String,
+    pub target: String,
+}
+
+impl FromStr for Remapping {
+    type Err = String;
+
+    fn from_str(remapping: &str) -> Result<Self, Self::Err> {
+        let (mapping, target) = match remapping.split_once('=') {
+            Some(split) => split,
+            None => return Err(format!("remapping '{remapping}' contains no '='")),
+        };
+
+        let (context, prefix) = match mapping.split_once(':') {
+            Some((context, prefix)) => (Some(context.to_owned()).filter(|c| !c.is_empty()), prefix),
+            None => (None, mapping),
+        };
+
+        if prefix.is_empty() {
+            return Err(format!("remapping '{remapping}' has an empty prefix"));
+        }
+
+        Ok(Remapping {
+            context,
+            prefix: prefix.to_owned(),
+            target: target.to_owned(),
+        })
+    }
+}
+
+impl Remapping {
+    /// Make a relative context absolute, relative to the given directory. The context is
+    /// compared with the canonical path of the importing file, so it has to be canonical too.
+    pub fn absolute_context(&mut self, dir: &Path) {
+        if let Some(context) = &self.context {
+            let path = dir.join(context);
+
+            self.context = Some(
+                path.canonicalize()
+                    .unwrap_or(path)
+                    .to_string_lossy()
+                    .to_string(),
+            );
+        }
+    }
 }
 
 /// When we resolve a file, we need to know its base compared to the import so
@@ -44,8 +103,10 @@ impl FileResolver {
     pub fn new() -> Self {
         FileResolver {
             import_paths: Vec::new(),
+            remappings: Vec::new(),
             cached_paths: HashMap::new(),
             files: Vec::new(),
+            not_found: Vec::new(),
         }
     }
 
@@ -72,6 +133,73 @@ impl FileResolver {
         }
     }
 
+    /// Add remapping
+    pub fn add_remapping(&mut self, remapping: Remapping) {
+        self.remappings.push(remapping);
+    }
+
+    /// Add the remappings from a Foundry style `remappings.txt` file, which has one remapping
+    /// per line. Relative contexts and targets are relative to the directory of the file.
+    pub fn add_remappings_file(&mut self, path: &Path) -> Result<(), String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| format!("cannot read '{}': {}", path.display(), err))?;
+
+        let dir = path
+            .canonicalize()
+            .map_err(|err| format!("cannot read '{}': {}", path.display(), err))?
+            .parent()
+            .expect("path should include filename")
+            .to_path_buf();
+
+        for line in contents.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut remapping = line
+                .parse::<Remapping>()
+                .map_err(|err| format!("{}: {}", path.display(), err))?;
+
+            remapping.absolute_context(&dir);
+            remapping.target = dir.join(&remapping.target).to_string_lossy().to_string();
+
+            self.remappings.push(remapping);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the remapping with the longest matching context and prefix, like solc does.
+    /// Returns `None` if no remapping applies.
+    fn remap(&self, parent: Option<&ResolvedFile>, filename: &OsStr) -> Option<OsString> {
+        let name = filename.to_string_lossy();
+        let parent_path = parent.map(|parent| parent.full_path.to_string_lossy());
+
+        let best = self
+            .remappings
+            .iter()
+            .filter(|remapping| name.starts_with(&remapping.prefix))
+            .filter(|remapping| match (&remapping.context, &parent_path) {
+                (None, _) => true,
+                (Some(context), Some(parent)) => parent.starts_with(context.as_str()),
+                (Some(_), None) => false,
+            })
+            .max_by_key(|remapping| {
+                (
+                    remapping.context.as_ref().map(String::len).unwrap_or(0),
+                    remapping.prefix.len(),
+                )
+            });
+
+        best.map(|remapping| {
+            OsString::from(format!(
+                "{}{}",
+                remapping.target,
+                &name[remapping.prefix.len()..]
+            ))
+        })
+    }
+
     /// Update the cache for the filename with the given contents
     pub fn set_file_contents(&mut self, path: &str, contents: String) {
         let pos = self.files.len();
@@ -123,13 +251,17 @@ impl FileResolver {
 
     /// Walk the import path to search for a file. If no import path is set up,
     /// return. Check each import path if the file can be found in a subdirectory
-    /// of that path, and return the canonicalized path.
+    /// of that path, and return the canonicalized path. If the file cannot be found
+    /// there either, look for it in the `node_modules` directories above the importing
+    /// file, like node does. A remapped import is never resolved relative to the importing
+    /// file. If the file is not found, the error lists all the paths which were tried.
     pub fn resolve_file(
         &mut self,
         parent: Option<&ResolvedFile>,
         filename: &OsStr,
     ) -> Result<ResolvedFile, String> {
-        let path = PathBuf::from(filename);
+        let remapped = self.remap(parent, filename);
+        let path = PathBuf::from(remapped.as_deref().unwrap_or(filename));
 
         let path = if let Ok(m) = path.strip_prefix("./") {
             m.to_path_buf()
@@ -137,6 +269,8 @@ impl FileResolver {
             path
         };
 
+        let mut tried = Vec::new();
+
         // first check maps
         let mut iter = path.iter();
         if let Some(first_part) = iter.next() {
@@ -145,7 +279,9 @@ impl FileResolver {
             for (import_no, import) in self.import_paths.iter().enumerate() {
                 if let (Some(mapping), import_path) = import {
                     if first_part == mapping {
-                        if let Ok(full_path) = import_path.join(relpath).canonicalize() {
+                        let candidate = import_path.join(relpath);
+
+                        if let Ok(full_path) = candidate.canonicalize() {
                             self.load_file(&full_path)?;
                             let base = full_path
                                 .parent()
@@ -158,6 +294,8 @@ impl FileResolver {
                                 base,
                             });
                         }
+
+                        tried.push(candidate);
                     }
                 }
             }
@@ -165,36 +303,52 @@ impl FileResolver {
 
         let mut start_import_no = 0;
 
-        // first try relative to the parent
+        // first try relative to the parent. The target of a remapping is relative to the
+        // import paths like solc's base path, never to the importing file
         if let Some(ResolvedFile {
             import_no, base, ..
-        }) = parent
+        }) = parent.filter(|_| remapped.is_none())
         {
             if self.import_paths.is_empty() {
-                // we have no import paths, resolve by what's in the cache
+                // we have no import paths, resolve by what's in the cache. The file may have
+                // been added with or without the `..` components
                 let full_path = base.join(&path);
-                let base = full_path
-                    .parent()
-                    .expect("path should include filename")
-                    .to_path_buf();
-
-                return Ok(ResolvedFile {
-                    full_path,
-                    base,
-                    import_no: 0,
-                });
-            } else if let Ok(full_path) = base.join(&path).canonicalize() {
-                self.load_file(&full_path)?;
-                let base = full_path
-                    .parent()
-                    .expect("path should include filename")
-                    .to_path_buf();
-
-                return Ok(ResolvedFile {
-                    full_path,
-                    base,
-                    import_no: 0,
-                });
+                let full_path = if self.cached_paths.contains_key(&full_path) {
+                    full_path
+                } else {
+                    normalize(&full_path)
+                };
+
+                if self.cached_paths.contains_key(&full_path) {
+                    let base = full_path
+                        .parent()
+                        .expect("path should include filename")
+                        .to_path_buf();
+
+                    return Ok(ResolvedFile {
+                        full_path,
+                        base,
+                        import_no: 0,
+                    });
+                }
+            } else {
+                let candidate = base.join(&path);
+
+                if let Ok(full_path) = candidate.canonicalize() {
+                    self.load_file(&full_path)?;
+                    let base = full_path
+                        .parent()
+                        .expect("path should include filename")
+                        .to_path_buf();
+
+                    return Ok(ResolvedFile {
+                        full_path,
+                        base,
+                        import_no: 0,
+                    });
+                }
+
+                tried.push(candidate);
             }
 
             // start with this import
@@ -220,7 +374,9 @@ impl FileResolver {
             let import_no = (i + start_import_no) % self.import_paths.len();
 
             if let (None, import_path) = &self.import_paths[import_no] {
-                if let Ok(full_path) = import_path.join(&path).canonicalize() {
+                let candidate = import_path.join(&path);
+
+                if let Ok(full_path) = candidate.canonicalize() {
                     let base = full_path
                         .parent()
                         .expect("path should include filename")
@@ -233,10 +389,61 @@ impl FileResolver {
                         base,
                     });
                 }
+
+                tried.push(candidate);
+            }
+        }
+
+        // imports like `@openzeppelin/contracts/token/ERC20/ERC20.sol` may be installed with npm
+        if let Some(ResolvedFile { base, .. }) = parent {
+            let relative = matches!(
+                path.components().next(),
+                Some(Component::CurDir | Component::ParentDir)
+            );
+
+            if !self.import_paths.is_empty() && !relative && path.is_relative() {
+                for dir in base.ancestors() {
+                    let candidate = dir.join("node_modules").join(&path);
+
+                    if let Ok(full_path) = candidate.canonicalize() {
+                        self.load_file(&full_path)?;
+                        let base = full_path
+                            .parent()
+                            .expect("path should include filename")
+                            .to_path_buf();
+
+                        return Ok(ResolvedFile {
+                            full_path,
+                            base,
+                            import_no: 0,
+                        });
+                    }
+
+                    tried.push(candidate);
+                }
+            }
+        }
+
+        let mut message = format!("file not found '{}'", filename.to_string_lossy());
+
+        if !tried.is_empty() {
+            message.push_str("; tried:");
+
+            // the parent directory can also be an import path
+            for candidate in tried.iter().unique() {
+                message.push_str(&format!("\n  {}", candidate.display()));
             }
         }
 
-        Err(format!("file not found '{}'", filename.to_string_lossy()))
+        self.not_found.extend(tried);
+
+        Err(message)
+    }
+
+    /// The paths which were tried for imports which could not be found. If any of them is
+    /// created, the import would resolve.
+    pub fn not_found(&self) -> &[PathBuf] {
+        &self.not_found
     }
 
     /// Get line and the target symbol's offset from loc
@@ -283,3 +490,23 @@ impl FileResolver {
         (full_line, begin_line, begin_column, size)
     }
 }
+
+/// Resolve `.` and `..` components without touching the filesystem, for files which only
+/// exist in the cache
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+
+    normalized
+}
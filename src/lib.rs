@@ -7,6 +7,7 @@ pub mod emit;
 pub mod file_resolver;
 #[cfg(feature = "llvm")]
 mod linker;
+pub mod sarif;
 pub mod standard_json;
 
 // In Sema, we use result unit for returning early
@@ -28,11 +29,35 @@ pub enum Target {
     Substrate {
         address_length: usize,
         value_length: usize,
+        api_version: SubstrateApiVersion,
     },
     /// Ethereum EVM, see <https://ethereum.org/en/developers/docs/evm/>
     EVM,
 }
 
+/// The version of the contracts pallet host functions used for calling and
+/// instantiating contracts on Substrate.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SubstrateApiVersion {
+    /// `seal1` call and instantiate, which take a single `gas` limit
+    V1,
+    /// `seal2` call and instantiate, which take weight v2 `ref_time` and `proof_size` limits
+    /// as well as a storage deposit limit
+    #[default]
+    V2,
+}
+
+impl SubstrateApiVersion {
+    /// Creates an API version from its number
+    pub fn from(version: u64) -> Option<Self> {
+        match version {
+            1 => Some(SubstrateApiVersion::V1),
+            2 => Some(SubstrateApiVersion::V2),
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Target {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -66,6 +91,7 @@ impl Target {
         Target::Substrate {
             address_length: 32,
             value_length: 16,
+            api_version: SubstrateApiVersion::V2,
         }
     }
 
@@ -145,6 +171,9 @@ pub fn compile(
     // codegen all the contracts
     codegen::codegen(&mut ns, &opts);
 
+    // codegen can find more warnings, which may be suppressed too
+    ns.suppress_warnings();
+
     if ns.diagnostics.any_errors() {
         return (Vec::new(), ns);
     }
@@ -187,6 +216,7 @@ pub fn parse_and_resolve(
                 message,
                 loc: pt::Loc::CommandLine,
                 notes: Vec::new(),
+                code: sema::codes::PARSER_ERROR,
             });
         }
         Ok(file) => {
@@ -194,6 +224,7 @@ pub fn parse_and_resolve(
         }
     }
 
+    ns.suppress_warnings();
     ns.diagnostics.sort_and_dedup();
 
     ns
@@ -3,7 +3,7 @@
 mod bpf;
 mod wasm;
 
-use crate::Target;
+use crate::{SubstrateApiVersion, Target};
 use once_cell::sync::Lazy;
 use std::ffi::CString;
 use std::sync::Mutex;
@@ -16,10 +16,10 @@ pub fn link(input: &[u8], name: &str, target: Target) -> Vec<u8> {
     // We should fix this one day
     let _lock = LINKER_MUTEX.lock().unwrap();
 
-    if target == Target::Solana {
-        bpf::link(input, name)
-    } else {
-        wasm::link(input, name)
+    match target {
+        Target::Solana => bpf::link(input, name),
+        Target::Substrate { api_version, .. } => wasm::link(input, name, api_version),
+        _ => wasm::link(input, name, SubstrateApiVersion::default()),
     }
 }
 
@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::SubstrateApiVersion;
 use std::ffi::CString;
 use std::fs::File;
 use std::io::Read;
@@ -11,7 +12,7 @@ use wasm_encoder::{
 };
 use wasmparser::{Global, Import, Parser, Payload::*, SectionLimited, TypeRef};
 
-pub fn link(input: &[u8], name: &str) -> Vec<u8> {
+pub fn link(input: &[u8], name: &str, api_version: SubstrateApiVersion) -> Vec<u8> {
     let dir = tempdir().expect("failed to create temp directory for linking");
 
     let object_filename = dir.path().join(format!("{name}.o"));
@@ -63,14 +64,14 @@ pub fn link(input: &[u8], name: &str) -> Vec<u8> {
         .read_to_end(&mut output)
         .expect("failed to read output file");
 
-    generate_module(&output)
+    generate_module(&output, api_version)
 }
 
-fn generate_module(input: &[u8]) -> Vec<u8> {
+fn generate_module(input: &[u8], api_version: SubstrateApiVersion) -> Vec<u8> {
     let mut module = Module::new();
     for payload in Parser::new(0).parse_all(input).map(|s| s.unwrap()) {
         match payload {
-            ImportSection(s) => generate_import_section(s, &mut module),
+            ImportSection(s) => generate_import_section(s, &mut module, api_version),
             GlobalSection(s) => generate_global_section(s, &mut module),
             ModuleSection { .. } | ComponentSection { .. } => panic!("nested WASM module"),
             _ => {
@@ -87,7 +88,11 @@ fn generate_module(input: &[u8]) -> Vec<u8> {
 }
 
 /// Resolve all pallet contracts runtime imports
-fn generate_import_section(section: SectionLimited<Import>, module: &mut Module) {
+fn generate_import_section(
+    section: SectionLimited<Import>,
+    module: &mut Module,
+    api_version: SubstrateApiVersion,
+) {
     let mut imports = ImportSection::new();
     for import in section.into_iter().map(|import| import.unwrap()) {
         let import_type = match import.ty {
@@ -100,14 +105,20 @@ fn generate_import_section(section: SectionLimited<Import>, module: &mut Module)
             }),
             _ => panic!("unexpected WASM import section {:?}", import),
         };
-        let module_name = match import.name {
-            "memory" => import.module,
-            "set_storage" => "seal2",
-            "clear_storage" | "contains_storage" | "get_storage" | "instantiate" | "terminate"
-            | "seal_call" => "seal1",
-            _ => "seal0",
+        let (module_name, name) = match (import.name, api_version) {
+            ("memory", _) => (import.module, import.name),
+            // seal_call is imported under its prefixed name, it would collide with the call export
+            ("seal_call", SubstrateApiVersion::V2) => ("seal2", "call"),
+            ("instantiate", SubstrateApiVersion::V2) => ("seal2", import.name),
+            ("set_storage", _) => ("seal2", import.name),
+            (
+                "clear_storage" | "contains_storage" | "get_storage" | "instantiate" | "terminate"
+                | "seal_call",
+                _,
+            ) => ("seal1", import.name),
+            _ => ("seal0", import.name),
         };
-        imports.import(module_name, import.name, import_type);
+        imports.import(module_name, name, import_type);
     }
     module.section(&imports);
 }
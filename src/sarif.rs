@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module defines the [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html)
+//! log format for `solang check --format sarif`, so that code scanning tools can ingest
+//! solang diagnostics.
+
+use crate::sema::codes;
+use serde::Serialize;
+use std::{collections::BTreeMap, path::Path};
+
+/// The base id which relative artifact uris are resolved against
+pub const SRCROOT: &str = "%SRCROOT%";
+
+#[derive(Serialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Run {
+    pub tool: Tool,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub original_uri_base_ids: BTreeMap<String, ArtifactLocation>,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+pub struct Tool {
+    pub driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+    pub name: String,
+    pub version: String,
+    pub information_uri: String,
+    pub rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rule {
+    pub id: String,
+    pub name: String,
+    pub short_description: Message,
+    pub full_description: Message,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub level: String,
+    pub message: Message,
+    pub locations: Vec<Location>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<Location>,
+}
+
+#[derive(Serialize)]
+pub struct Message {
+    pub text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<usize>,
+    pub physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    pub artifact_location: ArtifactLocation,
+    pub region: Region,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArtifactLocation {
+    pub uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<String>,
+}
+
+/// Lines and columns are 1-based, and the end column is exclusive
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl SarifLog {
+    /// Create a log with a single run, containing the given results. Artifact uris are
+    /// relative to the project root.
+    pub fn new(root: &Path, results: Vec<SarifResult>) -> Self {
+        let rules: BTreeMap<&str, &codes::CodeInfo> = results
+            .iter()
+            .filter_map(|result| {
+                codes::lookup(&result.rule_id).map(|info| (result.rule_id.as_str(), info))
+            })
+            .collect();
+
+        let rules = rules
+            .into_iter()
+            .map(|(id, info)| Rule {
+                id: id.to_owned(),
+                name: info.name.to_owned(),
+                short_description: Message {
+                    text: info.summary.to_owned(),
+                },
+                full_description: Message {
+                    text: info.explanation.to_owned(),
+                },
+            })
+            .collect();
+
+        let mut original_uri_base_ids = BTreeMap::new();
+
+        original_uri_base_ids.insert(
+            SRCROOT.to_owned(),
+            ArtifactLocation {
+                uri: format!("{}/", file_uri(root).trim_end_matches('/')),
+                uri_base_id: None,
+            },
+        );
+
+        SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json".to_owned(),
+            version: "2.1.0".to_owned(),
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "solang".to_owned(),
+                        version: env!("SOLANG_VERSION").to_owned(),
+                        information_uri: "https://github.com/hyperledger/solang".to_owned(),
+                        rules,
+                    },
+                },
+                original_uri_base_ids,
+                results,
+            }],
+        }
+    }
+}
+
+/// Create the location of a file. If the file is inside the project root, the uri is
+/// relative to the root; otherwise it is an absolute file uri.
+pub fn artifact_location(root: &Path, path: &Path) -> ArtifactLocation {
+    match path.strip_prefix(root) {
+        Ok(relative) => ArtifactLocation {
+            uri: percent_encode(&relative.to_string_lossy().replace('\\', "/")),
+            uri_base_id: Some(SRCROOT.to_owned()),
+        },
+        Err(_) => ArtifactLocation {
+            uri: file_uri(path),
+            uri_base_id: None,
+        },
+    }
+}
+
+fn file_uri(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+
+    if path.starts_with('/') {
+        format!("file://{}", percent_encode(&path))
+    } else {
+        // windows paths like c:/foo need an extra slash
+        format!("file:///{}", percent_encode(&path))
+    }
+}
+
+fn percent_encode(path: &str) -> String {
+    let mut encoded = String::new();
+
+    for b in path.bytes() {
+        if b.is_ascii_alphanumeric() || b"/-._~:".contains(&b) {
+            encoded.push(b as char);
+        } else {
+            encoded.push_str(&format!("%{b:02X}"));
+        }
+    }
+
+    encoded
+}
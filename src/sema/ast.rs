@@ -10,17 +10,16 @@ use crate::{codegen, Target};
 use indexmap::IndexMap;
 use num_bigint::BigInt;
 use num_rational::BigRational;
-use once_cell::unsync::OnceCell;
+use once_cell::sync::OnceCell;
 pub use solang_parser::diagnostics::*;
 use solang_parser::pt;
 use solang_parser::pt::{CodeLocation, FunctionTy, OptionalCodeLocation};
-use std::cell::RefCell;
 use std::{
     collections::HashSet,
     collections::{BTreeMap, HashMap},
     fmt, hash,
     path::PathBuf,
-    sync::Arc,
+    sync::{Arc, RwLock},
 };
 use tiny_keccak::{Hasher, Keccak};
 
@@ -126,11 +125,12 @@ pub trait RetrieveType {
 }
 
 impl Type {
-    pub fn get_type_size(&self) -> u16 {
+    /// The size in bits of a bool or integer type
+    pub fn get_type_size(&self) -> Option<u16> {
         match self {
-            Type::Int(n) | Type::Uint(n) => *n,
-            Type::Bool => 1,
-            _ => unimplemented!("size of type not known"),
+            Type::Int(n) | Type::Uint(n) => Some(*n),
+            Type::Bool => Some(1),
+            _ => None,
         }
     }
 
@@ -150,6 +150,9 @@ pub enum StructType {
     AccountMeta,
     ExternalFunction,
     SolParameters,
+    Clock,
+    EpochSchedule,
+    LoadedInstruction,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -335,7 +338,7 @@ pub struct Function {
     pub mangled_name_contracts: HashSet<usize>,
     /// This indexmap stores the accounts this functions needs to be called on Solana
     /// The string is the account's name
-    pub solana_accounts: RefCell<IndexMap<String, SolanaAccount>>,
+    pub solana_accounts: RwLock<IndexMap<String, SolanaAccount>>,
 }
 
 /// This struct represents a Solana account. There is no name field, because
@@ -503,6 +506,26 @@ impl Function {
             .any(|note| matches!(note, ConstructorAnnotation::Seed(..)))
     }
 
+    /// Does this function declare any accounts using annotations, e.g. @signer(name)?
+    pub fn has_declared_accounts(&self) -> bool {
+        self.solana_accounts
+            .read()
+            .unwrap()
+            .values()
+            .any(|account| !account.generated)
+    }
+
+    /// Return the index of a declared account in tx.accounts. The accounts declared by
+    /// annotations are resolved before codegen, and their order does not change. Codegen only
+    /// inserts the data account in front of them, unless the function is pure.
+    pub fn declared_account_index(&self, name: &str) -> Option<usize> {
+        self.solana_accounts
+            .read()
+            .unwrap()
+            .get_index_of(name)
+            .map(|index| if self.is_pure() { index } else { index + 1 })
+    }
+
     /// Does this function have the pure state
     pub fn is_pure(&self) -> bool {
         matches!(self.mutability, Mutability::Pure(_))
@@ -534,25 +557,6 @@ impl Function {
     }
 }
 
-impl From<&pt::Type> for Type {
-    fn from(p: &pt::Type) -> Type {
-        match p {
-            pt::Type::Bool => Type::Bool,
-            pt::Type::Address => Type::Address(false),
-            pt::Type::AddressPayable => Type::Address(true),
-            pt::Type::Payable => Type::Address(true),
-            pt::Type::Int(n) => Type::Int(*n),
-            pt::Type::Uint(n) => Type::Uint(*n),
-            pt::Type::Bytes(n) => Type::Bytes(*n),
-            pt::Type::String => Type::String,
-            pt::Type::Rational => Type::Rational,
-            pt::Type::DynamicBytes => Type::DynamicBytes,
-            // needs special casing
-            pt::Type::Function { .. } | pt::Type::Mapping { .. } => unimplemented!(),
-        }
-    }
-}
-
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct UserTypeDecl {
     pub tags: Vec<Tag>,
@@ -581,6 +585,7 @@ pub struct Variable {
     pub visibility: pt::Visibility,
     pub constant: bool,
     pub immutable: bool,
+    pub transient: bool,
     pub initializer: Option<Expression>,
     pub assigned: bool,
     pub read: bool,
@@ -654,6 +659,18 @@ pub struct File {
     pub line_starts: Vec<usize>,
     /// Indicates the file number in FileResolver.files
     pub cache_no: Option<usize>,
+    /// Ranges where warnings are disabled by `// solang-disable` comments
+    pub suppressions: Vec<Suppression>,
+}
+
+/// A range of a file where warnings are suppressed, by a `// solang-disable-next-line`
+/// or `// solang-disable` comment
+#[derive(Clone, Debug)]
+pub struct Suppression {
+    pub start: usize,
+    pub end: usize,
+    /// The code of the suppressed warnings, or None for all warnings
+    pub code: Option<&'static str>,
 }
 
 /// When resolving a Solidity file, this holds all the resolved items
@@ -698,6 +715,8 @@ pub struct Layout {
     pub contract_no: usize,
     pub var_no: usize,
     pub ty: Type,
+    /// Transient variables have their own slots, separate from contract storage
+    pub transient: bool,
 }
 
 pub struct Base {
@@ -753,6 +772,8 @@ pub struct Contract {
     pub dispatch_no: usize,
     /// Account of deployed program code on Solana
     pub program_id: Option<Vec<u8>>,
+    /// Emit events through a self-invocation rather than the program log on Solana
+    pub emit_cpi: bool,
 }
 
 impl Contract {
@@ -1467,9 +1488,16 @@ impl CodeLocation for Instr {
             | Instr::PushStorage { storage, .. }
             | Instr::PopStorage { storage, .. }
             | Instr::LoadStorage { storage, .. }
-            | Instr::ClearStorage { storage, .. } => storage.loc(),
-            Instr::ExternalCall { value, .. } | Instr::SetStorage { value, .. } => value.loc(),
+            | Instr::ClearStorage { storage, .. }
+            | Instr::TakeStorage { storage, .. }
+            | Instr::ContainsStorage { storage, .. }
+            | Instr::LoadTransientStorage { storage, .. }
+            | Instr::ClearTransientStorage { storage, .. } => storage.loc(),
+            Instr::ExternalCall { value, .. }
+            | Instr::SetStorage { value, .. }
+            | Instr::SetTransientStorage { value, .. } => value.loc(),
             Instr::PushMemory { value, .. } => value.loc(),
+            Instr::SetMemoryLength { length, .. } => length.loc(),
             Instr::Constructor { gas, .. } => gas.loc(),
             Instr::ValueTransfer { address, .. } => address.loc(),
             Instr::SelfDestruct { recipient } => recipient.loc(),
@@ -1529,6 +1557,8 @@ pub enum Builtin {
     ArrayPush,
     ArrayPop,
     ArrayLength,
+    ContainsStorage,
+    TakeStorage,
     Assert,
     Print,
     Require,
@@ -1568,6 +1598,12 @@ pub enum Builtin {
     ExternalFunctionAddress,
     FunctionSelector,
     SignatureVerify,
+    GetClock,
+    GetEpochSchedule,
+    RentMinimumBalance,
+    InstructionsCurrentIndex,
+    InstructionsLoad,
+    AccountRealloc,
     ReadInt8,
     ReadInt16LE,
     ReadInt32LE,
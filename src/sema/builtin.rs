@@ -4,6 +4,7 @@ use super::ast::{
     ArrayLength, Builtin, Diagnostic, Expression, File, Function, Namespace, Parameter, StructType,
     Symbol, Type,
 };
+use super::codes;
 use super::diagnostics::Diagnostics;
 use super::eval::eval_const_number;
 use super::expression::{ExprContext, ResolveTo};
@@ -32,7 +33,7 @@ pub struct Prototype {
 }
 
 // A list of all Solidity builtins functions
-static BUILTIN_FUNCTIONS: Lazy<[Prototype; 24]> = Lazy::new(|| {
+static BUILTIN_FUNCTIONS: Lazy<[Prototype; 31]> = Lazy::new(|| {
     [
         Prototype {
             builtin: Builtin::Assert,
@@ -144,6 +145,30 @@ static BUILTIN_FUNCTIONS: Lazy<[Prototype; 24]> = Lazy::new(|| {
             doc: "Calculates blake2-256 hash",
             constant: true,
         },
+        // The argument of contains_storage and take_storage is a storage reference of any
+        // value type, so these are resolved by resolve_storage_call()
+        Prototype {
+            builtin: Builtin::ContainsStorage,
+            namespace: None,
+            method: vec![],
+            name: "contains_storage",
+            params: vec![Type::Unresolved],
+            ret: vec![Type::Bool],
+            target: vec![Target::default_substrate()],
+            doc: "Checks whether a value is present in contract storage, without reading it",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::TakeStorage,
+            namespace: None,
+            method: vec![],
+            name: "take_storage",
+            params: vec![Type::Unresolved],
+            ret: vec![Type::Unresolved],
+            target: vec![Target::default_substrate()],
+            doc: "Removes a value from contract storage and returns it",
+            constant: false,
+        },
         Prototype {
             builtin: Builtin::Gasleft,
             namespace: None,
@@ -244,7 +269,7 @@ static BUILTIN_FUNCTIONS: Lazy<[Prototype; 24]> = Lazy::new(|| {
             name: "gasprice",
             params: vec![Type::Uint(64)],
             ret: vec![Type::Value],
-            target: vec![],
+            target: vec![Target::default_substrate(), Target::EVM],
             doc: "Calculate price of given gas units",
             constant: false,
         },
@@ -283,6 +308,61 @@ static BUILTIN_FUNCTIONS: Lazy<[Prototype; 24]> = Lazy::new(|| {
             doc: "ed25519 signature verification",
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::GetClock,
+            namespace: Some("Clock"),
+            method: vec![],
+            name: "get",
+            params: vec![],
+            ret: vec![Type::Struct(StructType::Clock)],
+            target: vec![Target::Solana],
+            doc: "Read the Clock sysvar",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::GetEpochSchedule,
+            namespace: Some("EpochSchedule"),
+            method: vec![],
+            name: "get",
+            params: vec![],
+            ret: vec![Type::Struct(StructType::EpochSchedule)],
+            target: vec![Target::Solana],
+            doc: "Read the EpochSchedule sysvar",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::RentMinimumBalance,
+            namespace: Some("Rent"),
+            method: vec![],
+            name: "minimumBalance",
+            params: vec![Type::Uint(64)],
+            ret: vec![Type::Uint(64)],
+            target: vec![Target::Solana],
+            doc: "Minimum balance in lamports for an account with the given number of bytes of data to be rent exempt",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::InstructionsCurrentIndex,
+            namespace: Some("Instructions"),
+            method: vec![],
+            name: "currentIndex",
+            params: vec![],
+            ret: vec![Type::Uint(16)],
+            target: vec![Target::Solana],
+            doc: "Index of the currently executing instruction in the transaction",
+            constant: false,
+        },
+        Prototype {
+            builtin: Builtin::InstructionsLoad,
+            namespace: Some("Instructions"),
+            method: vec![],
+            name: "load",
+            params: vec![Type::Uint(16)],
+            ret: vec![Type::Struct(StructType::LoadedInstruction)],
+            target: vec![Target::Solana],
+            doc: "Load an instruction of the transaction from the instructions sysvar",
+            constant: false,
+        },
         Prototype {
             builtin: Builtin::UserTypeWrap,
             namespace: None,
@@ -516,7 +596,7 @@ static BUILTIN_VARIABLE: Lazy<[Prototype; 18]> = Lazy::new(|| {
 });
 
 // A list of all Solidity builtins methods
-static BUILTIN_METHODS: Lazy<[Prototype; 27]> = Lazy::new(|| {
+static BUILTIN_METHODS: Lazy<[Prototype; 28]> = Lazy::new(|| {
     [
         Prototype {
             builtin: Builtin::ReadInt8,
@@ -815,6 +895,20 @@ static BUILTIN_METHODS: Lazy<[Prototype; 27]> = Lazy::new(|| {
             doc: "Write the contents of a bytes array (without its length) to the specified offset",
             constant: false,
         },
+        Prototype {
+            builtin: Builtin::AccountRealloc,
+            namespace: None,
+            method: vec![
+                Type::Struct(StructType::AccountInfo),
+                Type::Ref(Box::new(Type::Struct(StructType::AccountInfo))),
+            ],
+            name: "realloc",
+            params: vec![Type::Uint(64)],
+            ret: vec![],
+            target: vec![Target::Solana],
+            doc: "Resize the data of an account owned by this program",
+            constant: false,
+        },
     ]
 });
 
@@ -888,25 +982,19 @@ pub fn builtin_namespace(namespace: &str) -> bool {
         .any(|p| p.namespace == Some(namespace))
 }
 
-/// Is name reserved for builtins
-pub fn is_reserved(fname: &str) -> bool {
+/// Is name reserved for builtins on the given target
+pub fn is_reserved(fname: &str, target: Target) -> bool {
     if fname == "type" || fname == "super" || fname == "this" {
         return true;
     }
 
-    let is_builtin_function = BUILTIN_FUNCTIONS.iter().any(|p| {
-        (p.name == fname && p.namespace.is_none() && p.method.is_empty())
-            || (p.namespace == Some(fname))
-    });
-
-    if is_builtin_function {
-        return true;
-    }
+    let is_reserved_by = |p: &Prototype| {
+        ((p.name == fname && p.namespace.is_none() && p.method.is_empty())
+            || (p.namespace == Some(fname)))
+            && (p.target.is_empty() || p.target.contains(&target))
+    };
 
-    BUILTIN_VARIABLE.iter().any(|p| {
-        (p.name == fname && p.namespace.is_none() && p.method.is_empty())
-            || (p.namespace == Some(fname))
-    })
+    BUILTIN_FUNCTIONS.iter().any(is_reserved_by) || BUILTIN_VARIABLE.iter().any(is_reserved_by)
 }
 
 /// Resolve a builtin call
@@ -924,6 +1012,16 @@ pub(super) fn resolve_call(
         .iter()
         .filter(|p| p.name == id && p.namespace == namespace && p.method.is_empty())
         .collect::<Vec<&Prototype>>();
+
+    if let [func] = funcs.as_slice() {
+        if matches!(
+            func.builtin,
+            Builtin::ContainsStorage | Builtin::TakeStorage
+        ) {
+            return resolve_storage_call(loc, func, args, context, ns, symtable, diagnostics);
+        }
+    }
+
     let mut errors: Diagnostics = Diagnostics::default();
 
     for func in &funcs {
@@ -998,7 +1096,7 @@ pub(super) fn resolve_call(
                             String::from(
                                 "the function call 'tx.gasprice(1)' may round down to zero. See https://solang.readthedocs.io/en/latest/language/builtins.html#gasprice",
                             ),
-                        ));
+                        ).with_code(codes::GASPRICE_ROUNDING));
                     }
                 }
             }
@@ -1024,6 +1122,112 @@ pub(super) fn resolve_call(
     Err(())
 }
 
+/// Resolve a call to `contains_storage` or `take_storage`. The argument is a storage reference
+/// rather than a value, and the type `take_storage` returns is the type it references.
+fn resolve_storage_call(
+    loc: &pt::Loc,
+    func: &Prototype,
+    args: &[pt::Expression],
+    context: &ExprContext,
+    ns: &mut Namespace,
+    symtable: &mut Symtable,
+    diagnostics: &mut Diagnostics,
+) -> Result<Expression, ()> {
+    if context.constant {
+        diagnostics.push(Diagnostic::error(
+            *loc,
+            format!(
+                "cannot call function '{}' in constant expression",
+                func.name
+            ),
+        ));
+        return Err(());
+    }
+
+    if args.len() != 1 {
+        diagnostics.push(Diagnostic::error(
+            *loc,
+            format!(
+                "builtin function '{}' expects 1 arguments, {} provided",
+                func.name,
+                args.len()
+            ),
+        ));
+        return Err(());
+    }
+
+    let arg = expression(
+        &args[0],
+        context,
+        ns,
+        symtable,
+        diagnostics,
+        ResolveTo::Unknown,
+    )?;
+
+    let ty = match arg.ty() {
+        Type::StorageRef(_, ty) => ty,
+        _ => {
+            diagnostics.push(Diagnostic::error(
+                arg.loc(),
+                format!("argument to '{}' should be storage reference", func.name),
+            ));
+            return Err(());
+        }
+    };
+
+    if let Expression::StorageVariable {
+        contract_no,
+        var_no,
+        ..
+    } = &arg
+    {
+        if ns.contracts[*contract_no].variables[*var_no].transient {
+            diagnostics.push(Diagnostic::error(
+                arg.loc(),
+                format!(
+                    "'{}' cannot be used on transient storage variables",
+                    func.name
+                ),
+            ));
+            return Err(());
+        }
+    }
+
+    // Only values which are stored under a single key can be checked or taken in one go
+    let single_key = match ty.as_ref() {
+        Type::UserType(no) => ns.user_types[*no].ty.is_primitive(),
+        Type::Enum(_) | Type::Contract(_) => true,
+        Type::String | Type::DynamicBytes => func.builtin == Builtin::ContainsStorage,
+        ty => ty.is_primitive(),
+    };
+
+    if !single_key {
+        diagnostics.push(Diagnostic::error(
+            arg.loc(),
+            format!(
+                "'{}' cannot be used on storage of type '{}'",
+                func.name,
+                ty.to_string(ns)
+            ),
+        ));
+        return Err(());
+    }
+
+    let ret = if func.builtin == Builtin::TakeStorage {
+        ty.as_ref().clone()
+    } else {
+        Type::Bool
+    };
+
+    Ok(Expression::Builtin {
+        loc: *loc,
+        tys: vec![ret],
+        kind: func.builtin,
+        args: vec![arg],
+    })
+}
+
 /// Resolve a builtin namespace call. The takes the unresolved arguments, since it has
 /// to handle the special case "abi.decode(foo, (int32, bool, address))" where the
 /// second argument is a type list. The generic expression resolver cannot deal with
@@ -1464,6 +1668,7 @@ impl Namespace {
             path: PathBuf::from("solana"),
             line_starts: Vec::new(),
             cache_no: None,
+            suppressions: Vec::new(),
         });
 
         let id = pt::Identifier {
@@ -1490,6 +1695,24 @@ impl Namespace {
             Symbol::Struct(pt::Loc::Builtin, StructType::AccountMeta)
         ));
 
+        for (name, struct_ty) in [
+            ("Clock", StructType::Clock),
+            ("EpochSchedule", StructType::EpochSchedule),
+            ("LoadedInstruction", StructType::LoadedInstruction),
+        ] {
+            let id = pt::Identifier {
+                loc: pt::Loc::Builtin,
+                name: String::from(name),
+            };
+
+            assert!(self.add_symbol(
+                file_no,
+                None,
+                &id,
+                Symbol::Struct(pt::Loc::Builtin, struct_ty)
+            ));
+        }
+
         let mut func = Function::new(
             pt::Loc::Builtin,
             "create_program_address".to_string(),
@@ -1648,6 +1871,7 @@ impl Namespace {
             path: PathBuf::from("substrate"),
             line_starts: Vec::new(),
             cache_no: None,
+            suppressions: Vec::new(),
         });
 
         // The Hash type from ink primitives.
@@ -1775,5 +1999,189 @@ impl Namespace {
         self.functions.push(func);
 
         assert!(self.add_symbol(file_no, None, &id, Symbol::Function(vec![(loc, func_no)])));
+
+        // set_code_hash API
+        let mut func = Function::new(
+            loc,
+            "set_code_hash".to_string(),
+            None,
+            Vec::new(),
+            pt::FunctionTy::Function,
+            None,
+            pt::Visibility::Public(Some(loc)),
+            vec![Parameter {
+                loc,
+                id: Some(identifier("code_hash")),
+                ty: Type::Bytes(32),
+                ty_loc: Some(loc),
+                readonly: false,
+                indexed: false,
+                infinite_size: false,
+                recursive: false,
+                annotation: None,
+            }],
+            Vec::new(),
+            self,
+        );
+
+        func.has_body = true;
+        let func_no = self.functions.len();
+        let id = identifier(&func.name);
+        self.functions.push(func);
+
+        assert!(self.add_symbol(file_no, None, &id, Symbol::Function(vec![(loc, func_no)])));
+
+        let parameter = |name: &str, ty: Type| Parameter {
+            loc,
+            id: Some(identifier(name)),
+            ty,
+            ty_loc: Some(loc),
+            readonly: false,
+            indexed: false,
+            infinite_size: false,
+            recursive: false,
+            annotation: None,
+        };
+
+        // Cryptographic APIs
+        for (name, params, returns) in [
+            (
+                "ecdsa_recover",
+                vec![
+                    parameter("signature", Type::DynamicBytes),
+                    parameter("message_hash", Type::Bytes(32)),
+                ],
+                vec![
+                    parameter("success", Type::Bool),
+                    parameter("public_key", Type::DynamicBytes),
+                ],
+            ),
+            (
+                "ecdsa_to_eth_address",
+                vec![parameter("public_key", Type::DynamicBytes)],
+                vec![
+                    parameter("success", Type::Bool),
+                    parameter("eth_address", Type::Bytes(20)),
+                ],
+            ),
+            (
+                "sr25519_verify",
+                vec![
+                    parameter("public_key", Type::Address(false)),
+                    parameter("message", Type::DynamicBytes),
+                    parameter("signature", Type::DynamicBytes),
+                ],
+                vec![parameter("valid", Type::Bool)],
+            ),
+        ] {
+            let mut func = Function::new(
+                loc,
+                name.to_string(),
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                Some(pt::Mutability::Pure(loc)),
+                pt::Visibility::Public(Some(loc)),
+                params,
+                returns,
+                self,
+            );
+
+            func.has_body = true;
+            let func_no = self.functions.len();
+            let id = identifier(&func.name);
+            self.functions.push(func);
+
+            assert!(self.add_symbol(file_no, None, &id, Symbol::Function(vec![(loc, func_no)])));
+        }
+
+        // Runtime call and XCM APIs
+        for (name, params, returns) in [
+            (
+                "call_runtime",
+                vec![parameter("call", Type::DynamicBytes)],
+                vec![parameter("success", Type::Bool)],
+            ),
+            (
+                "xcm_execute",
+                vec![parameter("message", Type::DynamicBytes)],
+                vec![parameter("success", Type::Bool)],
+            ),
+            (
+                "xcm_send",
+                vec![
+                    parameter("destination", Type::DynamicBytes),
+                    parameter("message", Type::DynamicBytes),
+                ],
+                vec![
+                    parameter("success", Type::Bool),
+                    parameter("message_hash", Type::UserType(type_no)),
+                ],
+            ),
+        ] {
+            let mut func = Function::new(
+                loc,
+                name.to_string(),
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                None,
+                pt::Visibility::Public(Some(loc)),
+                params,
+                returns,
+                self,
+            );
+
+            func.has_body = true;
+            let func_no = self.functions.len();
+            let id = identifier(&func.name);
+            self.functions.push(func);
+
+            assert!(self.add_symbol(file_no, None, &id, Symbol::Function(vec![(loc, func_no)])));
+        }
+
+        // Caller and reentrancy introspection APIs
+        for (name, params, returns) in [
+            (
+                "caller_is_origin",
+                vec![],
+                vec![parameter("is_origin", Type::Bool)],
+            ),
+            (
+                "caller_is_root",
+                vec![],
+                vec![parameter("is_root", Type::Bool)],
+            ),
+            (
+                "reentrance_count",
+                vec![],
+                vec![parameter("count", Type::Uint(32))],
+            ),
+            (
+                "account_reentrance_count",
+                vec![parameter("account", Type::Address(false))],
+                vec![parameter("count", Type::Uint(32))],
+            ),
+        ] {
+            let mut func = Function::new(
+                loc,
+                name.to_string(),
+                None,
+                Vec::new(),
+                pt::FunctionTy::Function,
+                Some(pt::Mutability::View(loc)),
+                pt::Visibility::Public(Some(loc)),
+                params,
+                returns,
+                self,
+            );
+
+            func.has_body = true;
+            let func_no = self.functions.len();
+            let id = identifier(&func.name);
+            self.functions.push(func);
+
+            assert!(self.add_symbol(file_no, None, &id, Symbol::Function(vec![(loc, func_no)])));
+        }
     }
 }
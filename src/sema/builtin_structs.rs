@@ -1,10 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::sema::ast::{Namespace, Parameter, StructDecl, StructType, Type};
+use crate::sema::ast::{ArrayLength, Namespace, Parameter, StructDecl, StructType, Type};
 use once_cell::sync::Lazy;
 use solang_parser::pt;
 
-static BUILTIN_STRUCTS: Lazy<[StructDecl; 3]> = Lazy::new(|| {
+static BUILTIN_STRUCTS: Lazy<[StructDecl; 6]> = Lazy::new(|| {
     [
         StructDecl {
             tags: Vec::new(),
@@ -212,6 +212,221 @@ static BUILTIN_STRUCTS: Lazy<[StructDecl; 3]> = Lazy::new(|| {
             offsets: Vec::new(),
             storage_offsets: Vec::new(),
         },
+        StructDecl {
+            tags: Vec::new(),
+            loc: pt::Loc::Builtin,
+            contract: None,
+            name: "Clock".to_string(),
+            fields: vec![
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("slot"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Uint(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("epoch_start_timestamp"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Int(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("epoch"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Uint(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("leader_schedule_epoch"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Uint(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("unix_timestamp"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Int(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+            ],
+            offsets: Vec::new(),
+            storage_offsets: Vec::new(),
+        },
+        StructDecl {
+            tags: Vec::new(),
+            loc: pt::Loc::Builtin,
+            contract: None,
+            name: "EpochSchedule".to_string(),
+            fields: vec![
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("slots_per_epoch"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Uint(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("leader_schedule_slot_offset"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Uint(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("warmup"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Bool,
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("first_normal_epoch"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Uint(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("first_normal_slot"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Uint(64),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+            ],
+            offsets: Vec::new(),
+            storage_offsets: Vec::new(),
+        },
+        StructDecl {
+            tags: Vec::new(),
+            loc: pt::Loc::Builtin,
+            contract: None,
+            name: "LoadedInstruction".to_string(),
+            fields: vec![
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("program_id"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Address(false),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("accounts"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::Array(
+                        Box::new(Type::Struct(StructType::AccountMeta)),
+                        vec![ArrayLength::Dynamic],
+                    ),
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+                Parameter {
+                    loc: pt::Loc::Builtin,
+                    id: Some(pt::Identifier {
+                        name: String::from("data"),
+                        loc: pt::Loc::Builtin,
+                    }),
+                    ty: Type::DynamicBytes,
+                    ty_loc: None,
+                    indexed: false,
+                    readonly: false,
+                    infinite_size: false,
+                    recursive: false,
+                    annotation: None,
+                },
+            ],
+            offsets: Vec::new(),
+            storage_offsets: Vec::new(),
+        },
     ]
 });
 
@@ -223,6 +438,9 @@ impl StructType {
             StructType::AccountMeta => &BUILTIN_STRUCTS[1],
             StructType::ExternalFunction => &BUILTIN_STRUCTS[2],
             StructType::SolParameters => unreachable!("SolParameters is defined in a solana.c"),
+            StructType::Clock => &BUILTIN_STRUCTS[3],
+            StructType::EpochSchedule => &BUILTIN_STRUCTS[4],
+            StructType::LoadedInstruction => &BUILTIN_STRUCTS[5],
         }
     }
 }
@@ -0,0 +1,548 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Every diagnostic carries a stable code, so that it can be looked up with `solang explain`,
+//! and shown by all the output formats. Errors and warnings without a specific code get the
+//! generic code for their type, which the `Diagnostic` constructors in solang-parser set.
+//!
+//! Codes are never reused or renumbered; when a diagnostic is removed, its code is retired.
+
+// The generic codes are defined in solang-parser, since its constructors set them
+pub use solang_parser::diagnostics::codes::{
+    CAST_ERROR, DEBUG, DECLARATION_ERROR, ERROR, INFO, PARSER_ERROR, SYNTAX_ERROR, TYPE_ERROR,
+    WARNING,
+};
+
+pub const UNDEFINED_VARIABLE: &str = "E0010";
+pub const IMPORT_NOT_FOUND: &str = "E0011";
+
+pub const BUILTIN_SHADOWING: &str = "W0010";
+pub const SHADOWING: &str = "W0011";
+pub const UNUSED_VARIABLE: &str = "W0012";
+pub const UNUSED_PARAMETER: &str = "W0013";
+pub const UNASSIGNED_RETURN_VARIABLE: &str = "W0014";
+pub const UNUSED_STORAGE_VARIABLE: &str = "W0015";
+pub const UNUSED_CONSTANT: &str = "W0016";
+pub const UNUSED_EVENT: &str = "W0017";
+pub const UNUSED_ERROR: &str = "W0018";
+pub const UNUSED_YUL_FUNCTION: &str = "W0019";
+pub const UNREACHABLE_CODE: &str = "W0020";
+pub const RESTRICTABLE_MUTABILITY: &str = "W0021";
+pub const DEPRECATED_CONSTANT: &str = "W0022";
+pub const CONSTRUCTOR_VISIBILITY: &str = "W0023";
+pub const IMPLICIT_VIRTUAL: &str = "W0024";
+pub const DELETE_NON_STORAGE: &str = "W0025";
+pub const TRUNCATING_CONVERSION: &str = "W0026";
+pub const FOREIGN_CURRENCY_UNIT: &str = "W0027";
+pub const GASPRICE_ROUNDING: &str = "W0028";
+pub const DUPLICATE_TAG: &str = "W0029";
+pub const SHIFT_OVERFLOW: &str = "W0030";
+pub const UNKNOWN_PRAGMA: &str = "W0031";
+pub const REDEFINED_OPERATOR: &str = "W0032";
+pub const UNKNOWN_DIAGNOSTIC_CODE: &str = "W0033";
+
+/// The documentation for a diagnostic code
+pub struct CodeInfo {
+    pub code: &'static str,
+    /// Short kebab-case name, which can be used instead of the code
+    pub name: &'static str,
+    pub summary: &'static str,
+    /// Long explanation with example code, printed by `solang explain`
+    pub explanation: &'static str,
+}
+
+/// Find the documentation for a code. Either the code or its name can be given, and the
+/// case is ignored.
+pub fn lookup(code_or_name: &str) -> Option<&'static CodeInfo> {
+    CODES.iter().find(|info| {
+        info.code.eq_ignore_ascii_case(code_or_name) || info.name.eq_ignore_ascii_case(code_or_name)
+    })
+}
+
+pub static CODES: &[CodeInfo] = &[
+    CodeInfo {
+        code: ERROR,
+        name: "error",
+        summary: "General error",
+        explanation: r#"The code is not valid. This is the generic code for errors which are not
+covered by a more specific code; the message describes the problem.
+"#,
+    },
+    CodeInfo {
+        code: PARSER_ERROR,
+        name: "parser-error",
+        summary: "The source could not be parsed",
+        explanation: r#"The source file is not syntactically valid Solidity, so it cannot be parsed.
+This is often caused by a missing semicolon, parenthesis or brace.
+
+    contract c {
+        function f() public {
+            int x = 1   // missing ';'
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: SYNTAX_ERROR,
+        name: "syntax-error",
+        summary: "Invalid syntax or semantics",
+        explanation: r#"The code parses, but it is not valid Solidity. For example, a statement is
+used in a context where it is not permitted.
+
+    contract c {
+        function f() public {
+            break;      // 'break' outside of a loop
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: DECLARATION_ERROR,
+        name: "declaration-error",
+        summary: "Invalid or conflicting declaration",
+        explanation: r#"A declaration is not valid, or a name is declared twice in the same scope,
+or a name is used which was never declared.
+
+    contract c {
+        function f() public {
+            int x;
+            int x;      // 'x' already used in this scope
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: CAST_ERROR,
+        name: "cast-error",
+        summary: "Invalid type conversion",
+        explanation: r#"A value cannot be converted to the required type, either implicitly or with
+an explicit cast. Implicit conversions are only allowed if no information can be lost.
+
+    contract c {
+        function f(int64 a) public {
+            int32 b = a;    // implicit conversion would truncate
+        }
+    }
+
+Use an explicit cast like `int32(a)` if truncation is intended.
+"#,
+    },
+    CodeInfo {
+        code: TYPE_ERROR,
+        name: "type-error",
+        summary: "Type mismatch",
+        explanation: r#"An expression has a type which is not permitted where it is used, for
+example an arithmetic operation on a type which does not support it.
+
+    contract c {
+        function f(bool a) public returns (bool) {
+            return a + 1;   // bool does not support '+'
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: UNDEFINED_VARIABLE,
+        name: "undefined-variable",
+        summary: "A variable is read before it is assigned a value",
+        explanation: r#"A variable is read on a path where it has not been assigned a value. This is
+an error for storage references, since they must point to storage.
+
+    contract c {
+        int[] arr;
+
+        function f(bool cond) public {
+            int[] storage ref;
+            if (cond) {
+                ref = arr;
+            }
+            ref.push(1);    // 'ref' is undefined if cond is false
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: IMPORT_NOT_FOUND,
+        name: "import-not-found",
+        summary: "An imported file could not be found",
+        explanation: r#"The file given in an import directive could not be found. The message lists
+every path which was tried. Imports are resolved in this order:
+
+1. Remappings given with `--remapping`, in `remappings.txt` or in solang.toml rewrite the
+   start of the import path first
+2. If the first directory matches an `--importmap`, the file is looked for in its path
+3. Relative to the directory of the importing file
+4. In each `--importpath`, and the directories of the input files
+5. In the `node_modules` directory of the importing file's directory, or any directory above it
+
+    import "@openzeppelin/contracts/token/ERC20/ERC20.sol";
+
+Either install the package with npm, or map the prefix to where the package is:
+
+    solang compile --remapping @openzeppelin/=lib/openzeppelin-contracts/ ...
+"#,
+    },
+    CodeInfo {
+        code: WARNING,
+        name: "warning",
+        summary: "General warning",
+        explanation: r#"The code is valid but is likely to be a mistake. This is the generic code for
+warnings which are not covered by a more specific code; the message describes the problem.
+"#,
+    },
+    CodeInfo {
+        code: BUILTIN_SHADOWING,
+        name: "builtin-shadowing",
+        summary: "A declaration shadows a builtin",
+        explanation: r#"A declaration has the same name as a builtin, so the builtin can no longer be
+used in that scope.
+
+    contract c {
+        function f() public {
+            int block = 1;  // shadows the builtin 'block'
+        }
+    }
+
+Rename the declaration.
+"#,
+    },
+    CodeInfo {
+        code: SHADOWING,
+        name: "shadowing",
+        summary: "A declaration shadows another declaration",
+        explanation: r#"A declaration has the same name as a declaration in an enclosing scope, so
+the outer declaration cannot be used in this scope.
+
+    contract c {
+        int x;
+
+        function f() public {
+            int x = 1;      // shadows the state variable 'x'
+        }
+    }
+
+Rename one of the declarations.
+"#,
+    },
+    CodeInfo {
+        code: UNUSED_VARIABLE,
+        name: "unused-variable",
+        summary: "A local variable is never read",
+        explanation: r#"A local variable is declared, and possibly assigned, but its value is never
+read. This is often a mistake, or left over from earlier code.
+
+    contract c {
+        function f() public pure {
+            int x = 1;      // 'x' is never read
+        }
+    }
+
+Remove the variable, or use it.
+"#,
+    },
+    CodeInfo {
+        code: UNUSED_PARAMETER,
+        name: "unused-parameter",
+        summary: "A function parameter is never used",
+        explanation: r#"A function parameter is never read.
+
+    contract c {
+        function f(int x) public pure returns (int) {
+            return 1;       // 'x' is unused
+        }
+    }
+
+If the parameter is needed for the function signature, remove its name:
+`function f(int) public pure`.
+"#,
+    },
+    CodeInfo {
+        code: UNASSIGNED_RETURN_VARIABLE,
+        name: "unassigned-return-variable",
+        summary: "A named return variable is never assigned",
+        explanation: r#"A named return variable is never assigned, so the function always returns
+its default value.
+
+    contract c {
+        function f() public pure returns (int ret) {
+            int x = 1;      // 'ret' is never assigned
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: UNUSED_STORAGE_VARIABLE,
+        name: "unused-storage-variable",
+        summary: "A storage variable is never read",
+        explanation: r#"A contract storage variable is never read, so the storage it occupies is
+wasted.
+
+    contract c {
+        int x;              // 'x' is never used
+
+        function f() public {}
+    }
+"#,
+    },
+    CodeInfo {
+        code: UNUSED_CONSTANT,
+        name: "unused-constant",
+        summary: "A global constant is never used",
+        explanation: r#"A constant declared at file level is never used.
+
+    int constant FOO = 1;   // 'FOO' is never used
+
+    contract c {}
+"#,
+    },
+    CodeInfo {
+        code: UNUSED_EVENT,
+        name: "unused-event",
+        summary: "An event is never emitted",
+        explanation: r#"An event is declared but never emitted. It is still part of the contract
+metadata, which is misleading for users of the contract.
+
+    contract c {
+        event Foo(int a);   // 'Foo' is never emitted
+    }
+"#,
+    },
+    CodeInfo {
+        code: UNUSED_ERROR,
+        name: "unused-error",
+        summary: "An error is never used",
+        explanation: r#"A custom error is declared but never used in a revert statement.
+
+    contract c {
+        error Foo(int a);   // 'Foo' is never used
+    }
+"#,
+    },
+    CodeInfo {
+        code: UNUSED_YUL_FUNCTION,
+        name: "unused-yul-function",
+        summary: "A yul function is never called",
+        explanation: r#"A function declared in an assembly block is never called.
+
+    contract c {
+        function f() public {
+            assembly {
+                function g() {}     // 'g' is never called
+            }
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: UNREACHABLE_CODE,
+        name: "unreachable-code",
+        summary: "A statement can never be executed",
+        explanation: r#"A statement follows a statement which never completes, so it can never be
+executed.
+
+    contract c {
+        function f() public {
+            assembly {
+                return(0, 0)
+                let x := 1          // unreachable
+            }
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: RESTRICTABLE_MUTABILITY,
+        name: "restrictable-mutability",
+        summary: "A function can be declared 'pure' or 'view'",
+        explanation: r#"A function does not read or write contract storage, or does not write it, so
+it can be declared with a more restrictive mutability. This documents the behaviour of the
+function, and allows it to be called without a transaction.
+
+    contract c {
+        function f(int a) public returns (int) {   // can be 'pure'
+            return a + 1;
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: DEPRECATED_CONSTANT,
+        name: "deprecated-constant",
+        summary: "'constant' is deprecated as function mutability",
+        explanation: r#"The `constant` function mutability was replaced by `view`.
+
+    contract c {
+        int x;
+
+        function f() public constant returns (int) {   // use 'view'
+            return x;
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: CONSTRUCTOR_VISIBILITY,
+        name: "constructor-visibility",
+        summary: "Visibility for constructors is ignored",
+        explanation: r#"Constructors do not have a visibility, so the given visibility is ignored.
+Declare the contract `abstract` to prevent it from being deployed.
+
+    contract c {
+        constructor() public {}     // 'public' is ignored
+    }
+"#,
+    },
+    CodeInfo {
+        code: IMPLICIT_VIRTUAL,
+        name: "implicit-virtual",
+        summary: "Interface functions are implicitly virtual",
+        explanation: r#"Functions in an interface are always virtual, so `virtual` is redundant.
+
+    interface i {
+        function f() external virtual;  // 'virtual' is redundant
+    }
+"#,
+    },
+    CodeInfo {
+        code: DELETE_NON_STORAGE,
+        name: "delete-non-storage",
+        summary: "'delete' on a value which is not in storage",
+        explanation: r#"The argument to `delete` should be a storage reference. For other values,
+assign the default value instead.
+
+    contract c {
+        function f() public pure {
+            int x = 1;
+            delete x;       // use 'x = 0'
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: TRUNCATING_CONVERSION,
+        name: "truncating-conversion",
+        summary: "A conversion may truncate the value",
+        explanation: r#"A conversion is permitted, but the value may not fit into the target type on
+the current target, so it may be truncated.
+
+    contract c {
+        function f(uint256 a) public pure returns (bytes memory) {
+            return new bytes(a);    // memory size is 32 bits
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: FOREIGN_CURRENCY_UNIT,
+        name: "foreign-currency-unit",
+        summary: "A currency unit of another chain is used",
+        explanation: r#"A currency unit like `ether` or `sol` is used while targeting a chain which
+does not use that currency, so the value is probably not what was intended.
+
+    contract c {
+        function f() public pure returns (uint64) {
+            return 1 ether;         // while targeting Solana
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: GASPRICE_ROUNDING,
+        name: "gasprice-rounding",
+        summary: "'tx.gasprice(1)' may round down to zero",
+        explanation: r#"On Substrate, the price of a single unit of gas is likely to round down to
+zero. Ask for the price of a larger amount of gas instead.
+
+    contract c {
+        function f() public view returns (uint128) {
+            return tx.gasprice(1);  // use tx.gasprice(1000) / 1000
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: DUPLICATE_TAG,
+        name: "duplicate-tag",
+        summary: "A doc comment tag is given twice",
+        explanation: r#"A doc comment documents the same parameter twice.
+
+    contract c {
+        /// @param a first
+        /// @param a second
+        function f(int a) public {}
+    }
+"#,
+    },
+    CodeInfo {
+        code: SHIFT_OVERFLOW,
+        name: "shift-overflow",
+        summary: "A left shift may overflow",
+        explanation: r#"A constant left shift moves bits out of the type, so the result may overflow.
+
+    contract c {
+        function f() public pure returns (uint8) {
+            return uint8(1) << 9;   // overflows
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: UNKNOWN_PRAGMA,
+        name: "unknown-pragma",
+        summary: "An unknown pragma is ignored",
+        explanation: r#"The pragma is not known to Solang, so it has no effect.
+
+    pragma foo bar;
+
+    contract c {}
+"#,
+    },
+    CodeInfo {
+        code: REDEFINED_OPERATOR,
+        name: "redefined-operator",
+        summary: "A user defined operator is redefined to the same function",
+        explanation: r#"A `using` directive binds an operator to the same function as an earlier
+directive, so it has no effect.
+
+    type Int is int;
+
+    function add(Int a, Int b) pure returns (Int) {
+        return Int.wrap(Int.unwrap(a) + Int.unwrap(b));
+    }
+
+    using {add as +} for Int global;
+    using {add as +} for Int global;    // redundant
+"#,
+    },
+    CodeInfo {
+        code: UNKNOWN_DIAGNOSTIC_CODE,
+        name: "unknown-diagnostic-code",
+        summary: "A solang-disable comment has an unknown code",
+        explanation: r#"A `// solang-disable-next-line`, `// solang-disable` or `// solang-enable`
+comment lists a code which does not exist, so it has no effect. Use `solang explain` to list all
+codes.
+
+    contract c {
+        function f() public pure {
+            // solang-disable-next-line W9999
+            int x;
+        }
+    }
+"#,
+    },
+    CodeInfo {
+        code: INFO,
+        name: "info",
+        summary: "Informational message",
+        explanation: r#"An informational message, which does not indicate a problem. These are only
+printed with `--verbose`.
+"#,
+    },
+    CodeInfo {
+        code: DEBUG,
+        name: "debug",
+        summary: "Debug message",
+        explanation: r#"A message which is useful for debugging Solang itself. These are only printed
+with `--verbose`.
+"#,
+    },
+];
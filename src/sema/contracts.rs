@@ -12,7 +12,7 @@ use crate::sema::expression::constructor::match_constructor_to_args;
 use crate::{sema::ast::Namespace, sema::unused_variable::emit_warning_local_variable};
 use num_bigint::BigInt;
 use num_traits::Zero;
-use once_cell::unsync::OnceCell;
+use once_cell::sync::OnceCell;
 use solang_parser::diagnostics::Diagnostic;
 use solang_parser::pt::FunctionTy;
 use solang_parser::pt::{self, CodeLocation};
@@ -48,6 +48,7 @@ impl ast::Contract {
             instantiable,
             dispatch_no: 0,
             program_id: None,
+            emit_cpi: false,
         }
     }
 
@@ -2,12 +2,14 @@
 
 use super::ast::{Diagnostic, ErrorType, Level, Namespace};
 use crate::file_resolver::FileResolver;
+use crate::sarif::{self, Location, Message, PhysicalLocation, Region, SarifResult};
 use crate::standard_json::{LocJson, OutputJson};
 use codespan_reporting::{diagnostic, files, term};
 use itertools::Itertools;
 use solang_parser::pt::Loc;
 use std::{
     collections::HashMap,
+    path::Path,
     slice::Iter,
     {io, sync::Arc},
 };
@@ -47,6 +49,13 @@ impl Diagnostics {
         self.contents.extend(diagnostics.contents);
     }
 
+    /// Keep only the diagnostics for which the closure returns true. The closure may also
+    /// modify the diagnostic, for example to change its level.
+    pub fn retain_mut(&mut self, f: impl FnMut(&mut Diagnostic) -> bool) {
+        self.contents.retain_mut(f);
+        self.has_error = self.contents.iter().any(|diag| diag.level == Level::Error);
+    }
+
     /// Filter out all the diagnostics which are not the result of casting problems
     pub fn extend_non_casting(&mut self, other: &Diagnostics) -> bool {
         let others: Vec<_> = other
@@ -153,6 +162,7 @@ fn convert_diagnostic(
         Level::Error => diagnostic::Severity::Error,
         Level::Warning => diagnostic::Severity::Warning,
     })
+    .with_code(msg.code)
     .with_message(msg.message.to_owned());
 
     let mut labels = Vec::new();
@@ -254,6 +264,7 @@ impl Namespace {
                 ty: format!("{:?}", msg.ty),
                 component: "general".to_owned(),
                 severity: msg.level.to_string(),
+                errorCode: Some(msg.code.to_owned()),
                 message: msg.message.clone(),
                 formattedMessage: buffer.into_string(),
             });
@@ -262,6 +273,74 @@ impl Namespace {
         json
     }
 
+    /// Convert the diagnostics to SARIF results. The notes of a diagnostic become its related
+    /// locations, and file uris are relative to the given project root.
+    pub fn diagnostics_as_sarif(&self, root: &Path) -> Vec<SarifResult> {
+        let mut results = Vec::new();
+
+        for msg in self.diagnostics.iter() {
+            if msg.level == Level::Info || msg.level == Level::Debug {
+                continue;
+            }
+
+            let locations = self.sarif_location(root, &msg.loc).into_iter().collect();
+
+            let related_locations = msg
+                .notes
+                .iter()
+                .filter_map(|note| {
+                    self.sarif_location(root, &note.loc)
+                        .map(|location| (location, &note.message))
+                })
+                .enumerate()
+                .map(|(no, (location, message))| Location {
+                    id: Some(no + 1),
+                    message: Some(Message {
+                        text: message.clone(),
+                    }),
+                    ..location
+                })
+                .collect();
+
+            results.push(SarifResult {
+                rule_id: msg.code.to_owned(),
+                level: msg.level.to_string(),
+                message: Message {
+                    text: msg.message.clone(),
+                },
+                locations,
+                related_locations,
+            });
+        }
+
+        results
+    }
+
+    fn sarif_location(&self, root: &Path, loc: &Loc) -> Option<Location> {
+        if let Loc::File(file_no, start, end) = loc {
+            let file = &self.files[*file_no];
+
+            let (start_line, start_column) = file.offset_to_line_column(*start);
+            let (end_line, end_column) = file.offset_to_line_column(*end);
+
+            Some(Location {
+                id: None,
+                physical_location: PhysicalLocation {
+                    artifact_location: sarif::artifact_location(root, &file.path),
+                    region: Region {
+                        start_line: start_line + 1,
+                        start_column: start_column + 1,
+                        end_line: end_line + 1,
+                        end_column: end_column + 1,
+                    },
+                },
+                message: None,
+            })
+        } else {
+            None
+        }
+    }
+
     fn convert_files(
         &self,
         cache: &FileResolver,
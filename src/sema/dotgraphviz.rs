@@ -2022,7 +2022,8 @@ impl Dot {
             YulExpression::ConstantVariable(loc, ty, contract, var_no) => {
                 self.add_constant_variable(loc, ty, contract, *var_no, parent, parent_rel, ns);
             }
-            YulExpression::StorageVariable(loc, ty, contract, var_no) => {
+            YulExpression::StorageVariable(loc, ty, contract, var_no)
+            | YulExpression::ImmutableVariable(loc, ty, contract, var_no) => {
                 self.add_storage_variable(loc, ty, *contract, *var_no, parent, parent_rel, ns);
             }
             YulExpression::BuiltInCall(loc, builtin_ty, args) => {
@@ -2625,6 +2626,10 @@ impl Namespace {
                     labels.insert(2, String::from("immutable"));
                 }
 
+                if var.transient {
+                    labels.insert(2, String::from("transient"));
+                }
+
                 if var.constant {
                     labels.insert(2, String::from("constant"));
                 }
@@ -2,6 +2,7 @@
 
 use super::{
     ast::{Diagnostic, Expression, Namespace, Type},
+    codes,
     diagnostics::Diagnostics,
 };
 use num_bigint::BigInt;
@@ -545,10 +546,13 @@ fn eval_constants_in_expression(
                     (None, false)
                 } else {
                     if right >= &BigInt::from(left.bits()) {
-                        ns.diagnostics.push(Diagnostic::warning(
-                            *right_loc,
-                            format!("left shift by {right} may overflow the final result"),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning(
+                                *right_loc,
+                                format!("left shift by {right} may overflow the final result"),
+                            )
+                            .with_code(codes::SHIFT_OVERFLOW),
+                        );
                     }
 
                     (
@@ -730,7 +734,7 @@ pub(super) fn overflow_check(result: &BigInt, ty: &Type, loc: &Loc) -> Option<Di
                 return Some(Diagnostic::error(
                     *loc,
                     format!( "large negative value does not fit into type uint{}. Cannot implicitly convert signed literal to unsigned type.",
-                    ty.get_type_size()),
+                    bits),
                 ));
             }
 
@@ -738,10 +742,7 @@ pub(super) fn overflow_check(result: &BigInt, ty: &Type, loc: &Loc) -> Option<Di
             if result.bits() > *bits as u64 {
                 return Some(Diagnostic::error(
                     *loc,
-                    format!(
-                        "value is too large to fit into type uint{}",
-                        ty.get_type_size(),
-                    ),
+                    format!("value is too large to fit into type uint{}", bits,),
                 ));
             }
         }
@@ -751,10 +752,7 @@ pub(super) fn overflow_check(result: &BigInt, ty: &Type, loc: &Loc) -> Option<Di
             if result.to_signed_bytes_be().len() * 8 > (*bits as usize) {
                 return Some(Diagnostic::error(
                     *loc,
-                    format!(
-                        "value is too large to fit into type int{}",
-                        ty.get_type_size(),
-                    ),
+                    format!("value is too large to fit into type int{}", bits,),
                 ));
             }
         }
@@ -764,7 +762,7 @@ pub(super) fn overflow_check(result: &BigInt, ty: &Type, loc: &Loc) -> Option<Di
             if let Sign::Minus = result.sign() {
                 return Some(Diagnostic::error(
                 *loc,
-            format!( "negative value {} does not fit into type uint{}. Cannot implicitly convert signed literal to unsigned type.",result,ty.get_type_size()),
+            format!( "negative value {} does not fit into type uint{}. Cannot implicitly convert signed literal to unsigned type.",result,bits),
             ));
             }
 
@@ -772,11 +770,7 @@ pub(super) fn overflow_check(result: &BigInt, ty: &Type, loc: &Loc) -> Option<Di
             if result.bits() > *bits as u64 {
                 return Some(Diagnostic::error(
                     *loc,
-                    format!(
-                        "value {} does not fit into type uint{}.",
-                        result,
-                        ty.get_type_size(),
-                    ),
+                    format!("value {} does not fit into type uint{}.", result, bits,),
                 ));
             }
         }
@@ -786,11 +780,7 @@ pub(super) fn overflow_check(result: &BigInt, ty: &Type, loc: &Loc) -> Option<Di
             if result.to_signed_bytes_be().len() * 8 > (*bits as usize) {
                 return Some(Diagnostic::error(
                     *loc,
-                    format!(
-                        "value {} does not fit into type int{}.",
-                        result,
-                        ty.get_type_size(),
-                    ),
+                    format!("value {} does not fit into type int{}.", result, bits,),
                 ));
             }
         }
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast::{ArrayLength, CallArgs, Expression, Namespace, RetrieveType, Type};
+use crate::sema::codes;
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::expression::function_call::{collect_call_args, parse_call_args};
 use crate::sema::expression::resolve_expression::expression;
@@ -522,16 +523,19 @@ pub fn new(
     }
 
     let size = if size_ty.deref_any().bits(ns) > 32 {
-        diagnostics.push(Diagnostic::warning(
-            size_expr.loc(),
-            format!(
-                "conversion truncates {} to {}, as memory size is type {} on target {}",
-                size_ty.deref_any().to_string(ns),
-                expected_ty.to_string(ns),
-                expected_ty.to_string(ns),
-                ns.target
-            ),
-        ));
+        diagnostics.push(
+            Diagnostic::warning(
+                size_expr.loc(),
+                format!(
+                    "conversion truncates {} to {}, as memory size is type {} on target {}",
+                    size_ty.deref_any().to_string(ns),
+                    expected_ty.to_string(ns),
+                    expected_ty.to_string(ns),
+                    ns.target
+                ),
+            )
+            .with_code(codes::TRUNCATING_CONVERSION),
+        );
 
         Expression::CheckingTrunc {
             loc: size_loc,
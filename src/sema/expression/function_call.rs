@@ -1281,7 +1281,7 @@ fn try_type_method(
 
             let ty = match func.name.as_str() {
                 "call" => Some(CallTy::Regular),
-                "delegatecall" if ns.target == Target::EVM => Some(CallTy::Delegate),
+                "delegatecall" if ns.target != Target::Solana => Some(CallTy::Delegate),
                 "staticcall" if ns.target == Target::EVM => Some(CallTy::Static),
                 _ => None,
             };
@@ -2,6 +2,7 @@
 
 use crate::sema::address::to_hexstr_eip55;
 use crate::sema::ast::{ArrayLength, Expression, Namespace, RetrieveType, StructType, Type};
+use crate::sema::codes;
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::expression::integers::bigint_to_expression;
 use crate::sema::expression::resolve_expression::expression;
@@ -527,16 +528,22 @@ pub(crate) fn unit_literal(
     if let Some(unit) = unit {
         match unit.name.as_str() {
             "wei" | "gwei" | "ether" if ns.target != crate::Target::EVM => {
-                diagnostics.push(Diagnostic::warning(
-                    *loc,
-                    format!("ethereum currency unit used while targeting {}", ns.target),
-                ));
+                diagnostics.push(
+                    Diagnostic::warning(
+                        *loc,
+                        format!("ethereum currency unit used while targeting {}", ns.target),
+                    )
+                    .with_code(codes::FOREIGN_CURRENCY_UNIT),
+                );
             }
             "sol" | "lamports" if ns.target != crate::Target::Solana => {
-                diagnostics.push(Diagnostic::warning(
-                    *loc,
-                    format!("solana currency unit used while targeting {}", ns.target),
-                ));
+                diagnostics.push(
+                    Diagnostic::warning(
+                        *loc,
+                        format!("solana currency unit used while targeting {}", ns.target),
+                    )
+                    .with_code(codes::FOREIGN_CURRENCY_UNIT),
+                );
             }
             _ => (),
         }
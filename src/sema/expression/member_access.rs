@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::sema::ast::{ArrayLength, Builtin, Expression, Namespace, RetrieveType, Symbol, Type};
+use crate::sema::ast::{
+    ArrayLength, Builtin, Expression, Namespace, RetrieveType, StructType, Symbol, Type,
+};
 use crate::sema::builtin;
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::expression::constructor::circular_reference;
@@ -8,8 +10,10 @@ use crate::sema::expression::function_call::function_type;
 use crate::sema::expression::integers::bigint_to_expression;
 use crate::sema::expression::resolve_expression::expression;
 use crate::sema::expression::{ExprContext, ResolveTo};
+use crate::sema::solana_accounts::BuiltinAccounts;
 use crate::sema::symtable::Symtable;
 use crate::sema::unused_variable::{assigned_variable, used_variable};
+use crate::Target;
 use num_bigint::{BigInt, Sign};
 use num_traits::{FromPrimitive, One, Zero};
 use solang_parser::diagnostics::Diagnostic;
@@ -51,6 +55,11 @@ pub(super) fn member_access(
         }
     }
 
+    // is it a declared account, like "tx.accounts.authority"
+    if let Some(expr) = named_account(loc, e, id, context, ns, diagnostics)? {
+        return Ok(expr);
+    }
+
     // is it an enum value
     if let Some(expr) = enum_value(
         loc,
@@ -747,3 +756,72 @@ fn type_name_expr(
         }
     }
 }
+
+/// Resolve an account declared with an annotation, e.g. `tx.accounts.authority` for
+/// `@signer(authority)`. This is a subscript of tx.accounts, since the position of
+/// declared accounts is known in sema.
+fn named_account(
+    loc: &pt::Loc,
+    e: &pt::Expression,
+    id: &pt::Identifier,
+    context: &ExprContext,
+    ns: &Namespace,
+    diagnostics: &mut Diagnostics,
+) -> Result<Option<Expression>, ()> {
+    if ns.target != Target::Solana || id.name == "length" {
+        return Ok(None);
+    }
+
+    match e {
+        pt::Expression::MemberAccess(_, tx, accounts)
+            if accounts.name == "accounts"
+                && matches!(tx.as_ref(), pt::Expression::Variable(tx) if tx.name == "tx") => {}
+        _ => return Ok(None),
+    }
+
+    let index = context.function_no.and_then(|function_no| {
+        let func = &ns.functions[function_no];
+
+        // Codegen always inserts the data account first, unless the function is pure
+        if id.name == BuiltinAccounts::DataAccount {
+            (!func.is_pure()).then_some(0)
+        } else {
+            func.declared_account_index(&id.name)
+        }
+    });
+
+    let index = if let Some(index) = index {
+        index
+    } else {
+        diagnostics.push(Diagnostic::error(
+            id.loc,
+            format!(
+                "account '{}' not declared. Accounts can be declared with annotations like '@account({})' on the function",
+                id.name, id.name
+            ),
+        ));
+        return Err(());
+    };
+
+    let array_ty = Type::Array(
+        Box::new(Type::Struct(StructType::AccountInfo)),
+        vec![ArrayLength::Dynamic],
+    );
+
+    Ok(Some(Expression::Subscript {
+        loc: *loc,
+        ty: Type::Ref(Box::new(Type::Struct(StructType::AccountInfo))),
+        array_ty: array_ty.clone(),
+        array: Box::new(Expression::Builtin {
+            loc: e.loc(),
+            tys: vec![array_ty],
+            kind: Builtin::Accounts,
+            args: vec![],
+        }),
+        index: Box::new(Expression::NumberLiteral {
+            loc: id.loc,
+            ty: Type::Uint(32),
+            value: BigInt::from(index),
+        }),
+    }))
+}
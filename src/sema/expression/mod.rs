@@ -15,6 +15,7 @@ mod tests;
 mod variable;
 
 use super::ast::{ArrayLength, Diagnostic, Expression, Mutability, Namespace, RetrieveType, Type};
+use super::codes;
 use super::diagnostics::Diagnostics;
 use super::eval::eval_const_rational;
 use crate::sema::contracts::is_base;
@@ -694,16 +695,19 @@ impl Expression {
 
                 match from_len.cmp(&to_len) {
                     Ordering::Greater => {
-                        diagnostics.push(Diagnostic::cast_warning(
-                            *loc,
-                            format!(
+                        diagnostics.push(
+                            Diagnostic::cast_warning(
+                                *loc,
+                                format!(
                                 "conversion truncates {} to {}, as value is type {} on target {}",
                                 from.to_string(ns),
                                 to.to_string(ns),
                                 Type::Value.to_string(ns),
                                 ns.target
                             ),
-                        ));
+                            )
+                            .with_code(codes::TRUNCATING_CONVERSION),
+                        );
 
                         Ok(Expression::CheckingTrunc {
                             loc: *loc,
@@ -1212,7 +1216,7 @@ impl Expression {
                             format!(
                                 "function selector should only be casted to bytes{selector_length} or larger"
                             ),
-                        ));
+                        ).with_code(codes::TRUNCATING_CONVERSION));
                     }
                     self.cast_types(
                         loc,
@@ -1232,7 +1236,7 @@ impl Expression {
                         format!(
                             "function selector needs an integer of at least {selector_width} bits to avoid being truncated"
                         ),
-                    ));
+                    ).with_code(codes::TRUNCATING_CONVERSION));
                 }
                 self.cast_types(
                     loc,
@@ -24,6 +24,7 @@ impl File {
             path,
             line_starts,
             cache_no: Some(cache_no),
+            suppressions: Vec::new(),
         }
     }
 
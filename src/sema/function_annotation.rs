@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::{
-    ast::{ConstructorAnnotation, Diagnostic, Expression, Function, Namespace, Type},
+    ast::{ConstructorAnnotation, Diagnostic, Expression, Function, Mutability, Namespace, Type},
     diagnostics::Diagnostics,
     eval::overflow_check,
     expression::literals::{hex_number_literal, unit_literal},
@@ -17,7 +17,6 @@ use crate::Target;
 use indexmap::map::Entry;
 use num_traits::ToPrimitive;
 use solang_parser::pt::{self, Annotation, CodeLocation};
-use std::str::FromStr;
 
 /// Annotations are processed in two different places during sema. When we are resolving the
 /// function header, we collect the parameter annotations in 'UnresolvedAnnotation' data structure.
@@ -193,6 +192,9 @@ pub(super) fn function_body_annotations(
     let is_solana_constructor =
         ns.target == Target::Solana && ns.functions[function_no].ty == pt::FunctionTy::Constructor;
 
+    let is_solana_function =
+        ns.target == Target::Solana && ns.functions[function_no].ty == pt::FunctionTy::Function;
+
     for note in body_annotations {
         match note.id.name.as_str() {
             "selector" => {
@@ -246,10 +248,42 @@ pub(super) fn function_body_annotations(
                     symtable,
                 );
             }
-            "payer" if is_solana_constructor => {
+            "payer" if is_solana_constructor || is_solana_function => {
                 let loc = note.loc;
+
+                // On functions, the payer tops up the rent when the data account grows
+                if is_solana_function {
+                    let func = &ns.functions[function_no];
+
+                    if !func.is_public() {
+                        diagnostics.push(Diagnostic::error(
+                            note.loc,
+                            format!(
+                                "'@payer' annotation only permitted on 'public' or 'external' function, not '{}'",
+                                func.visibility
+                            ),
+                        ));
+                        continue;
+                    }
+
+                    if matches!(func.mutability, Mutability::Pure(_) | Mutability::View(_)) {
+                        diagnostics.push(Diagnostic::error(
+                            note.loc,
+                            format!(
+                                "'@payer' annotation not permitted on '{}' function",
+                                func.mutability
+                            ),
+                        ));
+                        continue;
+                    }
+                }
+
                 if let pt::Expression::Variable(id) = note.value.as_ref().unwrap() {
-                    if BuiltinAccounts::from_str(&id.name).is_ok() {
+                    if BuiltinAccounts::is_reserved(
+                        &id.name,
+                        ns.functions[function_no].contract_no,
+                        ns,
+                    ) {
                         diagnostics.push(Diagnostic::error(
                             id.loc,
                             format!("'{}' is a reserved account name", id.name),
@@ -259,7 +293,8 @@ pub(super) fn function_body_annotations(
 
                     match ns.functions[function_no]
                         .solana_accounts
-                        .borrow_mut()
+                        .write()
+                        .unwrap()
                         .entry(id.name.clone())
                     {
                         Entry::Occupied(other_account) => {
@@ -299,6 +334,9 @@ pub(super) fn function_body_annotations(
                     ));
                 }
             }
+            "signer" | "mutableSigner" | "account" | "mutableAccount" if is_solana_function => {
+                account_annotation(function_no, note, &mut diagnostics, ns);
+            }
             _ => diagnostics.push(Diagnostic::error(
                 note.loc,
                 format!(
@@ -398,6 +436,74 @@ pub(super) fn function_body_annotations(
     ns.functions[function_no].annotations = resolved_annotations;
 }
 
+/// Declare an account for a Solana function, e.g. `@signer(authority)`. Declared accounts are
+/// listed in the IDL, checked in the function dispatch and can be accessed with
+/// `tx.accounts.authority`.
+fn account_annotation(
+    function_no: usize,
+    note: &pt::Annotation,
+    diagnostics: &mut Diagnostics,
+    ns: &mut Namespace,
+) {
+    let func = &ns.functions[function_no];
+
+    if !func.is_public() {
+        diagnostics.push(Diagnostic::error(
+            note.loc,
+            format!(
+                "'@{}' annotation only permitted on 'public' or 'external' function, not '{}'",
+                note.id.name, func.visibility
+            ),
+        ));
+        return;
+    }
+
+    let id = if let pt::Expression::Variable(id) = note.value.as_ref().unwrap() {
+        id
+    } else {
+        diagnostics.push(Diagnostic::error(
+            note.loc,
+            "invalid parameter for annotation".to_string(),
+        ));
+        return;
+    };
+
+    // 'length' would hide tx.accounts.length
+    if BuiltinAccounts::is_reserved(&id.name, func.contract_no, ns) || id.name == "length" {
+        diagnostics.push(Diagnostic::error(
+            id.loc,
+            format!("'{}' is a reserved account name", id.name),
+        ));
+        return;
+    }
+
+    let (is_signer, is_writer) = match note.id.name.as_str() {
+        "signer" => (true, false),
+        "mutableSigner" => (true, true),
+        "account" => (false, false),
+        _ => (false, true),
+    };
+
+    match func.solana_accounts.write().unwrap().entry(id.name.clone()) {
+        Entry::Occupied(other_account) => {
+            diagnostics.push(Diagnostic::error_with_note(
+                id.loc,
+                format!("account '{}' already defined", id.name),
+                other_account.get().loc,
+                "previous definition".to_string(),
+            ));
+        }
+        Entry::Vacant(vacancy) => {
+            vacancy.insert(SolanaAccount {
+                loc: note.loc,
+                is_signer,
+                is_writer,
+                generated: false,
+            });
+        }
+    }
+}
+
 /// Resolve the body annotations
 fn body_annotation(
     name: &str,
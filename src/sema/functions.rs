@@ -2,6 +2,7 @@
 
 use super::{
     ast::{Diagnostic, Function, Mutability, Namespace, Parameter, StructType, Symbol, Type},
+    codes,
     contracts::is_base,
     diagnostics::Diagnostics,
     function_annotation::function_prototype_annotations,
@@ -138,10 +139,13 @@ pub fn contract_function(
                 }
 
                 if let pt::Mutability::Constant(loc) = m {
-                    ns.diagnostics.push(Diagnostic::warning(
-                        *loc,
-                        "'constant' is deprecated. Use 'view' instead".to_string(),
-                    ));
+                    ns.diagnostics.push(
+                        Diagnostic::warning(
+                            *loc,
+                            "'constant' is deprecated. Use 'view' instead".to_string(),
+                        )
+                        .with_code(codes::DEPRECATED_CONSTANT),
+                    );
 
                     mutability = Some(pt::Mutability::View(*loc));
                 } else {
@@ -246,10 +250,13 @@ pub fn contract_function(
 
                 pt::Visibility::Internal(v.loc_opt())
             } else if func.ty == pt::FunctionTy::Constructor {
-                ns.diagnostics.push(Diagnostic::warning(
-                    v.loc_opt().unwrap(),
-                    format!("'{v}': visibility for constructors is ignored"),
-                ));
+                ns.diagnostics.push(
+                    Diagnostic::warning(
+                        v.loc_opt().unwrap(),
+                        format!("'{v}': visibility for constructors is ignored"),
+                    )
+                    .with_code(codes::CONSTRUCTOR_VISIBILITY),
+                );
 
                 pt::Visibility::Public(v.loc_opt())
             } else {
@@ -385,10 +392,13 @@ pub fn contract_function(
     // all functions in an interface are implicitly virtual
     let is_virtual = if ns.contracts[contract_no].is_interface() {
         if let Some(loc) = is_virtual {
-            ns.diagnostics.push(Diagnostic::warning(
-                loc,
-                "functions in an interface are implicitly virtual".to_string(),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    loc,
+                    "functions in an interface are implicitly virtual".to_string(),
+                )
+                .with_code(codes::IMPLICIT_VIRTUAL),
+            );
         }
 
         true
@@ -693,10 +703,13 @@ pub fn function(
                 }
 
                 if let pt::Mutability::Constant(loc) = m {
-                    ns.diagnostics.push(Diagnostic::warning(
-                        *loc,
-                        "'constant' is deprecated. Use 'view' instead".to_string(),
-                    ));
+                    ns.diagnostics.push(
+                        Diagnostic::warning(
+                            *loc,
+                            "'constant' is deprecated. Use 'view' instead".to_string(),
+                        )
+                        .with_code(codes::DEPRECATED_CONSTANT),
+                    );
 
                     mutability = Some(pt::Mutability::View(*loc));
                 } else {
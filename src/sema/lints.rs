@@ -0,0 +1,198 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Warnings can be suppressed with comments in the source code:
+//!
+//! - `// solang-disable-next-line W0012, W0013` suppresses the warnings on the next line
+//! - `// solang-disable W0012` suppresses the warnings until the end of the file, or until
+//!   a matching `// solang-enable W0012` comment
+//!
+//! Codes can be given by name too, like `unused-variable`. Without any codes, all warnings
+//! are suppressed. The level of each warning can also be set in the `[lints]` table of
+//! solang.toml, see [`LintLevel`].
+
+use super::ast::{Diagnostic, Level, Namespace, Suppression};
+use super::codes;
+use serde::Deserialize;
+use solang_parser::pt::{Comment, Loc};
+use std::collections::{BTreeMap, HashMap};
+
+/// How warnings with a given code are reported
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// Do not report the warning
+    Off,
+    /// Report as a warning, which is the default
+    Warn,
+    /// Report as an error
+    Error,
+}
+
+/// Resolve the codes or names of a `[lints]` table. Only warnings can be configured.
+pub fn resolve_lints(
+    lints: &BTreeMap<String, LintLevel>,
+) -> Result<HashMap<&'static str, LintLevel>, String> {
+    let mut resolved = HashMap::new();
+
+    for (code, level) in lints {
+        let info = match codes::lookup(code) {
+            Some(info) => info,
+            None => return Err(format!("unknown diagnostic code '{code}' in lints")),
+        };
+
+        if !info.code.starts_with('W') {
+            return Err(format!(
+                "'{code}' is not a warning; only warnings can be configured in lints"
+            ));
+        }
+
+        resolved.insert(info.code, *level);
+    }
+
+    Ok(resolved)
+}
+
+/// Collect the ranges of a file where warnings are suppressed by comments
+pub(super) fn collect_suppressions(comments: &[Comment], file_no: usize, ns: &mut Namespace) {
+    let mut suppressions = Vec::new();
+    // codes which are disabled and not enabled again yet, with the offset of the disable comment
+    let mut disabled: Vec<(Option<&'static str>, usize)> = Vec::new();
+
+    for comment in comments {
+        let (loc, start, text) = match comment {
+            Comment::Line(loc @ Loc::File(_, start, _), text) => (*loc, *start, text),
+            _ => continue,
+        };
+
+        let mut words = text
+            .trim_start_matches('/')
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|word| !word.is_empty());
+
+        let directive = match words.next() {
+            Some(directive @ "solang-disable-next-line")
+            | Some(directive @ "solang-disable")
+            | Some(directive @ "solang-enable") => directive,
+            _ => continue,
+        };
+
+        let mut codes = Vec::new();
+
+        for word in words {
+            if let Some(info) = codes::lookup(word) {
+                codes.push(Some(info.code));
+            } else {
+                ns.diagnostics.push(
+                    Diagnostic::warning(
+                        loc,
+                        format!("unknown diagnostic code '{word}' in '{directive}' comment"),
+                    )
+                    .with_code(codes::UNKNOWN_DIAGNOSTIC_CODE),
+                );
+            }
+        }
+
+        if codes.is_empty() {
+            codes.push(None);
+        }
+
+        match directive {
+            "solang-disable-next-line" => {
+                let file = &ns.files[file_no];
+                let (line, _) = file.offset_to_line_column(start);
+
+                // line_starts does not include the first line, so this is the next line
+                if let Some(next_line) = file.line_starts.get(line) {
+                    let end = file
+                        .line_starts
+                        .get(line + 1)
+                        .copied()
+                        .unwrap_or(usize::MAX);
+
+                    for code in codes {
+                        suppressions.push(Suppression {
+                            start: *next_line,
+                            end,
+                            code,
+                        });
+                    }
+                }
+            }
+            "solang-disable" => {
+                for code in codes {
+                    if !disabled
+                        .iter()
+                        .any(|(disabled_code, _)| *disabled_code == code)
+                    {
+                        disabled.push((code, start));
+                    }
+                }
+            }
+            _ => {
+                // solang-enable without codes enables everything again
+                disabled.retain(|(disabled_code, disabled_start)| {
+                    if codes.contains(&None) || codes.contains(disabled_code) {
+                        suppressions.push(Suppression {
+                            start: *disabled_start,
+                            end: start,
+                            code: *disabled_code,
+                        });
+                        false
+                    } else {
+                        true
+                    }
+                });
+            }
+        }
+    }
+
+    for (code, start) in disabled {
+        suppressions.push(Suppression {
+            start,
+            end: usize::MAX,
+            code,
+        });
+    }
+
+    ns.files[file_no].suppressions = suppressions;
+}
+
+impl Namespace {
+    /// Remove the warnings which are suppressed by comments. Errors cannot be suppressed.
+    pub fn suppress_warnings(&mut self) {
+        let files = &self.files;
+
+        self.diagnostics.retain_mut(|diag| {
+            if diag.level != Level::Warning {
+                return true;
+            }
+
+            if let Loc::File(file_no, start, _) = diag.loc {
+                !files[file_no].suppressions.iter().any(|suppression| {
+                    start >= suppression.start
+                        && start < suppression.end
+                        && suppression.code.map_or(true, |code| code == diag.code)
+                })
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Apply the levels from the `[lints]` table to the warnings. This should be done after
+    /// codegen, since codegen can find more warnings.
+    pub fn apply_lints(&mut self, lints: &HashMap<&'static str, LintLevel>) {
+        // codegen warnings have not been suppressed yet
+        self.suppress_warnings();
+
+        self.diagnostics
+            .retain_mut(|diag| match (&diag.level, lints.get(diag.code)) {
+                (Level::Warning, Some(LintLevel::Off)) => false,
+                (Level::Warning, Some(LintLevel::Error)) => {
+                    diag.level = Level::Error;
+                    true
+                }
+                _ => true,
+            });
+    }
+}
@@ -20,6 +20,7 @@ mod address;
 pub mod ast;
 pub mod builtin;
 mod builtin_structs;
+pub mod codes;
 pub(crate) mod contracts;
 pub mod diagnostics;
 mod dotgraphviz;
@@ -29,6 +30,7 @@ pub mod file;
 mod format;
 mod function_annotation;
 mod functions;
+pub mod lints;
 mod mutability;
 mod namespace;
 pub(crate) mod solana_accounts;
@@ -110,6 +112,8 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
         }
     };
 
+    lints::collect_suppressions(&comments, file_no, ns);
+
     let tree = collect_annotations_doccomments(&pt, &comments, ns);
 
     // first resolve all the types we can find
@@ -232,8 +236,10 @@ fn resolve_import(
     } else {
         match resolver.resolve_file(parent, os_filename) {
             Err(message) => {
-                ns.diagnostics
-                    .push(ast::Diagnostic::error(filename.loc, message));
+                ns.diagnostics.push(
+                    ast::Diagnostic::error(filename.loc, message)
+                        .with_code(codes::IMPORT_NOT_FOUND),
+                );
 
                 return;
             }
@@ -402,13 +408,16 @@ fn resolve_pragma(
             "pragma 'abicoder' with value 'v2' is ignored".to_string(),
         ));
     } else {
-        ns.diagnostics.push(ast::Diagnostic::warning(
-            *loc,
-            format!(
-                "unknown pragma '{}' with value '{}' ignored",
-                name.name, value.string
-            ),
-        ));
+        ns.diagnostics.push(
+            ast::Diagnostic::warning(
+                *loc,
+                format!(
+                    "unknown pragma '{}' with value '{}' ignored",
+                    name.name, value.string
+                ),
+            )
+            .with_code(codes::UNKNOWN_PRAGMA),
+        );
     }
 }
 
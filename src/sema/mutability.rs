@@ -5,6 +5,7 @@ use super::{
         Builtin, CallTy, DestructureField, Diagnostic, Expression, Function, Mutability, Namespace,
         RetrieveType, Statement, Type,
     },
+    codes,
     yul::ast::{YulExpression, YulStatement},
     Recurse,
 };
@@ -193,29 +194,35 @@ fn check_mutability(func: &Function, ns: &Namespace) -> Vec<Diagnostic> {
             match func.mutability {
                 Mutability::Payable(_) | Mutability::Pure(_) => (),
                 Mutability::Nonpayable(_) => {
-                    state.diagnostics.push(Diagnostic::warning(
-                        func.loc,
-                        "function can be declared 'pure'".to_string(),
-                    ));
+                    state.diagnostics.push(
+                        Diagnostic::warning(
+                            func.loc,
+                            "function can be declared 'pure'".to_string(),
+                        )
+                        .with_code(codes::RESTRICTABLE_MUTABILITY),
+                    );
                 }
                 _ => {
-                    state.diagnostics.push(Diagnostic::warning(
-                        func.loc,
-                        format!(
-                            "function declared '{}' can be declared 'pure'",
-                            func.mutability
-                        ),
-                    ));
+                    state.diagnostics.push(
+                        Diagnostic::warning(
+                            func.loc,
+                            format!(
+                                "function declared '{}' can be declared 'pure'",
+                                func.mutability
+                            ),
+                        )
+                        .with_code(codes::RESTRICTABLE_MUTABILITY),
+                    );
                 }
             }
         }
 
         // don't suggest marking payable as view (declared_access == Value)
         if state.required_access == Access::Read && state.declared_access == Access::Write {
-            state.diagnostics.push(Diagnostic::warning(
-                func.loc,
-                "function can be declared 'view'".to_string(),
-            ));
+            state.diagnostics.push(
+                Diagnostic::warning(func.loc, "function can be declared 'view'".to_string())
+                    .with_code(codes::RESTRICTABLE_MUTABILITY),
+            );
         }
     }
 
@@ -349,12 +356,21 @@ fn read_expression(expr: &Expression, state: &mut StateCheck) -> bool {
                 | Builtin::GasLimit
                 | Builtin::MinimumBalance
                 | Builtin::Balance
-                | Builtin::Accounts,
+                | Builtin::Accounts
+                | Builtin::GetClock
+                | Builtin::GetEpochSchedule
+                | Builtin::RentMinimumBalance
+                | Builtin::InstructionsCurrentIndex
+                | Builtin::InstructionsLoad,
             ..
         } => state.read(loc),
         Expression::Builtin {
             loc,
-            kind: Builtin::PayableSend | Builtin::PayableTransfer | Builtin::SelfDestruct,
+            kind:
+                Builtin::PayableSend
+                | Builtin::PayableTransfer
+                | Builtin::SelfDestruct
+                | Builtin::AccountRealloc,
             ..
         } => state.write(loc),
         Expression::Builtin {
@@ -376,6 +392,11 @@ fn read_expression(expr: &Expression, state: &mut StateCheck) -> bool {
             args,
             ..
         } if args[0].ty().is_contract_storage() => state.write(loc),
+        Expression::Builtin {
+            loc,
+            kind: Builtin::TakeStorage,
+            ..
+        } => state.write(loc),
 
         Expression::Constructor { loc, .. } => {
             state.write(loc);
@@ -505,6 +526,10 @@ fn check_expression_mutability_yul(expr: &YulExpression, state: &mut StateCheck)
             true
         }
         YulExpression::FunctionCall(..) => true,
+        YulExpression::ImmutableVariable(loc, ..) => {
+            state.read(loc);
+            false
+        }
         _ => false,
     }
 }
@@ -5,7 +5,7 @@ use super::{
         ArrayLength, Diagnostic, Mapping, Mutability, Namespace, Note, Parameter, RetrieveType,
         Symbol, Type,
     },
-    builtin,
+    builtin, codes,
     diagnostics::Diagnostics,
     eval::eval_const_number,
     expression::{ExprContext, ResolveTo},
@@ -33,6 +33,7 @@ impl Namespace {
             Target::Substrate {
                 address_length,
                 value_length,
+                ..
             } => (address_length, value_length),
             Target::Solana => (32, 8),
         };
@@ -77,11 +78,15 @@ impl Namespace {
         id: &pt::Identifier,
         symbol: Symbol,
     ) -> bool {
-        if builtin::is_reserved(&id.name) {
-            self.diagnostics.push(Diagnostic::warning(
-                id.loc,
-                format!("'{}' shadows name of a builtin", id.name),
-            ));
+        // Builtin structs do not shadow builtins of the same name, e.g. the Solana Clock struct
+        // and Clock.get(), whether declared by the compiler or imported from 'solana'
+        if builtin::is_reserved(&id.name, self.target)
+            && !matches!(symbol, Symbol::Struct(pt::Loc::Builtin, _))
+        {
+            self.diagnostics.push(
+                Diagnostic::warning(id.loc, format!("'{}' shadows name of a builtin", id.name))
+                    .with_code(codes::BUILTIN_SHADOWING),
+            );
         }
 
         if let Some(Symbol::Function(v)) =
@@ -194,11 +199,14 @@ impl Namespace {
                     })
                     .collect();
 
-                self.diagnostics.push(Diagnostic::warning_with_notes(
-                    id.loc,
-                    format!("{} is already defined as a function", id.name),
-                    notes,
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_notes(
+                        id.loc,
+                        format!("{} is already defined as a function", id.name),
+                        notes,
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
 
             if let Some(sym) = self
@@ -207,70 +215,94 @@ impl Namespace {
             {
                 match sym {
                     Symbol::Contract(c, _) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as a contract name", id.name),
-                            *c,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as a contract name", id.name),
+                                *c,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                     Symbol::Enum(c, _) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as an enum", id.name),
-                            *c,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as an enum", id.name),
+                                *c,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                     Symbol::Struct(c, _) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as a struct", id.name),
-                            *c,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as a struct", id.name),
+                                *c,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                     Symbol::Event(_) if symbol.is_event() => (),
                     Symbol::Event(e) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as an event", id.name),
-                            e[0].0,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as an event", id.name),
+                                e[0].0,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                     Symbol::Error(c, _) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as an error", id.name),
-                            *c,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as an error", id.name),
+                                *c,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                     Symbol::Variable(c, _, _) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as a contract variable", id.name),
-                            *c,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as a contract variable", id.name),
+                                *c,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                     Symbol::Function(_) => unreachable!(),
                     Symbol::Import(loc, _) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as an import", id.name),
-                            *loc,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as an import", id.name),
+                                *loc,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                     Symbol::UserType(loc, _) => {
-                        self.diagnostics.push(Diagnostic::warning_with_note(
-                            id.loc,
-                            format!("{} is already defined as an user type", id.name),
-                            *loc,
-                            "location of previous definition".to_string(),
-                        ));
+                        self.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                id.loc,
+                                format!("{} is already defined as an user type", id.name),
+                                *loc,
+                                "location of previous definition".to_string(),
+                            )
+                            .with_code(codes::SHADOWING),
+                        );
                     }
                 }
             }
@@ -764,11 +796,11 @@ impl Namespace {
         contract_no: Option<usize>,
         id: &pt::Identifier,
     ) {
-        if builtin::is_reserved(&id.name) {
-            self.diagnostics.push(Diagnostic::warning(
-                id.loc,
-                format!("'{}' shadows name of a builtin", id.name),
-            ));
+        if builtin::is_reserved(&id.name, self.target) {
+            self.diagnostics.push(
+                Diagnostic::warning(id.loc, format!("'{}' shadows name of a builtin", id.name))
+                    .with_code(codes::BUILTIN_SHADOWING),
+            );
             return;
         }
 
@@ -791,21 +823,27 @@ impl Namespace {
         match s {
             Some(Symbol::Enum(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows enum definition", id.name),
-                    loc,
-                    "previous definition of enum".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows enum definition", id.name),
+                        loc,
+                        "previous definition of enum".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Struct(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows struct definition", id.name),
-                    loc,
-                    "previous definition of struct".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows struct definition", id.name),
+                        loc,
+                        "previous definition of struct".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Event(events)) => {
                 let notes = events
@@ -816,20 +854,26 @@ impl Namespace {
                     })
                     .collect();
 
-                self.diagnostics.push(Diagnostic::warning_with_notes(
-                    id.loc,
-                    format!("declaration of '{}' shadows event definition", id.name),
-                    notes,
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_notes(
+                        id.loc,
+                        format!("declaration of '{}' shadows event definition", id.name),
+                        notes,
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Error(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows error definition", id.name),
-                    loc,
-                    "previous definition of error".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows error definition", id.name),
+                        loc,
+                        "previous definition of error".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Function(v)) => {
                 let notes = v
@@ -839,47 +883,62 @@ impl Namespace {
                         message: "previous declaration of function".to_owned(),
                     })
                     .collect();
-                self.diagnostics.push(Diagnostic::warning_with_notes(
-                    id.loc,
-                    format!("declaration of '{}' shadows function", id.name),
-                    notes,
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_notes(
+                        id.loc,
+                        format!("declaration of '{}' shadows function", id.name),
+                        notes,
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Variable(loc, _, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows state variable", id.name),
-                    loc,
-                    "previous declaration of state variable".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows state variable", id.name),
+                        loc,
+                        "previous declaration of state variable".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Contract(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows contract name", id.name),
-                    loc,
-                    "previous declaration of contract name".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows contract name", id.name),
+                        loc,
+                        "previous declaration of contract name".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::UserType(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows type", id.name),
-                    loc,
-                    "previous declaration of type".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows type", id.name),
+                        loc,
+                        "previous declaration of type".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             Some(Symbol::Import(loc, _)) => {
                 let loc = *loc;
-                self.diagnostics.push(Diagnostic::warning_with_note(
-                    id.loc,
-                    format!("declaration of '{}' shadows import", id.name),
-                    loc,
-                    "previous declaration of import".to_string(),
-                ));
+                self.diagnostics.push(
+                    Diagnostic::warning_with_note(
+                        id.loc,
+                        format!("declaration of '{}' shadows import", id.name),
+                        loc,
+                        "previous declaration of import".to_string(),
+                    )
+                    .with_code(codes::SHADOWING),
+                );
             }
             None => (),
         }
@@ -1008,10 +1067,14 @@ impl Namespace {
                                 }
 
                                 if let pt::Mutability::Constant(loc) = m {
-                                    diagnostics.push(Diagnostic::warning(
-                                        *loc,
-                                        "'constant' is deprecated. Use 'view' instead".to_string(),
-                                    ));
+                                    diagnostics.push(
+                                        Diagnostic::warning(
+                                            *loc,
+                                            "'constant' is deprecated. Use 'view' instead"
+                                                .to_string(),
+                                        )
+                                        .with_code(codes::DEPRECATED_CONSTANT),
+                                    );
 
                                     mutability = Some(pt::Mutability::View(*loc));
                                 } else {
@@ -1173,7 +1236,15 @@ impl Namespace {
                         Type::Address(true)
                     }
                 }
-                _ => Type::from(ty),
+                pt::Type::Bool => Type::Bool,
+                pt::Type::Address => Type::Address(false),
+                pt::Type::AddressPayable => Type::Address(true),
+                pt::Type::Int(n) => Type::Int(*n),
+                pt::Type::Uint(n) => Type::Uint(*n),
+                pt::Type::Bytes(n) => Type::Bytes(*n),
+                pt::Type::String => Type::String,
+                pt::Type::Rational => Type::Rational,
+                pt::Type::DynamicBytes => Type::DynamicBytes,
             };
 
             return if dimensions.is_empty() {
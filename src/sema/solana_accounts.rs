@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::sema::ast::Namespace;
 use std::str::FromStr;
 
 pub enum BuiltinAccounts {
@@ -15,6 +16,24 @@ pub enum BuiltinAccounts {
     /// automatically populated
     DataAccount,
     InstructionAccount,
+    /// The program derived address which signs the self-invocations that carry events, and
+    /// the program itself, which must be in the transaction to be invoked
+    EventAuthority,
+    Program,
+}
+
+impl BuiltinAccounts {
+    /// Is the name reserved for a builtin account in the contract. The accounts for emitting
+    /// events through a self-invocation are only reserved in contracts with `@emit_cpi(true)`.
+    pub fn is_reserved(name: &str, contract_no: Option<usize>, ns: &Namespace) -> bool {
+        match BuiltinAccounts::from_str(name) {
+            Ok(BuiltinAccounts::EventAuthority | BuiltinAccounts::Program) => {
+                contract_no.map_or(false, |contract_no| ns.contracts[contract_no].emit_cpi)
+            }
+            Ok(_) => true,
+            Err(_) => false,
+        }
+    }
 }
 
 impl ToString for BuiltinAccounts {
@@ -27,6 +46,8 @@ impl ToString for BuiltinAccounts {
             BuiltinAccounts::TokenProgramId => "tokenProgram",
             BuiltinAccounts::DataAccount => "dataAccount",
             BuiltinAccounts::InstructionAccount => "SysvarInstruction",
+            BuiltinAccounts::EventAuthority => "eventAuthority",
+            BuiltinAccounts::Program => "program",
         };
 
         str.to_string()
@@ -45,6 +66,8 @@ impl FromStr for BuiltinAccounts {
             "tokenProgram" => BuiltinAccounts::TokenProgramId,
             "dataAccount" => BuiltinAccounts::DataAccount,
             "SysvarInstruction" => BuiltinAccounts::InstructionAccount,
+            "eventAuthority" => BuiltinAccounts::EventAuthority,
+            "program" => BuiltinAccounts::Program,
             _ => return Err(()),
         };
 
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::ast::*;
+use super::codes;
 use super::contracts::is_base;
 use super::diagnostics::Diagnostics;
 use super::eval::check_term_for_constant_overflow;
@@ -767,10 +768,13 @@ fn statement(
 
                         Ok(true)
                     } else {
-                        ns.diagnostics.push(Diagnostic::warning(
-                            *loc,
-                            "argument to 'delete' should be storage reference".to_string(),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning(
+                                *loc,
+                                "argument to 'delete' should be storage reference".to_string(),
+                            )
+                            .with_code(codes::DELETE_NON_STORAGE),
+                        );
 
                         Err(())
                     };
@@ -908,7 +912,10 @@ fn statement(
             }
             Ok(false)
         }
-        pt::Statement::Error(_) => unimplemented!(),
+        pt::Statement::Error(loc) => {
+            diagnostics.push(Diagnostic::error(*loc, "syntax error in statement".into()));
+            Err(())
+        }
     }
 }
 
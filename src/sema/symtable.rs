@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::sema::codes;
 use indexmap::IndexMap;
 use solang_parser::diagnostics::{ErrorType, Level, Note};
 use std::collections::{HashMap, HashSet};
@@ -159,6 +160,7 @@ impl Symtable {
             ns.diagnostics.push(Diagnostic {
                 level: Level::Error,
                 ty: ErrorType::DeclarationError,
+                code: codes::DECLARATION_ERROR,
                 loc: id.loc,
                 message: format!("variable name '{}' already used in this scope", id.name),
                 notes: vec![Note {
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::ast::{Diagnostic, Namespace, Parameter, Tag};
+use super::codes;
 use solang_parser::{doccomment::DocComment, pt};
 use std::fmt::Write;
 
@@ -51,12 +52,15 @@ pub fn resolve_tags(
                 if let Some(no) = params.unwrap().iter().position(|p| p.name_as_str() == name) {
                     if let Some(other) = res.iter().find(|e| e.tag == "param" && e.no == no) {
                         // Note: solc does not detect this problem
-                        ns.diagnostics.push(Diagnostic::warning_with_note(
-                            loc,
-                            format!("duplicate tag '@param' for '{name}'"),
-                            other.loc,
-                            format!("previous tag '@param' for '{name}'"),
-                        ));
+                        ns.diagnostics.push(
+                            Diagnostic::warning_with_note(
+                                loc,
+                                format!("duplicate tag '@param' for '{name}'"),
+                                other.loc,
+                                format!("previous tag '@param' for '{name}'"),
+                            )
+                            .with_code(codes::DUPLICATE_TAG),
+                        );
                     } else {
                         res.push(Tag {
                             loc,
@@ -622,3 +622,116 @@ contract Child {
         "either 'address' or 'accounts' call argument is required on solana"
     );
 }
+
+#[test]
+fn diagnostic_codes() {
+    use crate::sema::codes;
+    use std::collections::HashSet;
+
+    let mut seen = HashSet::new();
+
+    for info in codes::CODES {
+        assert!(seen.insert(info.code), "duplicate code {}", info.code);
+        assert!(seen.insert(info.name), "duplicate name {}", info.name);
+    }
+
+    // the generic codes set by the constructors must be documented
+    let loc = Loc::File(0, 1, 2);
+    for diag in [
+        ast::Diagnostic::debug(loc, String::new()),
+        ast::Diagnostic::info(loc, String::new()),
+        ast::Diagnostic::parser_error(loc, String::new()),
+        ast::Diagnostic::error(loc, String::new()),
+        ast::Diagnostic::decl_error(loc, String::new()),
+        ast::Diagnostic::cast_error(loc, String::new()),
+        ast::Diagnostic::type_error(loc, String::new()),
+        ast::Diagnostic::cast_warning(loc, String::new()),
+        ast::Diagnostic::warning(loc, String::new()),
+        ast::Diagnostic::error_with_notes(loc, String::new(), Vec::new()),
+    ] {
+        assert!(codes::lookup(diag.code).is_some(), "{} missing", diag.code);
+    }
+
+    let ns = parse(
+        r#"
+        contract c {
+            function f() public pure {
+                int x;
+            }
+        }"#,
+    );
+
+    assert_eq!(ns.diagnostics.first_warning().code, codes::UNUSED_VARIABLE);
+    assert_eq!(codes::lookup("unused-variable").unwrap().code, "W0012");
+    assert_eq!(codes::lookup("w0012").unwrap().name, "unused-variable");
+}
+
+#[test]
+fn suppress_warnings() {
+    use crate::sema::codes;
+    use crate::sema::lints::{resolve_lints, LintLevel};
+    use std::collections::BTreeMap;
+
+    let ns = parse(
+        r#"
+        contract c {
+            function f(int a) public pure {
+                // solang-disable-next-line W0012
+                int x;
+                int y;
+            }
+
+            // solang-disable unused-parameter, W9999
+            function g(int b) public pure {}
+            // solang-enable unused-parameter
+
+            function h(int c) public pure {}
+        }"#,
+    );
+
+    let warnings: Vec<_> = ns
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.level == ast::Level::Warning)
+        .map(|diag| diag.message.as_str())
+        .collect();
+
+    assert!(!warnings.contains(&"local variable 'x' has never been read nor assigned"));
+    assert!(warnings.contains(&"local variable 'y' has never been read nor assigned"));
+    assert!(warnings.contains(&"function parameter 'a' is unused"));
+    assert!(!warnings.contains(&"function parameter 'b' is unused"));
+    assert!(warnings.contains(&"function parameter 'c' is unused"));
+    assert!(warnings.contains(&"unknown diagnostic code 'W9999' in 'solang-disable' comment"));
+
+    let mut ns = parse(
+        r#"
+        contract c {
+            function f(int a) public pure {
+                int x;
+            }
+        }"#,
+    );
+
+    let lints = resolve_lints(&BTreeMap::from([
+        ("unused-parameter".to_owned(), LintLevel::Off),
+        ("W0012".to_owned(), LintLevel::Error),
+    ]))
+    .unwrap();
+
+    ns.apply_lints(&lints);
+
+    assert!(ns.diagnostics.any_errors());
+    assert_eq!(
+        ns.diagnostics.first_error(),
+        "local variable 'x' has never been read nor assigned"
+    );
+    assert!(!ns
+        .diagnostics
+        .iter()
+        .any(|diag| diag.code == codes::UNUSED_PARAMETER));
+
+    assert_eq!(
+        resolve_lints(&BTreeMap::from([("E0006".to_owned(), LintLevel::Off)])).unwrap_err(),
+        "'E0006' is not a warning; only warnings can be configured in lints"
+    );
+}
@@ -609,8 +609,35 @@ fn contract_annotations(
     ns: &mut ast::Namespace,
 ) {
     let mut seen_program_id = None;
+    let mut seen_emit_cpi = None;
 
     for note in annotations {
+        if ns.target == Target::Solana && note.id.name == "emit_cpi" {
+            if let Some(prev_loc) = seen_emit_cpi {
+                ns.diagnostics.push(Diagnostic::error_with_note(
+                    note.loc,
+                    "duplicate emit_cpi annotation".into(),
+                    prev_loc,
+                    "location of previous emit_cpi annotation".into(),
+                ));
+
+                continue;
+            }
+
+            seen_emit_cpi = Some(note.loc);
+
+            if let Some(pt::Expression::BoolLiteral(_, value)) = &note.value {
+                ns.contracts[contract_no].emit_cpi = *value;
+            } else {
+                ns.diagnostics.push(Diagnostic::error(
+                    note.loc,
+                    "annotation takes a boolean, for example '@emit_cpi(true)'".into(),
+                ));
+            }
+
+            continue;
+        }
+
         if ns.target != Target::Solana || note.id.name != "program_id" {
             ns.diagnostics.push(Diagnostic::error(
                 note.loc,
@@ -1486,6 +1513,7 @@ impl Type {
             Type::String
             | Type::DynamicBytes
             | Type::Slice(_)
+            | Type::BufferPointer
             | Type::InternalFunction { .. }
             | Type::Ref(_)
             | Type::StorageRef(..) => BigInt::from(ns.target.ptr_size() / 8),
@@ -1494,12 +1522,11 @@ impl Type {
                 Type::Address(false).memory_size_of_internal(ns, structs_visited)
                     + Type::Uint(32).memory_size_of_internal(ns, structs_visited)
             }
-            Type::Unresolved | Type::Mapping(..) => BigInt::zero(),
+            Type::Unresolved | Type::Mapping(..) | Type::Void | Type::Unreachable => BigInt::zero(),
             Type::UserType(no) => ns.user_types[*no]
                 .ty
                 .memory_size_of_internal(ns, structs_visited),
             Type::FunctionSelector => BigInt::from(ns.target.selector_length()),
-            _ => unimplemented!("sizeof on {:?}", self),
         })
     }
 
@@ -1577,6 +1604,10 @@ impl Type {
             Type::String | Type::DynamicBytes => BigInt::from(4),
             Type::Ref(ty) | Type::StorageRef(_, ty) => ty.solana_storage_size(ns),
             Type::UserType(no) => ns.user_types[*no].ty.solana_storage_size(ns),
+            Type::ExternalFunction { .. } => {
+                // Address and selector
+                BigInt::from(ns.address_length + ns.target.selector_length() as usize)
+            }
             // Other types have the same size both in storage and in memory
             _ => self.memory_size_of(ns),
         }
@@ -1715,13 +1746,17 @@ impl Type {
                 Type::InternalFunction { .. } => BigInt::from(ns.target.ptr_size()),
                 Type::ExternalFunction { .. } => {
                     // Address and selector
-                    BigInt::from(ns.address_length + 4)
+                    BigInt::from(ns.address_length + ns.target.selector_length() as usize)
                 }
                 Type::Mapping(..) => BigInt::from(SOLANA_BUCKET_SIZE) * BigInt::from(4),
                 Type::Ref(ty) | Type::StorageRef(_, ty) => ty.storage_slots(ns),
                 Type::Unresolved => BigInt::one(),
                 Type::UserType(no) => ns.user_types[*no].ty.storage_slots(ns),
-                _ => unimplemented!(),
+                Type::FunctionSelector => BigInt::from(ns.target.selector_length()),
+                // values of these types are never stored
+                Type::Void | Type::Unreachable | Type::Slice(_) | Type::BufferPointer => {
+                    BigInt::zero()
+                }
             }
         } else {
             match self {
@@ -1787,8 +1822,14 @@ impl Type {
                 Type::ExternalFunction { .. } => BigInt::from(ns.address_length),
                 Type::Mapping(..) => BigInt::from(4),
                 Type::Ref(ty) | Type::StorageRef(_, ty) => ty.storage_align(ns),
-                Type::Unresolved => BigInt::one(),
-                _ => unimplemented!(),
+                Type::UserType(no) => ns.user_types[*no].ty.storage_align(ns),
+                Type::FunctionSelector => BigInt::from(ns.target.selector_length()),
+                // values of these types are never stored
+                Type::Unresolved
+                | Type::Void
+                | Type::Unreachable
+                | Type::Slice(_)
+                | Type::BufferPointer => BigInt::one(),
             };
 
             if length > BigInt::from(8) {
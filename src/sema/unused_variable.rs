@@ -3,6 +3,7 @@
 use crate::sema::ast::{
     Builtin, CallArgs, Diagnostic, EventDecl, Expression, Namespace, RetrieveType,
 };
+use crate::sema::codes;
 use crate::sema::symtable::{Symtable, VariableUsage};
 use crate::sema::{ast, symtable};
 use solang_parser::pt::{ContractTy, Loc};
@@ -145,11 +146,12 @@ pub fn used_variable(ns: &mut Namespace, exp: &Expression, symtable: &mut Symtab
         }
 
         Expression::Builtin {
-            kind: Builtin::ArrayPush | Builtin::ArrayPop,
+            kind: Builtin::ArrayPush | Builtin::ArrayPop | Builtin::TakeStorage,
             args,
             ..
         } => {
-            // Array push and pop return values, so they are both read and assigned.
+            // Array push and pop return values, so they are both read and assigned. The same
+            // goes for take_storage, which also clears the value.
             used_variable(ns, &args[0], symtable);
             assigned_variable(ns, &args[0], symtable);
         }
@@ -236,7 +238,7 @@ pub fn check_function_call(ns: &mut Namespace, exp: &Expression, symtable: &mut
             args,
             ..
         } => match expr_type {
-            Builtin::ArrayPush | Builtin::ArrayPop => {
+            Builtin::ArrayPush | Builtin::ArrayPop | Builtin::TakeStorage => {
                 assigned_variable(ns, &args[0], symtable);
                 if args.len() > 1 {
                     used_variable(ns, &args[1], symtable);
@@ -306,10 +308,13 @@ pub fn emit_warning_local_variable(
             if (!variable.read && !variable.ty.is_reference_type(ns))
                 || (!variable.read && !variable.assigned && variable.ty.is_reference_type(ns))
             {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!("function parameter '{}' is unused", variable.id.name),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!("function parameter '{}' is unused", variable.id.name),
+                    )
+                    .with_code(codes::UNUSED_PARAMETER),
+                );
             }
             None
         }
@@ -325,13 +330,16 @@ pub fn emit_warning_local_variable(
                         ),
                     ));
                 } else {
-                    return Some(Diagnostic::warning(
-                        variable.id.loc,
-                        format!(
-                            "return variable '{}' has never been assigned",
-                            variable.id.name
-                        ),
-                    ));
+                    return Some(
+                        Diagnostic::warning(
+                            variable.id.loc,
+                            format!(
+                                "return variable '{}' has never been assigned",
+                                variable.id.name
+                            ),
+                        )
+                        .with_code(codes::UNASSIGNED_RETURN_VARIABLE),
+                    );
                 }
             }
             None
@@ -340,36 +348,45 @@ pub fn emit_warning_local_variable(
         VariableUsage::LocalVariable => {
             let assigned = variable.initializer.has_initializer() || variable.assigned;
             if !assigned && !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "local variable '{}' has never been read nor assigned",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "local variable '{}' has never been read nor assigned",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             } else if assigned && !variable.read && !variable.is_reference() {
                 // Values assigned to variables that reference others change the value of its reference
                 // No warning needed in this case
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "local variable '{}' has been assigned, but never read",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "local variable '{}' has been assigned, but never read",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
             None
         }
 
         VariableUsage::DestructureVariable => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "destructure variable '{}' has never been used",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "destructure variable '{}' has never been used",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -377,13 +394,16 @@ pub fn emit_warning_local_variable(
 
         VariableUsage::TryCatchReturns => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "try-catch returns variable '{}' has never been read",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "try-catch returns variable '{}' has never been read",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -391,13 +411,16 @@ pub fn emit_warning_local_variable(
 
         VariableUsage::TryCatchErrorBytes => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "try-catch error bytes '{}' has never been used",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "try-catch error bytes '{}' has never been used",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -405,13 +428,16 @@ pub fn emit_warning_local_variable(
 
         VariableUsage::TryCatchErrorString => {
             if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "try-catch error string '{}' has never been used",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "try-catch error string '{}' has never been used",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
 
             None
@@ -419,18 +445,24 @@ pub fn emit_warning_local_variable(
         VariableUsage::YulLocalVariable => {
             let has_value = variable.assigned || variable.initializer.has_initializer();
             if !variable.read && !has_value {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!(
-                        "yul variable '{}' has never been read or assigned",
-                        variable.id.name
-                    ),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!(
+                            "yul variable '{}' has never been read or assigned",
+                            variable.id.name
+                        ),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             } else if !variable.read {
-                return Some(Diagnostic::warning(
-                    variable.id.loc,
-                    format!("yul variable '{}' has never been read", variable.id.name),
-                ));
+                return Some(
+                    Diagnostic::warning(
+                        variable.id.loc,
+                        format!("yul variable '{}' has never been read", variable.id.name),
+                    )
+                    .with_code(codes::UNUSED_VARIABLE),
+                );
             }
             None
         }
@@ -441,18 +473,24 @@ pub fn emit_warning_local_variable(
 /// Emit warnings depending on the storage variable usage
 fn emit_warning_contract_variables(variable: &ast::Variable) -> Option<Diagnostic> {
     if variable.assigned && !variable.read {
-        return Some(Diagnostic::warning(
-            variable.loc,
-            format!(
-                "storage variable '{}' has been assigned, but never read",
-                variable.name
-            ),
-        ));
+        return Some(
+            Diagnostic::warning(
+                variable.loc,
+                format!(
+                    "storage variable '{}' has been assigned, but never read",
+                    variable.name
+                ),
+            )
+            .with_code(codes::UNUSED_STORAGE_VARIABLE),
+        );
     } else if !variable.assigned && !variable.read {
-        return Some(Diagnostic::warning(
-            variable.loc,
-            format!("storage variable '{}' has never been used", variable.name),
-        ));
+        return Some(
+            Diagnostic::warning(
+                variable.loc,
+                format!("storage variable '{}' has never been used", variable.name),
+            )
+            .with_code(codes::UNUSED_STORAGE_VARIABLE),
+        );
     }
 
     //Solidity attributes zero value to contract values that have never been assigned
@@ -474,10 +512,13 @@ pub fn check_unused_namespace_variables(ns: &mut Namespace) {
     // Global constants should have been initialized during declaration
     for constant in &ns.constants {
         if !constant.read {
-            ns.diagnostics.push(Diagnostic::warning(
-                constant.loc,
-                format!("global constant '{}' has never been used", constant.name),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    constant.loc,
+                    format!("global constant '{}' has never been used", constant.name),
+                )
+                .with_code(codes::UNUSED_CONSTANT),
+            );
         }
     }
 }
@@ -552,10 +593,13 @@ pub fn check_unused_events(ns: &mut Namespace) {
                 }
             }
 
-            ns.diagnostics.push(Diagnostic::warning(
-                event.loc,
-                format!("event '{}' has never been emitted", event.name),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    event.loc,
+                    format!("event '{}' has never been emitted", event.name),
+                )
+                .with_code(codes::UNUSED_EVENT),
+            );
         }
     }
 }
@@ -592,10 +636,13 @@ pub fn check_unused_errors(ns: &mut Namespace) {
                 }
             }
 
-            ns.diagnostics.push(Diagnostic::warning(
-                error.loc,
-                format!("error '{}' has never been used", error.name),
-            ));
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    error.loc,
+                    format!("error '{}' has never been used", error.name),
+                )
+                .with_code(codes::UNUSED_ERROR),
+            );
         }
     }
 }
@@ -4,6 +4,7 @@ use super::{
     ast::{
         Diagnostic, Expression, Mutability, Namespace, Note, Type, Using, UsingFunction, UsingList,
     },
+    codes,
     diagnostics::Diagnostics,
     expression::{ExprContext, ResolveTo},
     symtable::Symtable,
@@ -236,7 +237,7 @@ pub(crate) fn using_decl(
                                         "previous definition of '{oper}' was '{}'",
                                         ns.functions[existing.function_no].name
                                     ),
-                                ));
+                                ).with_code(codes::REDEFINED_OPERATOR));
                             }
                             continue;
                         }
@@ -269,7 +270,13 @@ pub(crate) fn using_decl(
             UsingList::Functions(res)
         }
 
-        pt::UsingList::Error => unimplemented!(),
+        pt::UsingList::Error => {
+            ns.diagnostics.push(Diagnostic::error(
+                using.loc,
+                "syntax error in using directive".into(),
+            ));
+            return Err(());
+        }
     };
 
     let mut file_no = Some(file_no);
@@ -131,6 +131,7 @@ pub fn variable_decl<'a>(
     let mut constant = false;
     let mut visibility: Option<pt::Visibility> = None;
     let mut has_immutable: Option<pt::Loc> = None;
+    let mut has_transient: Option<pt::Loc> = None;
     let mut is_override: Option<(pt::Loc, Vec<usize>)> = None;
 
     for attr in attrs {
@@ -155,6 +156,17 @@ pub fn variable_decl<'a>(
                 }
                 has_immutable = Some(*loc);
             }
+            pt::VariableAttribute::Transient(loc) => {
+                if let Some(prev) = &has_transient {
+                    ns.diagnostics.push(Diagnostic::error_with_note(
+                        *loc,
+                        "duplicate 'transient' attribute".to_string(),
+                        *prev,
+                        "previous 'transient' attribute".to_string(),
+                    ));
+                }
+                has_transient = Some(*loc);
+            }
             pt::VariableAttribute::Override(loc, bases) => {
                 if let Some((prev, _)) = &is_override {
                     ns.diagnostics.push(Diagnostic::error_with_note(
@@ -242,6 +254,22 @@ pub fn variable_decl<'a>(
         }
     }
 
+    if let Some(loc) = &has_transient {
+        if constant {
+            ns.diagnostics.push(Diagnostic::error(
+                *loc,
+                "variable cannot be declared both 'transient' and 'constant'".to_string(),
+            ));
+            has_transient = None;
+        } else if has_immutable.is_some() {
+            ns.diagnostics.push(Diagnostic::error(
+                *loc,
+                "variable cannot be declared both 'transient' and 'immutable'".to_string(),
+            ));
+            has_transient = None;
+        }
+    }
+
     let visibility = match visibility {
         Some(v) => v,
         None => pt::Visibility::Internal(Some(def.ty.loc())),
@@ -308,13 +336,46 @@ pub fn variable_decl<'a>(
         ns.diagnostics
             .push(Diagnostic::error(def.ty.loc(), message));
         return None;
-    } else if let Some(ty) = ty.contains_builtins(ns, &StructType::AccountMeta) {
+    } else if let Some(ty) = [
+        StructType::AccountMeta,
+        StructType::Clock,
+        StructType::EpochSchedule,
+    ]
+    .iter()
+    .find_map(|builtin| ty.contains_builtins(ns, builtin))
+    {
         let message = format!("variable cannot be of builtin type '{}'", ty.to_string(ns));
         ns.diagnostics
             .push(Diagnostic::error(def.ty.loc(), message));
         return None;
     }
 
+    if has_transient.is_some() {
+        if ty.is_reference_type(ns)
+            || matches!(
+                ty,
+                Type::InternalFunction { .. } | Type::ExternalFunction { .. }
+            )
+        {
+            ns.diagnostics.push(Diagnostic::error(
+                def.ty.loc(),
+                format!(
+                    "transient storage variable of type '{}' not supported, only value types can be transient",
+                    ty.to_string(ns)
+                ),
+            ));
+            return None;
+        }
+
+        if let Some(initializer) = &def.initializer {
+            ns.diagnostics.push(Diagnostic::error(
+                initializer.loc(),
+                "transient storage variable cannot have an initializer".to_string(),
+            ));
+            return None;
+        }
+    }
+
     let initializer = if constant {
         if let Some(initializer) = &def.initializer {
             let mut diagnostics = Diagnostics::default();
@@ -389,6 +450,7 @@ pub fn variable_decl<'a>(
         ty: ty.clone(),
         constant,
         immutable: has_immutable.is_some(),
+        transient: has_transient.is_some(),
         assigned: def.initializer.is_some(),
         initializer,
         read: matches!(visibility, pt::Visibility::Public(_)),
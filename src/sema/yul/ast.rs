@@ -41,6 +41,7 @@ pub enum YulExpression {
     SolidityLocalVariable(pt::Loc, Type, Option<StorageLocation>, usize),
     ConstantVariable(pt::Loc, Type, Option<usize>, usize),
     StorageVariable(pt::Loc, Type, usize, usize),
+    ImmutableVariable(pt::Loc, Type, usize, usize),
     BuiltInCall(pt::Loc, YulBuiltInFunction, Vec<YulExpression>),
     FunctionCall(pt::Loc, usize, Vec<YulExpression>, Arc<Vec<Parameter>>),
     SuffixAccess(pt::Loc, Box<YulExpression>, YulSuffix),
@@ -55,7 +56,8 @@ impl RetrieveType for YulExpression {
             | YulExpression::YulLocalVariable(_, ty, ..)
             | YulExpression::SolidityLocalVariable(_, ty, ..)
             | YulExpression::ConstantVariable(_, ty, ..)
-            | YulExpression::StorageVariable(_, ty, ..) => ty.clone(),
+            | YulExpression::StorageVariable(_, ty, ..)
+            | YulExpression::ImmutableVariable(_, ty, ..) => ty.clone(),
 
             YulExpression::SuffixAccess(..) => Type::Uint(256),
 
@@ -112,6 +114,7 @@ impl CodeLocation for YulExpression {
             | YulExpression::SolidityLocalVariable(loc, ..)
             | YulExpression::ConstantVariable(loc, ..)
             | YulExpression::StorageVariable(loc, ..)
+            | YulExpression::ImmutableVariable(loc, ..)
             | YulExpression::BuiltInCall(loc, ..)
             | YulExpression::SuffixAccess(loc, ..)
             | YulExpression::FunctionCall(loc, ..) => *loc,
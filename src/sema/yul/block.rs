@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast::Namespace;
+use crate::sema::codes;
 use crate::sema::expression::ExprContext;
 use crate::sema::symtable::{LoopScopes, Symtable};
 use crate::sema::yul::ast::{YulBlock, YulStatement};
@@ -117,10 +118,10 @@ pub(crate) fn process_statements(
                     && !has_unreachable
                     && !matches!(item, pt::YulStatement::FunctionDefinition(..))
                 {
-                    ns.diagnostics.push(Diagnostic::warning(
-                        item.loc(),
-                        "unreachable yul statement".to_string(),
-                    ));
+                    ns.diagnostics.push(
+                        Diagnostic::warning(item.loc(), "unreachable yul statement".to_string())
+                            .with_code(codes::UNREACHABLE_CODE),
+                    );
                     has_unreachable = true;
                 }
                 reachable &= can_reach_next_statement;
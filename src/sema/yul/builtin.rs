@@ -105,6 +105,8 @@ pub enum YulBuiltInFunction {
     Difficulty = 74,
     GasLimit = 75,
     PrevRandao = 76,
+    TLoad = 77,
+    TStore = 78,
 }
 
 // These are functions that do high level stuff in a contract and are not yet implemented.
@@ -153,6 +155,8 @@ static BUILTIN_YUL_FUNCTIONS: phf::Map<&'static str, YulBuiltInFunction> = phf_m
     "mstore8" => YulBuiltInFunction::MStore8,
     "sload" => YulBuiltInFunction::SLoad,
     "sstore" => YulBuiltInFunction::SStore,
+    "tload" => YulBuiltInFunction::TLoad,
+    "tstore" => YulBuiltInFunction::TStore,
     "msize" => YulBuiltInFunction::MSize,
     "gas" => YulBuiltInFunction::Gas,
     "address" => YulBuiltInFunction::Address,
@@ -214,6 +218,7 @@ impl YulBuiltInFunction {
         matches!(
             self,
             YulBuiltInFunction::SStore
+                | YulBuiltInFunction::TStore
                 | YulBuiltInFunction::Log0
                 | YulBuiltInFunction::Log1
                 | YulBuiltInFunction::Log2
@@ -253,6 +258,7 @@ impl YulBuiltInFunction {
                 | YulBuiltInFunction::GasLimit
                 | YulBuiltInFunction::StaticCall
                 | YulBuiltInFunction::SLoad
+                | YulBuiltInFunction::TLoad
         )
     }
 }
@@ -266,7 +272,7 @@ impl ToString for YulBuiltInFunction {
 
 // Yul built-in functions.
 // Descriptions copied and slightly modified from: https://docs.soliditylang.org/en/v0.8.12/yul.html
-static YUL_BUILTIN: [YulBuiltinPrototype; 77] =
+static YUL_BUILTIN: [YulBuiltinPrototype; 79] =
     [
         YulBuiltinPrototype {
             name: "stop",
@@ -806,7 +812,7 @@ static YUL_BUILTIN: [YulBuiltinPrototype; 77] =
             doc: "selfdestruct(a) ends execution, destroy current contract and sends funds to a",
             ty: YulBuiltInFunction::SelfDestruct,
             stops_execution: true,
-            availability: [true, true, true],
+            availability: [true, true, false],
         },
         YulBuiltinPrototype {
             name: "invalid",
@@ -887,7 +893,7 @@ static YUL_BUILTIN: [YulBuiltinPrototype; 77] =
             doc: "Returns the transaction sender",
             ty: YulBuiltInFunction::Origin,
             stops_execution: false,
-            availability: [true, true, true],
+            availability: [true, false, false],
         },
         YulBuiltinPrototype {
             name: "gasprice",
@@ -961,6 +967,24 @@ static YUL_BUILTIN: [YulBuiltinPrototype; 77] =
             stops_execution: false,
             availability: [true, false, false],
         },
+        YulBuiltinPrototype {
+            name: "tload",
+            no_args: 1,
+            no_returns: 1,
+            doc: "tload(p) returns transient storage[p], i.e. storage which is discarded at the end of the transaction",
+            ty: YulBuiltInFunction::TLoad,
+            stops_execution: false,
+            availability: [true, true, true],
+        },
+        YulBuiltinPrototype {
+            name: "tstore",
+            no_args: 2,
+            no_returns: 0,
+            doc: "tstore(p, v) stores v into transient storage[p]",
+            ty: YulBuiltInFunction::TStore,
+            stops_execution: false,
+            availability: [true, true, true],
+        },
     ];
 
 #[test]
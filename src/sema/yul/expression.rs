@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast::{ArrayLength, Namespace, Parameter, Symbol, Type};
+use crate::sema::codes;
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::expression::{strings::unescape, ExprContext};
 use crate::sema::symtable::{Symtable, VariableUsage};
@@ -54,6 +55,7 @@ pub(crate) fn resolve_yul_expression(
                 ns.diagnostics.push(Diagnostic {
                     loc: value.loc,
                     ty: ErrorType::DeclarationError,
+                    code: codes::DECLARATION_ERROR,
                     level: Level::Error,
                     message: format!("hex string \"{}\" has odd number of characters", value.hex),
                     notes: vec![],
@@ -95,6 +97,16 @@ fn get_type_from_big_int(big_int: &BigInt) -> Type {
     }
 }
 
+/// The size in bits of the type of a literal, so that it can be checked that the literal fits
+fn literal_type_size(loc: &pt::Loc, ty: &Type, ns: &mut Namespace) -> Result<u16, ()> {
+    ty.get_type_size().ok_or_else(|| {
+        ns.diagnostics.push(Diagnostic::error(
+            *loc,
+            format!("type '{}' cannot be used for a literal", ty.to_string(ns)),
+        ));
+    })
+}
+
 fn resolve_bool_literal(
     loc: &pt::Loc,
     value: bool,
@@ -170,6 +182,7 @@ fn resolve_number_literal(
                     loc: *loc,
                     level: Level::Error,
                     ty: ErrorType::TypeError,
+                    code: codes::TYPE_ERROR,
                     message: "signed integer cannot fit in unsigned integer".to_string(),
                     notes: vec![],
                 });
@@ -187,7 +200,7 @@ fn resolve_number_literal(
         get_type_from_big_int(&value)
     };
 
-    let type_size = new_type.get_type_size();
+    let type_size = literal_type_size(loc, &new_type, ns)?;
 
     let bits_needed = match value.sign() {
         Sign::Minus => value.bits() + 1,
@@ -198,6 +211,7 @@ fn resolve_number_literal(
         ns.diagnostics.push(Diagnostic {
             level: Level::Error,
             ty: ErrorType::TypeError,
+            code: codes::TYPE_ERROR,
             loc: *loc,
             message: format!(
                 "the provided literal requires {bits_needed} bits, but the type only supports {type_size}"
@@ -219,11 +233,12 @@ fn resolve_hex_literal(
 
     let s: String = value.chars().skip(2).filter(|v| *v != '_').collect();
     let val = BigInt::from_str_radix(&s, 16).unwrap();
-    let type_size = new_type.get_type_size();
+    let type_size = literal_type_size(loc, &new_type, ns)?;
     if val.bits() > type_size as u64 {
         ns.diagnostics.push(Diagnostic {
             level: Level::Error,
             ty: ErrorType::TypeError,
+            code: codes::TYPE_ERROR,
             loc: *loc,
             message: format!(
                 "the provided literal requires {} bits, but the type only supports {}",
@@ -244,12 +259,13 @@ fn resolve_string_literal(
     ns: &mut Namespace,
 ) -> Result<YulExpression, ()> {
     let new_type = get_default_type_from_identifier(ty, ns)?;
-    let type_size = new_type.get_type_size();
+    let type_size = literal_type_size(loc, &new_type, ns)?;
 
     if byte_array.len() * 8 > type_size as usize {
         ns.diagnostics.push(Diagnostic {
             level: Level::Error,
             ty: ErrorType::DeclarationError,
+            code: codes::DECLARATION_ERROR,
             loc: *loc,
             message: format!(
                 "the provided literal requires {} bits, but the type only supports {}",
@@ -294,14 +310,22 @@ fn resolve_variable_reference(
             Some(Symbol::Variable(_, Some(var_contract_no), var_no)) => {
                 let var = &ns.contracts[*var_contract_no].variables[*var_no];
                 if var.immutable {
-                    ns.diagnostics.push(Diagnostic::error(
+                    if !var.ty.is_primitive() {
+                        ns.diagnostics.push(Diagnostic::error(
+                            id.loc,
+                            "only immutable variables of value type can be accessed in assembly"
+                                .to_string(),
+                        ));
+                        return Err(());
+                    }
+
+                    Ok(YulExpression::ImmutableVariable(
                         id.loc,
-                        "assembly access to immutable variables is not supported".to_string(),
-                    ));
-                    return Err(());
-                }
-
-                if var.constant {
+                        var.ty.clone(),
+                        *var_contract_no,
+                        *var_no,
+                    ))
+                } else if var.constant {
                     Ok(YulExpression::ConstantVariable(
                         id.loc,
                         var.ty.clone(),
@@ -404,6 +428,7 @@ pub(crate) fn resolve_function_call(
             ns.diagnostics.push(Diagnostic {
                 level: Level::Error,
                 ty: ErrorType::TypeError,
+                code: codes::TYPE_ERROR,
                 loc: func_call.loc,
                 message: format!(
                     "builtin function '{}' requires {} arguments, but {} were provided",
@@ -491,36 +516,42 @@ fn check_function_argument(
         }
     };
 
-    if matches!(parameter.ty, Type::Bool) && !matches!(arg_type, Type::Bool) {
-        ns.diagnostics.push(Diagnostic::warning(
-            argument.loc(),
-            "Truncating argument to bool".to_string(),
-        ));
-    } else if (matches!(parameter.ty, Type::Uint(_)) && matches!(arg_type, Type::Uint(_)))
-        || (matches!(parameter.ty, Type::Int(_)) && matches!(arg_type, Type::Int(_)))
-    {
-        let n1 = parameter.ty.get_type_size();
-        let n2 = arg_type.get_type_size();
-        if n1 < n2 {
-            ns.diagnostics.push(Diagnostic::warning(
-                argument.loc(),
-                format!("{n2} bit type may not fit into {n1} bit type"),
-            ));
+    match (&parameter.ty, &arg_type) {
+        (Type::Bool, arg_type) if !matches!(arg_type, Type::Bool) => {
+            ns.diagnostics.push(
+                Diagnostic::warning(argument.loc(), "Truncating argument to bool".to_string())
+                    .with_code(codes::TRUNCATING_CONVERSION),
+            );
         }
-    } else if matches!(parameter.ty, Type::Uint(_)) && matches!(arg_type, Type::Int(_)) {
-        ns.diagnostics.push(Diagnostic::warning(
-            argument.loc(),
-            "signed integer may not be correctly represented as unsigned integer".to_string(),
-        ));
-    } else if matches!(parameter.ty, Type::Int(_)) && matches!(arg_type, Type::Uint(_)) {
-        let n1 = parameter.ty.get_type_size();
-        let n2 = arg_type.get_type_size();
-        if n1 == n2 {
-            ns.diagnostics.push(Diagnostic::warning(
-                argument.loc(),
-                format!("{n1} bit unsigned integer may not fit into {n2} bit signed integer"),
-            ));
+        (Type::Uint(n1), Type::Uint(n2)) | (Type::Int(n1), Type::Int(n2)) if n1 < n2 => {
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    argument.loc(),
+                    format!("{n2} bit type may not fit into {n1} bit type"),
+                )
+                .with_code(codes::TRUNCATING_CONVERSION),
+            );
         }
+        (Type::Uint(_), Type::Int(_)) => {
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    argument.loc(),
+                    "signed integer may not be correctly represented as unsigned integer"
+                        .to_string(),
+                )
+                .with_code(codes::TRUNCATING_CONVERSION),
+            );
+        }
+        (Type::Int(n1), Type::Uint(n2)) if n1 == n2 => {
+            ns.diagnostics.push(
+                Diagnostic::warning(
+                    argument.loc(),
+                    format!("{n1} bit unsigned integer may not fit into {n2} bit signed integer"),
+                )
+                .with_code(codes::TRUNCATING_CONVERSION),
+            );
+        }
+        _ => (),
     }
 }
 
@@ -582,6 +613,21 @@ fn resolve_suffix_access(
             }
         }
 
+        YulExpression::SolidityLocalVariable(
+            _,
+            ref ty @ (Type::Array(..) | Type::DynamicBytes | Type::String),
+            Some(StorageLocation::Memory(_)),
+            _,
+        ) if ty.is_dynamic_memory() => {
+            if id.name != "length" {
+                ns.diagnostics.push(Diagnostic::error(
+                    id.loc,
+                    "memory arrays only support '.length'".to_string(),
+                ));
+                return Err(());
+            }
+        }
+
         YulExpression::SolidityLocalVariable(_, Type::InternalFunction { .. }, ..)
         | YulExpression::ConstantVariable(_, Type::InternalFunction { .. }, ..)
         | YulExpression::StorageVariable(_, Type::InternalFunction { .. }, ..) => {
@@ -625,6 +671,7 @@ fn resolve_suffix_access(
         | YulExpression::NumberLiteral(..)
         | YulExpression::StringLiteral(..)
         | YulExpression::YulLocalVariable(..)
+        | YulExpression::ImmutableVariable(..)
         | YulExpression::SolidityLocalVariable(_, _, Some(StorageLocation::Memory(_)), ..)
         | YulExpression::SolidityLocalVariable(_, _, Some(StorageLocation::Calldata(_)), ..)
         | YulExpression::SolidityLocalVariable(_, _, None, ..)
@@ -677,6 +724,13 @@ pub(crate) fn check_type(
                 ));
             }
 
+            YulExpression::ImmutableVariable(..) => {
+                return Some(Diagnostic::error(
+                    expr.loc(),
+                    "cannot assign a value to an immutable variable in assembly".to_string(),
+                ));
+            }
+
             YulExpression::BuiltInCall(..) | YulExpression::FunctionCall(..) => {
                 return Some(Diagnostic::error(
                     expr.loc(),
@@ -685,26 +739,19 @@ pub(crate) fn check_type(
             }
 
             YulExpression::SuffixAccess(_, member, YulSuffix::Length) => {
-                return if matches!(
+                if !matches!(
                     **member,
                     YulExpression::SolidityLocalVariable(
                         _,
-                        _,
-                        Some(StorageLocation::Calldata(_)),
+                        Type::Array(..) | Type::DynamicBytes | Type::String,
+                        Some(StorageLocation::Calldata(_) | StorageLocation::Memory(_)),
                         _
                     )
                 ) {
-                    Some(Diagnostic::error(
-                        expr.loc(),
-                        "assignment to length is not implemented. If there is need for this feature, please file a Github issue \
-                        at https://github.com/hyperledger/solang/issues\
-                        ".to_string(),
-                    ))
-                } else {
-                    Some(Diagnostic::error(
+                    return Some(Diagnostic::error(
                         expr.loc(),
                         "this expression does not support the '.length' suffix".to_string(),
-                    ))
+                    ));
                 }
             }
 
@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast::{Namespace, Parameter, Type};
+use crate::sema::codes;
 use crate::sema::expression::ExprContext;
 use crate::sema::symtable::{LoopScopes, Symtable, VariableInitializer, VariableUsage};
 use crate::sema::yul::ast::YulFunction;
@@ -55,10 +56,13 @@ impl FunctionsTable {
             if header.called {
                 self.resolved_functions[*function_no - self.offset].called = true;
             } else {
-                ns.diagnostics.push(Diagnostic::warning(
-                    header.id.loc,
-                    "yul function has never been used".to_string(),
-                ));
+                ns.diagnostics.push(
+                    Diagnostic::warning(
+                        header.id.loc,
+                        "yul function has never been used".to_string(),
+                    )
+                    .with_code(codes::UNUSED_YUL_FUNCTION),
+                );
             }
         }
     }
@@ -102,6 +106,7 @@ impl FunctionsTable {
             return Some(Diagnostic {
                 level: Level::Error,
                 ty: ErrorType::DeclarationError,
+                code: codes::DECLARATION_ERROR,
                 loc: id.loc,
                 message: format!("function name '{}' is already taken", id.name),
                 notes: vec![Note {
@@ -197,6 +202,7 @@ pub(crate) fn process_function_header(
         ns.diagnostics.push(Diagnostic {
             level: Level::Error,
             ty: ErrorType::DeclarationError,
+            code: codes::DECLARATION_ERROR,
             loc: func_def.id.loc,
             message: format!("function '{}' is already defined", func_def.id.name),
             notes: vec![Note {
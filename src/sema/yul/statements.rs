@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::sema::ast::{Namespace, Type};
+use crate::sema::codes;
 use crate::sema::expression::ExprContext;
 use crate::sema::symtable::{LoopScopes, Symtable, VariableInitializer, VariableUsage};
 use crate::sema::yul::ast::{YulExpression, YulStatement};
@@ -230,6 +231,7 @@ fn resolve_variable_declaration(
             ns.diagnostics.push(Diagnostic {
                 level: Level::Error,
                 ty: ErrorType::DeclarationError,
+                code: codes::DECLARATION_ERROR,
                 loc: item.loc,
                 message: format!("name '{}' has been defined as a function", item.id.name),
                 notes: vec![Note {
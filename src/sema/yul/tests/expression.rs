@@ -392,6 +392,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: true,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -404,6 +405,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -417,6 +419,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: true,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -432,6 +435,7 @@ fn resolve_variable_contract() {
         visibility: Visibility::Public(None),
         constant: true,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -520,11 +524,10 @@ fn resolve_variable_contract() {
         name: "imut".to_string(),
     });
     let res = resolve_yul_expression(&expr, &context, &mut symtable, &mut function_table, &mut ns);
-    assert!(res.is_err());
-    assert_eq!(ns.diagnostics.len(), 1);
+    assert!(res.is_ok());
     assert_eq!(
-        ns.diagnostics.iter().next().unwrap().message,
-        "assembly access to immutable variables is not supported"
+        YulExpression::ImmutableVariable(loc, Type::Int(128), 0, 2),
+        res.unwrap()
     );
 }
 
@@ -855,6 +858,7 @@ fn test_member_access() {
         visibility: Visibility::Public(None),
         constant: false,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -958,6 +962,7 @@ fn test_check_types() {
         visibility: Visibility::Public(None),
         constant: true,
         immutable: false,
+        transient: false,
         initializer: None,
         assigned: false,
         read: false,
@@ -1040,10 +1045,37 @@ contract testTypes {
 }
     "#;
 
+    let ns = parse(file);
+    assert!(!ns.diagnostics.any_errors());
+
+    let file = r#"
+    contract testTypes {
+    function testAsm(uint[] memory vl) public pure {
+        assembly {
+            vl.length := 1
+        }
+    }
+}
+    "#;
+
+    let ns = parse(file);
+    assert!(!ns.diagnostics.any_errors());
+
+    let file = r#"
+    contract testTypes {
+    uint immutable b = 2;
+    function testAsm() public view {
+        assembly {
+            b := 1
+        }
+    }
+}
+    "#;
+
     let ns = parse(file);
     assert!(ns
         .diagnostics
-        .contains_message("assignment to length is not implemented. If there is need for this feature, please file a Github issue at https://github.com/hyperledger/solang/issues"));
+        .contains_message("cannot assign a value to an immutable variable in assembly"));
 
     let file = r#"
 contract testTypes {
@@ -1061,7 +1093,7 @@ contract testTypes {
 
     let ns = parse(file);
     assert!(ns.diagnostics.contains_message(
-        r#"unrecognised token ':=', expected "abstract", "address", "anonymous", "as", "assembly", "bool", "break", "byte", "bytes", "calldata", "catch", "constant", "constructor", "continue", "contract", "do", "else", "emit", "enum", "event", "external", "fallback", "for", "function", "if", "immutable", "import", "indexed", "interface", "internal", "is", "leave", "let", "library", "mapping", "memory", "modifier", "new", "override", "payable", "pragma", "private", "public", "pure", "receive", "return", "returns", "revert", "storage", "string", "struct", "switch", "throw", "try", "unchecked", "using", "view", "virtual", "while", "{", "}", Int, Uint, identifier"#
+        r#"unrecognised token ':=', expected "abstract", "address", "anonymous", "as", "assembly", "bool", "break", "byte", "bytes", "calldata", "catch", "constant", "constructor", "continue", "contract", "do", "else", "emit", "enum", "event", "external", "fallback", "for", "function", "if", "immutable", "import", "indexed", "interface", "internal", "is", "leave", "let", "library", "mapping", "memory", "modifier", "new", "override", "payable", "pragma", "private", "public", "pure", "receive", "return", "returns", "revert", "storage", "string", "struct", "switch", "throw", "transient", "try", "unchecked", "using", "view", "virtual", "while", "{", "}", Int, Uint, identifier"#
     ));
 
     let file = r#"
@@ -1607,10 +1639,7 @@ contract foo {
     let ns = parse_and_resolve(
         OsStr::new("test.sol"),
         &mut cache,
-        Target::Substrate {
-            address_length: 32,
-            value_length: 16,
-        },
+        Target::default_substrate(),
     );
 
     assert!(ns.diagnostics.contains_message("builtin 'coinbase' is not available for target substrate. Please, open a GitHub issue at https://github.com/hyperledger/solang/issues if there is need to support this function"));
@@ -1631,4 +1660,39 @@ contract foo {
     let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::Solana);
 
     assert!(ns.diagnostics.contains_message("builtin 'log0' is not available for target solana. Please, open a GitHub issue at https://github.com/hyperledger/solang/issues if there is need to support this function"));
+
+    let file = r#"
+    contract foo {
+       function testing() public {
+       assembly {
+           let f := origin()
+           selfdestruct(f)
+       }
+    }
+}
+    "#;
+
+    let mut cache = FileResolver::new();
+    cache.set_file_contents("test.sol", file.to_string());
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::Solana);
+
+    assert!(ns.diagnostics.contains_message("builtin 'origin' is not available for target solana. Please, open a GitHub issue at https://github.com/hyperledger/solang/issues if there is need to support this function"));
+
+    let file = r#"
+    contract foo {
+       function testing() public {
+       assembly {
+           selfdestruct(0x40)
+       }
+    }
+}
+    "#;
+
+    let mut cache = FileResolver::new();
+    cache.set_file_contents("test.sol", file.to_string());
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::Solana);
+
+    assert!(ns.diagnostics.contains_message("builtin 'selfdestruct' is not available for target solana. Please, open a GitHub issue at https://github.com/hyperledger/solang/issues if there is need to support this function"));
 }
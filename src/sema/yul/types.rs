@@ -57,6 +57,7 @@ pub(crate) fn verify_type_from_expression(
         | YulExpression::StringLiteral(_, _, ty)
         | YulExpression::YulLocalVariable(_, ty, _)
         | YulExpression::ConstantVariable(_, ty, ..)
+        | YulExpression::ImmutableVariable(_, ty, ..)
         | YulExpression::SolidityLocalVariable(_, ty, None, _) => Ok(ty.clone()),
 
         YulExpression::SolidityLocalVariable(_, _, Some(_), _)
@@ -33,7 +33,8 @@ pub(crate) fn used_variable(ns: &mut Namespace, exp: &YulExpression, symtable: &
             var.read = true;
         }
 
-        YulExpression::StorageVariable(_, _, contract_no, var_no) => {
+        YulExpression::StorageVariable(_, _, contract_no, var_no)
+        | YulExpression::ImmutableVariable(_, _, contract_no, var_no) => {
             ns.contracts[*contract_no].variables[*var_no].read = true;
         }
 
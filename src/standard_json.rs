@@ -3,20 +3,33 @@
 //! This module defines the json format for `solang compile --standard-json`.
 
 use crate::abi::ethereum::ABI;
-use serde::Serialize;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Serialize)]
 pub struct EwasmContract {
     pub wasm: String,
 }
 
+#[derive(Serialize)]
+pub struct BytecodeJson {
+    pub object: String,
+}
+
+#[derive(Serialize)]
+pub struct EvmContract {
+    pub bytecode: BytecodeJson,
+}
+
 #[derive(Serialize)]
 pub struct JsonContract {
-    pub abi: Vec<ABI>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abi: Option<Vec<ABI>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ewasm: Option<EwasmContract>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm: Option<EvmContract>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub minimum_space: Option<u32>,
 }
 
@@ -26,6 +39,15 @@ pub struct JsonResult {
     pub target: String,
     #[serde(skip_serializing_if = "String::is_empty")]
     pub program: String,
+    pub contracts: HashMap<String, JsonContract>,
+}
+
+/// The output for standard json input. Like solc, the contracts are grouped by source unit,
+/// and only the selected outputs are included.
+#[derive(Serialize)]
+pub struct JsonInputResult {
+    pub errors: Vec<OutputJson>,
+    pub target: String,
     pub contracts: HashMap<String, HashMap<String, JsonContract>>,
 }
 
@@ -44,6 +66,65 @@ pub struct OutputJson {
     pub ty: String,
     pub component: String,
     pub severity: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub errorCode: Option<String>,
     pub message: String,
     pub formattedMessage: String,
 }
+
+/// The solc compatible input which `solang compile --standard-json` reads from stdin
+#[derive(Deserialize)]
+pub struct JsonInput {
+    pub language: String,
+    pub sources: BTreeMap<String, JsonSource>,
+    #[serde(default)]
+    pub settings: JsonSettings,
+}
+
+/// A source unit is given either inline, or as a list of local files to read it from
+#[derive(Deserialize)]
+pub struct JsonSource {
+    pub content: Option<String>,
+    #[serde(default)]
+    pub urls: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSettings {
+    #[serde(default)]
+    pub remappings: Vec<String>,
+    #[serde(default)]
+    pub optimizer: JsonOptimizer,
+    /// Solang's equivalent of `evmVersion`: solana, substrate or evm
+    pub target: Option<String>,
+    pub address_length: Option<u64>,
+    pub value_length: Option<u64>,
+    #[serde(default)]
+    pub output_selection: HashMap<String, HashMap<String, Vec<String>>>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct JsonOptimizer {
+    pub enabled: Option<bool>,
+}
+
+impl JsonSettings {
+    /// Check whether an output is selected for a contract. Like solc, `*` matches any file,
+    /// contract or output, and selecting `evm` also selects `evm.bytecode.object`.
+    pub fn output_selected(&self, file: &str, contract: &str, output: &str) -> bool {
+        self.output_selection
+            .iter()
+            .filter(|(f, _)| *f == "*" || *f == file)
+            .flat_map(|(_, contracts)| contracts.iter())
+            .filter(|(c, _)| *c == "*" || *c == contract)
+            .flat_map(|(_, outputs)| outputs.iter())
+            .any(|selected| {
+                selected == "*"
+                    || selected == output
+                    || output
+                        .strip_prefix(selected.as_str())
+                        .map_or(false, |rest| rest.starts_with('.'))
+            })
+    }
+}
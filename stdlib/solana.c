@@ -35,6 +35,8 @@ uint64_t entrypoint(const uint8_t *input)
 
     params.ka_clock = NULL;
     params.ka_instructions = NULL;
+    params.ka_payer = NULL;
+    params.transient = NULL;
 
     for (int account_no = 0; account_no < params.ka_num; account_no++)
     {
@@ -191,6 +193,94 @@ bool sol_try_transfer(uint8_t *to_address, uint64_t lamports, SolParameters *par
     return true;
 }
 
+uint64_t sol_try_find_program_address(const SolSignerSeed *seeds, uint64_t seeds_len, const SolPubkey *program_id,
+                                      SolPubkey *address, uint8_t *bump);
+
+// Anchor's tag for the instruction a program invokes itself with to emit an event
+#define EVENT_IX_TAG 0x1d9acb512ea545e4
+
+static const uint8_t event_authority_seed[] = "__event_authority";
+
+// The event authority is the program derived address which signs the self-invocations
+static uint64_t event_authority(const SolParameters *params, SolPubkey *authority, uint8_t *bump)
+{
+    const SolSignerSeed seed = {event_authority_seed, sizeof(event_authority_seed) - 1};
+
+    return sol_try_find_program_address(&seed, 1, params->program_id, authority, bump);
+}
+
+// Emit an event like Anchor's emit_cpi!, by invoking ourselves with the event as instruction
+// data. Unlike the program log, instruction data is never truncated.
+uint64_t sol_emit_cpi_event(const uint8_t *data, uint32_t data_len, SolParameters *params)
+{
+    SolPubkey authority;
+    uint8_t bump;
+
+    uint64_t ret = event_authority(params, &authority, &bump);
+    if (ret)
+    {
+        return ret;
+    }
+
+    uint8_t *input = __malloc(data_len + 8);
+
+    *(uint64_t *)input = EVENT_IX_TAG;
+    __memcpy(input + 8, data, data_len);
+
+    SolAccountMeta meta = {
+        .pubkey = &authority,
+        .is_writable = false,
+        .is_signer = true,
+    };
+
+    SolInstruction instruction = {
+        .program_id = params->program_id,
+        .accounts = &meta,
+        .account_len = 1,
+        .data = input,
+        .data_len = data_len + 8,
+    };
+
+    const SolSignerSeed seeds[] = {
+        {event_authority_seed, sizeof(event_authority_seed) - 1},
+        {&bump, 1},
+    };
+
+    const SolSignerSeeds signer = {seeds, 2};
+
+    return sol_invoke_signed_c(&instruction, params->ka, params->ka_num, &signer, 1);
+}
+
+// The self-invocation which carries an event must be signed by our event authority
+uint64_t sol_event_authority_check(SolParameters *params)
+{
+    if (params->ka_num < 1)
+    {
+        return ERROR_NOT_ENOUGH_ACCOUNT_KEYS;
+    }
+
+    if (!params->ka[0].is_signer)
+    {
+        return ERROR_MISSING_REQUIRED_SIGNATURES;
+    }
+
+    SolPubkey authority;
+    uint8_t bump;
+
+    uint64_t ret = event_authority(params, &authority, &bump);
+    if (ret)
+    {
+        return ret;
+    }
+
+    if (!SolPubkey_same(&authority, params->ka[0].key))
+    {
+        return ERROR_INVALID_ARGUMENT;
+    }
+
+    return 0;
+}
+
 #endif
 
 uint64_t address_hash(uint8_t data[32])
@@ -331,6 +421,167 @@ struct clock_layout *sol_clock(SolParameters *params)
     return clock_data;
 }
 
+struct rent_layout
+{
+    uint64_t lamports_per_byte_year;
+    uint64_t exemption_threshold; // IEEE 754 double
+    uint8_t burn_percent;
+};
+
+// The number of bytes of overhead for each account, see solana_program::rent::ACCOUNT_STORAGE_OVERHEAD
+#define ACCOUNT_STORAGE_OVERHEAD 128
+
+// Calculate the minimum balance for an account with space bytes of data to be rent exempt. The exemption threshold
+// is a double; we have no floating point, so multiply by its mantissa and shift by its exponent.
+uint64_t sol_rent_minimum_balance(uint64_t space)
+{
+    struct rent_layout rent;
+
+    if (sol_get_rent_sysvar(&rent))
+    {
+        sol_log("failed to get rent sysvar");
+        sol_panic();
+    }
+
+    uint64_t lamports = (ACCOUNT_STORAGE_OVERHEAD + space) * rent.lamports_per_byte_year;
+    uint64_t biased_exponent = (rent.exemption_threshold >> 52) & 0x7ff;
+
+    // zero or subnormal
+    if (biased_exponent == 0 || lamports == 0)
+    {
+        return 0;
+    }
+
+    uint64_t mantissa = (rent.exemption_threshold & 0xfffffffffffffull) | 0x10000000000000ull;
+    int64_t exponent = (int64_t)biased_exponent - 1075;
+
+    while (!(mantissa & 1))
+    {
+        mantissa >>= 1;
+        exponent++;
+    }
+
+    if (lamports > UINT64_MAX / mantissa)
+    {
+        return UINT64_MAX;
+    }
+
+    lamports *= mantissa;
+
+    if (exponent >= 0)
+    {
+        if (exponent >= 64 || lamports > (UINT64_MAX >> exponent))
+        {
+            return UINT64_MAX;
+        }
+
+        return lamports << exponent;
+    }
+    else if (exponent <= -64)
+    {
+        return 0;
+    }
+    else
+    {
+        return lamports >> -exponent;
+    }
+}
+
+const SolAccountInfo *sol_instructions_sysvar(SolParameters *params)
+{
+    if (!params->ka_instructions)
+    {
+        sol_log("instructions sysvar account missing from transaction");
+        sol_panic();
+    }
+
+    return params->ka_instructions;
+}
+
+// The index of the currently executing instruction is stored in the last two bytes of the instructions sysvar
+uint64_t sol_instructions_current_index(SolParameters *params)
+{
+    const SolAccountInfo *ai = sol_instructions_sysvar(params);
+
+    return *(uint16_t *)(ai->data + ai->data_len - 2);
+}
+
+// This is the layout of the builtin struct LoadedInstruction
+struct instruction_layout
+{
+    SolPubkey program_id;
+    struct vector *accounts;
+    struct vector *data;
+};
+
+struct instruction_layout *sol_instructions_load(uint64_t index, SolParameters *params)
+{
+    const SolAccountInfo *ai = sol_instructions_sysvar(params);
+    uint16_t *offsets = (uint16_t *)ai->data;
+
+    if (index >= offsets[0])
+    {
+        sol_log("instruction index out of bounds");
+        sol_panic();
+    }
+
+    uint8_t *instr = ai->data + offsets[1 + index];
+
+    uint16_t num_accounts = *(uint16_t *)instr;
+    instr += 2;
+
+    struct vector *accounts = __malloc(sizeof(struct vector) + num_accounts * sizeof(SolAccountMeta));
+
+    accounts->len = num_accounts;
+    accounts->size = num_accounts;
+
+    SolAccountMeta *metas = (SolAccountMeta *)accounts->data;
+
+    for (uint64_t account_no = 0; account_no < num_accounts; account_no++)
+    {
+        uint8_t flags = *instr++;
+
+        metas[account_no].is_signer = (flags & 1) != 0;
+        metas[account_no].is_writable = (flags & 2) != 0;
+        metas[account_no].pubkey = (SolPubkey *)instr;
+
+        instr += SIZE_PUBKEY;
+    }
+
+    struct instruction_layout *result = __malloc(sizeof(*result));
+
+    sol_memcpy(&result->program_id, instr, SIZE_PUBKEY);
+    instr += SIZE_PUBKEY;
+
+    uint16_t data_len = *(uint16_t *)instr;
+    instr += 2;
+
+    struct vector *data = __malloc(sizeof(struct vector) + data_len);
+
+    data->len = data_len;
+    data->size = data_len;
+
+    sol_memcpy(data->data, instr, data_len);
+
+    result->accounts = accounts;
+    result->data = data;
+
+    return result;
+}
+
+// Transient storage lives on the heap, so it is discarded at the end of the instruction. It is
+// allocated and zeroed when it is first used.
+uint8_t *transient_storage(SolParameters *params, uint32_t size)
+{
+    if (!params->transient)
+    {
+        params->transient = __malloc(size);
+        __memset(params->transient, 0, size);
+    }
+
+    return params->transient;
+}
+
 struct account_data_header
 {
     uint32_t magic;
@@ -355,8 +606,148 @@ struct chunk
 
 #define ROUND_UP(n, d) (((n) + (d)-1) & ~(d - 1))
 
-uint64_t account_data_alloc(SolAccountInfo *ai, uint32_t size, uint32_t *res)
+// The end of the heap is the end of the trailing chunk. The data account cannot shrink below this.
+uint32_t account_data_heap_end(void *data)
+{
+    struct account_data_header *hdr = data;
+    uint32_t offset = hdr->heap_offset;
+
+    for (;;)
+    {
+        struct chunk *chunk = data + offset;
+
+        if (!chunk->offset_next)
+        {
+            return offset + sizeof(struct chunk);
+        }
+
+        offset = chunk->offset_next;
+    }
+}
+
+// Resize the account data in place. Solana reserves MAX_PERMITTED_DATA_INCREASE bytes after the data of each
+// account, so the data does not move. The length before the program was invoked is stored in the padding
+// before the key of the account.
+uint64_t account_data_resize(SolAccountInfo *ai, uint64_t new_len, SolParameters *params)
 {
+    uint64_t original_len = *(uint32_t *)((uint8_t *)ai->key - sizeof(uint32_t));
+
+    if (new_len > original_len + MAX_PERMITTED_DATA_INCREASE)
+    {
+        sol_log("account data cannot grow by more than 10240 bytes in one instruction");
+        return ERROR_ACCOUNT_DATA_TOO_SMALL;
+    }
+
+    if (new_len > ai->data_len)
+    {
+        sol_memset(ai->data + ai->data_len, 0, new_len - ai->data_len);
+    }
+
+    // The runtime reads the new length from the serialized parameters
+    *(uint64_t *)(ai->data - sizeof(uint64_t)) = new_len;
+
+    // The account may be passed more than once
+    for (int i = 0; i < params->ka_num; i++)
+    {
+        if (params->ka[i].data == ai->data)
+        {
+            params->ka[i].data_len = new_len;
+        }
+    }
+
+    return 0;
+}
+
+// Transfer lamports from the payer so that the account is rent exempt for its current length
+uint64_t account_rent_top_up(SolAccountInfo *ai, SolParameters *params)
+{
+    uint64_t minimum_balance = sol_rent_minimum_balance(ai->data_len);
+
+    if (!params->ka_payer || *ai->lamports >= minimum_balance)
+    {
+        return 0;
+    }
+
+    // SystemInstruction::Transfer
+    struct __attribute__((packed))
+    {
+        uint32_t instruction;
+        uint64_t lamports;
+    } transfer = {2, minimum_balance - *ai->lamports};
+
+    SolAccountMeta metas[2] = {
+        {.pubkey = params->ka_payer->key, .is_writable = true, .is_signer = true},
+        {.pubkey = ai->key, .is_writable = true, .is_signer = false},
+    };
+
+    SolPubkey system_program = {0};
+
+    SolInstruction instruction = {
+        .program_id = &system_program,
+        .accounts = metas,
+        .account_len = 2,
+        .data = (uint8_t *)&transfer,
+        .data_len = sizeof(transfer),
+    };
+
+    return sol_invoke(&instruction, params->ka, params->ka_num);
+}
+
+// Grow the data account when the heap is full, if the function has a payer for the rent. Grow in steps of
+// 1KB, so that not every allocation needs a transfer.
+uint64_t account_data_grow(SolParameters *params, uint64_t min_len)
+{
+    SolAccountInfo *ai = &params->ka[0];
+
+    if (!params->ka_payer)
+    {
+        return ERROR_ACCOUNT_DATA_TOO_SMALL;
+    }
+
+    uint64_t max_len = *(uint32_t *)((uint8_t *)ai->key - sizeof(uint32_t)) + MAX_PERMITTED_DATA_INCREASE;
+    uint64_t new_len = ROUND_UP(min_len, 1024);
+
+    if (new_len > max_len && min_len <= max_len)
+    {
+        new_len = max_len;
+    }
+
+    uint64_t rc = account_data_resize(ai, new_len, params);
+    if (rc)
+    {
+        return rc;
+    }
+
+    return account_rent_top_up(ai, params);
+}
+
+// Implements AccountInfo.realloc(newSize). The data account cannot shrink below the end of the heap.
+uint64_t sol_account_realloc(SolAccountInfo *ai, uint64_t new_len, SolParameters *params)
+{
+    if (!ai->is_writable)
+    {
+        sol_log("account is not writable");
+        return ERROR_INVALID_ACCOUNT_DATA;
+    }
+
+    if (ai->data == params->ka[0].data && new_len < account_data_heap_end(ai->data))
+    {
+        sol_log("account data cannot shrink below the contract storage");
+        return ERROR_ACCOUNT_DATA_TOO_SMALL;
+    }
+
+    uint64_t rc = account_data_resize(ai, new_len, params);
+    if (rc)
+    {
+        return rc;
+    }
+
+    return account_rent_top_up(ai, params);
+}
+
+uint64_t account_data_alloc(SolParameters *params, uint32_t size, uint32_t *res)
+{
+    SolAccountInfo *ai = &params->ka[0];
     void *data = ai->data;
     struct account_data_header *hdr = data;
 
@@ -384,7 +775,11 @@ uint64_t account_data_alloc(SolAccountInfo *ai, uint32_t size, uint32_t *res)
 
                 if (offset + alloc_size + sizeof(struct chunk) >= ai->data_len)
                 {
-                    return ERROR_ACCOUNT_DATA_TOO_SMALL;
+                    uint64_t rc = account_data_grow(params, offset + alloc_size + sizeof(struct chunk) + 1);
+                    if (rc)
+                    {
+                        return rc;
+                    }
                 }
 
                 chunk->offset_next = offset + alloc_size;
@@ -530,22 +925,22 @@ void account_data_free(void *data, uint32_t offset)
     }
 }
 
-uint64_t account_data_realloc(SolAccountInfo *ai, uint32_t offset, uint32_t size, uint32_t *res)
+uint64_t account_data_realloc(SolParameters *params, uint32_t offset, uint32_t size, uint32_t *res)
 {
+    void *data = params->ka[0].data;
+
     if (!size)
     {
-        account_data_free(ai->data, offset);
+        account_data_free(data, offset);
         *res = 0;
         return 0;
     }
 
     if (!offset)
     {
-        return account_data_alloc(ai, size, res);
+        return account_data_alloc(params, size, res);
     }
 
-    void *data = ai->data;
-
     uint32_t chunk_offset = offset - sizeof(struct chunk);
 
     struct chunk *chunk = data + chunk_offset;
@@ -658,7 +1053,10 @@ uint64_t account_data_realloc(SolAccountInfo *ai, uint32_t offset, uint32_t size
         }
         else
         {
-            if (offset + alloc_size + sizeof(struct chunk) < ai->data_len)
+            // the next chunk is the trailing chunk, so grow the data account if needed
+            uint32_t end = offset + alloc_size + sizeof(struct chunk);
+
+            if (end < params->ka[0].data_len || !account_data_grow(params, end + 1))
             {
                 chunk->offset_next = offset + alloc_size;
                 chunk->length = size;
@@ -678,7 +1076,7 @@ uint64_t account_data_realloc(SolAccountInfo *ai, uint32_t offset, uint32_t size
 
     uint32_t old_length = account_data_len(data, offset);
     uint32_t new_offset;
-    uint64_t rc = account_data_alloc(ai, size, &new_offset);
+    uint64_t rc = account_data_alloc(params, size, &new_offset);
     if (rc)
         return rc;
 
@@ -761,9 +1159,12 @@ void validate_heap(void *data, uint32_t offs[100], uint32_t lens[100])
 int main()
 {
     uint8_t data[0x10000];
-    SolAccountInfo ai;
-    ai.data = data;
-    ai.data_len = sizeof(data);
+    SolParameters params;
+    params.ka_num = 1;
+    params.ka_payer = NULL;
+    SolAccountInfo *ai = &params.ka[0];
+    ai->data = data;
+    ai->data_len = sizeof(data);
     uint32_t offs[100], lens[100];
     uint32_t allocs = 0;
 
@@ -789,7 +1190,7 @@ int main()
         if (offs[n] == 0)
         {
             // printf("STEP: alloc %d\n", n);
-            status = account_data_alloc(&ai, 100, &new_offset);
+            status = account_data_alloc(&params, 100, &new_offset);
             assert(status == 0);
             offs[n] = new_offset;
             memset(data + offs[n], n, 100);
@@ -798,15 +1199,15 @@ int main()
         else if (rand() % 2)
         {
             // printf("STEP: free %d (0x%x)\n", n, offs[n]);
-            account_data_free(ai.data, offs[n]);
+            account_data_free(ai->data, offs[n]);
             offs[n] = 0;
         }
         else
         {
             // printf("STEP: realloc %d (0x%x)\n", n, offs[n]);
             int size = (rand() % 200) + 10;
-            int old_size = account_data_len(ai.data, offs[n]);
-            status = account_data_realloc(&ai, offs[n], size, &new_offset);
+            int old_size = account_data_len(ai->data, offs[n]);
+            status = account_data_realloc(&params, offs[n], size, &new_offset);
             assert(status == 0);
             offs[n] = new_offset;
             if (size > old_size)
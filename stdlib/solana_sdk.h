@@ -217,6 +217,8 @@ typedef struct
     SolPubkey *program_id; /** program_id of the currently executing program */
     const SolAccountInfo *ka_clock;
     const SolAccountInfo *ka_instructions;
+    const SolAccountInfo *ka_payer; /** Pays the rent when the data account grows, set by the dispatch */
+    uint8_t *transient;             /** Transient storage, allocated on the heap when it is first used */
 } SolParameters;
 
 /**
@@ -372,6 +374,15 @@ typedef struct
  */
 static uint64_t sol_sha256(const SolBytes *bytes, int bytes_len, const uint8_t *result);
 
+/**
+ * Sysvars
+ *
+ * Copy the contents of a sysvar into the buffer at addr
+ */
+uint64_t sol_get_clock_sysvar(void *addr);
+uint64_t sol_get_rent_sysvar(void *addr);
+uint64_t sol_get_epoch_schedule_sysvar(void *addr);
+
 /**
  * Account Meta
  */
@@ -120,3 +120,646 @@ fn basic_compilation_from_toml() {
 
     compile_cmd.current_dir(substrate_test).assert().success();
 }
+
+#[test]
+fn report_compute_units() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/solana/flipper.sol",
+            "--target",
+            "solana",
+            "--report-compute-units",
+            "--compute-units-args",
+            "new=01",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines();
+
+    assert_eq!(lines.next(), Some("Compute units for contract flipper:"));
+
+    let functions: Vec<&str> = lines
+        .map(|line| {
+            assert!(!line.contains("failed"), "{line}");
+
+            let mut columns = line.split_whitespace();
+            let name = columns.next().unwrap();
+            let units: u64 = columns.next().unwrap().parse().unwrap();
+
+            assert!(units > 0);
+
+            name
+        })
+        .collect();
+
+    assert_eq!(functions, vec!["new", "flip", "get"]);
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/substrate/flipper.sol",
+            "--target",
+            "substrate",
+            "--report-compute-units",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .assert()
+        .failure();
+}
+
+#[test]
+fn standard_json_input() {
+    let input = serde_json::json!({
+        "language": "Solidity",
+        "sources": {
+            "contracts/flipper.sol": {
+                "content": "import \"@lib/value.sol\";\nimport \"../lib/value.sol\";\ncontract flipper is Value {\n\tfunction flip() public { value = !value; }\n}\n"
+            },
+            "lib/value.sol": {
+                "content": "abstract contract Value {\n\tbool value;\n\tfunction get() internal view returns (bool) { bool unused = value; return value; }\n}\n"
+            }
+        },
+        "settings": {
+            "target": "substrate",
+            "remappings": ["@lib/=lib/"],
+            "optimizer": { "enabled": false },
+            "outputSelection": {
+                "contracts/flipper.sol": { "*": ["abi", "evm.bytecode"] }
+            }
+        }
+    });
+
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--standard-json"])
+        .write_stdin(input.to_string())
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert!(json["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|error| error["severity"] != "error"));
+    assert_eq!(json["target"], "substrate");
+
+    let flipper = &json["contracts"]["contracts/flipper.sol"]["flipper"];
+
+    assert!(flipper["abi"].is_array());
+    assert!(!flipper["evm"]["bytecode"]["object"]
+        .as_str()
+        .unwrap()
+        .is_empty());
+    assert!(flipper.get("ewasm").is_none());
+
+    // the abstract contract is not instantiable, and lib/value.sol is not selected
+    assert!(json["contracts"].get("lib/value.sol").is_none());
+
+    // lib/value.sol is resolved for both source units, but its warning is only reported once
+    assert_eq!(
+        json["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|error| error["message"].as_str().unwrap().contains("'unused'"))
+            .count(),
+        1
+    );
+
+    // with files on the command line, the contracts are not grouped by file
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "--standard-json",
+            "--target",
+            "substrate",
+            "examples/incrementer.sol",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert!(json["contracts"]["incrementer"]["abi"].is_array());
+    assert!(json["contracts"]["incrementer"]["ewasm"]["wasm"].is_string());
+
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--standard-json"])
+        .write_stdin(r#"{"language": "Vyper", "sources": {}}"#)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(json["errors"][0]["type"], "JSONError");
+    assert_eq!(
+        json["errors"][0]["message"],
+        "only Solidity is supported as a language, not 'Vyper'"
+    );
+}
+
+#[test]
+fn compile_multiple_files() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let mut outputs = Vec::new();
+
+    for run in ["first", "second"] {
+        let out = tmp.path().join(run);
+
+        Command::cargo_bin("solang")
+            .unwrap()
+            .args([
+                "compile",
+                "examples/substrate/flipper.sol",
+                "examples/substrate/ballot.sol",
+                "--target",
+                "substrate",
+                "--output",
+            ])
+            .arg(&out)
+            .assert()
+            .success();
+
+        outputs.push((
+            std::fs::read(out.join("flipper.wasm")).unwrap(),
+            std::fs::read(out.join("Ballot.wasm")).unwrap(),
+        ));
+    }
+
+    // files and contracts are built in parallel, but the output must not depend on the order
+    assert_eq!(outputs[0], outputs[1]);
+}
+
+#[test]
+fn build_cache() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let cache = tmp.path().join("cache");
+    let out = tmp.path().join("out");
+
+    let compile = || {
+        Command::cargo_bin("solang")
+            .unwrap()
+            .args([
+                "compile",
+                "examples/substrate/flipper.sol",
+                "--target",
+                "substrate",
+                "--cache",
+                "--cache-dir",
+            ])
+            .arg(&cache)
+            .arg("--output")
+            .arg(&out)
+            .assert()
+            .success();
+
+        std::fs::read(out.join("flipper.wasm")).unwrap()
+    };
+
+    let built = compile();
+
+    let entries: Vec<_> = std::fs::read_dir(&cache).unwrap().collect();
+    assert_eq!(entries.len(), 1);
+
+    let entry = entries[0].as_ref().unwrap().path();
+    assert_eq!(std::fs::read(&entry).unwrap(), built);
+
+    // the second build must come from the cache, so tamper with the entry to prove it
+    std::fs::write(&entry, b"cached").unwrap();
+
+    assert_eq!(compile(), b"cached");
+}
+
+#[test]
+fn check() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let warning = tmp.path().join("warning.sol");
+    std::fs::write(
+        &warning,
+        "contract c {\n\tfunction f() public pure {\n\t\tint x;\n\t}\n}\n",
+    )
+    .unwrap();
+
+    let error = tmp.path().join("error.sol");
+    std::fs::write(
+        &error,
+        "contract c {\n\tfunction f() public pure {\n\t\tx = 1;\n\t}\n}\n",
+    )
+    .unwrap();
+
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["check", "--target", "solana"])
+        .arg(&warning)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("local variable 'x' has never been read nor assigned"));
+    assert!(stderr.contains("warning[W0012]"));
+
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["check", "--target", "solana", "--format", "json"])
+        .arg(&warning)
+        .arg(&error)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let severities: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|diagnostic| diagnostic["severity"].as_str().unwrap())
+        .collect();
+
+    assert!(severities.contains(&"warning"));
+    assert!(severities.contains(&"error"));
+    assert!(json
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|diagnostic| diagnostic["errorCode"] == "W0012"));
+
+    // nothing is written, since no code is generated
+    assert!(!tmp.path().join("c.so").exists());
+}
+
+#[test]
+fn check_sarif() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let file = tmp.path().join("overload.sol");
+    std::fs::write(
+        &file,
+        "contract c {\n\tfunction f() public {}\n\tfunction f() public {}\n}\n",
+    )
+    .unwrap();
+
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["check", "--target", "solana", "--format", "sarif"])
+        .arg(&file)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+
+    let sarif: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    assert_eq!(sarif["version"], "2.1.0");
+
+    let run = &sarif["runs"][0];
+
+    assert_eq!(run["tool"]["driver"]["name"], "solang");
+    assert!(run["originalUriBaseIds"]["%SRCROOT%"]["uri"]
+        .as_str()
+        .unwrap()
+        .starts_with("file://"));
+
+    let result = run["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|result| result["level"] == "error")
+        .unwrap();
+
+    assert_eq!(result["ruleId"], "E0001");
+    assert_eq!(
+        result["message"]["text"],
+        "function 'f' overrides function in same contract"
+    );
+
+    let location = &result["locations"][0]["physicalLocation"];
+
+    // the uri is relative to the project root, which is the current directory
+    let uri = location["artifactLocation"]["uri"].as_str().unwrap();
+    assert!(uri.starts_with("tests/"));
+    assert!(uri.ends_with("/overload.sol"));
+    assert_eq!(location["artifactLocation"]["uriBaseId"], "%SRCROOT%");
+    assert_eq!(location["region"]["startLine"], 3);
+
+    let related = &result["relatedLocations"][0];
+
+    assert_eq!(related["message"]["text"], "previous definition of 'f'");
+    assert_eq!(related["physicalLocation"]["region"]["startLine"], 2);
+
+    let rule = &run["tool"]["driver"]["rules"][0];
+
+    assert_eq!(rule["id"], "E0001");
+    assert_eq!(rule["name"], "error");
+}
+
+#[test]
+fn explain() {
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["explain", "W0012"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("W0012 unused-variable: "));
+    assert!(stdout.contains("int x = 1;"));
+
+    // the name can be used instead of the code
+    let by_name = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["explain", "unused-variable"])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.stdout, by_name.stdout);
+
+    let output = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["explain"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .any(|line| line.starts_with("W0012 unused-variable")));
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args(["explain", "X9999"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn check_lints() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    std::fs::write(
+        tmp.path().join("lints.sol"),
+        "contract c {\n\tfunction f(int a) public pure {\n\t\t// solang-disable-next-line unused-variable\n\t\tint x;\n\t\tint y;\n\t}\n}\n",
+    )
+    .unwrap();
+
+    let check = || {
+        Command::cargo_bin("solang")
+            .unwrap()
+            .current_dir(tmp.path())
+            .args(["check", "--target", "solana", "lints.sol"])
+            .output()
+            .unwrap()
+    };
+
+    let output = check();
+
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("local variable 'x'"));
+    assert!(stderr.contains("warning[W0012]"));
+    assert!(stderr.contains("local variable 'y'"));
+    assert!(stderr.contains("warning[W0013]"));
+
+    std::fs::write(
+        tmp.path().join("solang.toml"),
+        "[lints]\nunused-parameter = \"off\"\nW0012 = \"error\"\n",
+    )
+    .unwrap();
+
+    let output = check();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("error[W0012]"));
+    assert!(!stderr.contains("W0013"));
+}
+
+#[test]
+fn deps() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    std::fs::create_dir_all(tmp.path().join("lib/util")).unwrap();
+    std::fs::write(
+        tmp.path().join("lib/util/Util.sol"),
+        "library Util {\n\tfunction one() internal pure returns (int64) {\n\t\treturn 1;\n\t}\n}\n",
+    )
+    .unwrap();
+    std::fs::copy(
+        "tests/imports_testcases/math.tar",
+        tmp.path().join("math.tar"),
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join("solang.toml"),
+        r#"
+[dependencies]
+util = { path = "lib/util" }
+math = { archive = "math.tar", root = "math-1.0/src" }
+"#,
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join("deps.sol"),
+        r#"
+import "util/Util.sol";
+import "math/Math.sol";
+
+contract c {
+    function f(int64 a) public pure returns (int64) {
+        return Math.min(a, Util.one());
+    }
+}
+"#,
+    )
+    .unwrap();
+
+    let solang = |args: &[&str]| {
+        Command::cargo_bin("solang")
+            .unwrap()
+            .current_dir(tmp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let check = ["check", "--target", "solana", "deps.sol"];
+
+    // the archive has not been unpacked yet
+    let output = solang(&check);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: dependency 'math' is not unpacked; run 'solang deps' first\n"
+    );
+
+    let output = solang(&["deps", "--verify"]);
+    assert!(!output.status.success());
+
+    let output = solang(&["deps"]);
+    assert!(output.status.success());
+    assert!(tmp
+        .path()
+        .join(".solang/deps/math/math-1.0/src/Math.sol")
+        .exists());
+
+    let lock = std::fs::read_to_string(tmp.path().join("solang.lock")).unwrap();
+    assert!(lock.contains("[math]\nsource = \"archive math.tar\"\nsha256 = \""));
+    assert!(lock.contains("\"\nunpacked_sha256 = \""));
+    assert!(lock.contains("[util]\nsource = \"path lib/util\"\nsha256 = \""));
+
+    let output = solang(&check);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+
+    assert!(solang(&["deps", "--verify"]).status.success());
+
+    std::fs::write(tmp.path().join("lib/util/Extra.sol"), "").unwrap();
+
+    let output = solang(&["deps", "--verify"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: dependency 'util' has changed since solang.lock was written\n"
+    );
+
+    std::fs::remove_file(tmp.path().join("lib/util/Extra.sol")).unwrap();
+    std::fs::write(
+        tmp.path().join(".solang/deps/math/math-1.0/src/Math.sol"),
+        "library Math {}\n",
+    )
+    .unwrap();
+
+    let output = solang(&["deps", "--verify"]);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: unpacked dependency 'math' has changed since solang.lock was written\n"
+    );
+}
+
+#[test]
+fn deps_config_file_in_subdirectory() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    std::fs::create_dir_all(tmp.path().join("project")).unwrap();
+    std::fs::copy(
+        "tests/imports_testcases/math.tar",
+        tmp.path().join("project/math.tar"),
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join("project/solang.toml"),
+        "[dependencies]\nmath = { archive = \"math.tar\", root = \"math-1.0/src\" }\n",
+    )
+    .unwrap();
+    std::fs::write(
+        tmp.path().join("deps.sol"),
+        "import \"math/Math.sol\";\n\ncontract c {\n\tfunction f(int64 a) public pure returns (int64) {\n\t\treturn Math.min(a, 1);\n\t}\n}\n",
+    )
+    .unwrap();
+
+    let solang = |args: &[&str]| {
+        Command::cargo_bin("solang")
+            .unwrap()
+            .current_dir(tmp.path())
+            .args(args)
+            .output()
+            .unwrap()
+    };
+
+    let output = solang(&["deps", "--config-file", "project/solang.toml"]);
+    assert!(output.status.success());
+    assert!(tmp.path().join("project/solang.lock").exists());
+    assert!(tmp
+        .path()
+        .join("project/.solang/deps/math/math-1.0/src/Math.sol")
+        .exists());
+    assert!(!tmp.path().join("solang.lock").exists());
+    assert!(!tmp.path().join(".solang").exists());
+
+    assert!(
+        solang(&["deps", "--verify", "--config-file", "project/solang.toml"])
+            .status
+            .success()
+    );
+
+    let output = solang(&[
+        "check",
+        "--target",
+        "solana",
+        "--config-file",
+        "project/solang.toml",
+        "deps.sol",
+    ]);
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
+
+#[test]
+fn deps_unsupported_archive() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    std::fs::copy(
+        "tests/imports_testcases/pax.tar",
+        tmp.path().join("pax.tar"),
+    )
+    .unwrap();
+    std::fs::write(tmp.path().join("math.tar.gz"), [0x1f, 0x8b, 8, 0]).unwrap();
+
+    let deps = |dependencies: &str| {
+        std::fs::write(
+            tmp.path().join("solang.toml"),
+            format!("[dependencies]\n{dependencies}\n"),
+        )
+        .unwrap();
+
+        Command::cargo_bin("solang")
+            .unwrap()
+            .current_dir(tmp.path())
+            .arg("deps")
+            .output()
+            .unwrap()
+    };
+
+    let output = deps(r#"math = { archive = "pax.tar" }"#);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: dependency 'math': pax.tar: pax headers are not supported; create the archive with 'tar --format=gnu'\n"
+    );
+    assert!(!tmp.path().join("solang.lock").exists());
+
+    let output = deps(r#"math = { archive = "math.tar.gz" }"#);
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: dependency 'math': math.tar.gz: compressed archives are not supported; decompress it with 'gunzip' first\n"
+    );
+}
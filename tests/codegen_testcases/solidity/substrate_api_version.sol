@@ -0,0 +1,19 @@
+// RUN: --target substrate --substrate-api-version 1 --emit llvm-ir
+// READ: ApiVersion.ll
+
+// BEGIN-CHECK: declare i32 @seal_call(i32, ptr, i64, ptr, ptr, i32, ptr, ptr)
+// BEGIN-CHECK: declare i32 @instantiate(ptr, i64, ptr, ptr, i32, ptr, ptr, ptr, ptr, ptr, i32)
+
+contract ApiVersion {
+    function call_other(Other other) public {
+        other.foo();
+    }
+
+    function create() public returns (Other) {
+        return new Other();
+    }
+}
+
+contract Other {
+    function foo() public {}
+}
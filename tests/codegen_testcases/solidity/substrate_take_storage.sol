@@ -0,0 +1,35 @@
+// RUN: --target substrate --emit cfg
+
+contract TakeStorage {
+    int64[] arr;
+    mapping(address => uint128) balances;
+
+    // BEGIN-CHECK: TakeStorage::function::pop_value
+    function pop_value() public returns (int64) {
+        // CHECK: = take storage slot(%temp.
+        // NOT-CHECK: clear storage slot
+        return arr.pop();
+    }
+
+    // BEGIN-CHECK: TakeStorage::function::pop_unused
+    function pop_unused() public {
+        // CHECK: clear storage slot
+        // NOT-CHECK: take storage slot
+        arr.pop();
+    }
+
+    // BEGIN-CHECK: TakeStorage::function::remove
+    function remove(address owner) public returns (uint128) {
+        // CHECK: = take storage slot((keccak256
+        // NOT-CHECK: load storage slot
+        // NOT-CHECK: clear storage slot
+        return take_storage(balances[owner]);
+    }
+
+    // BEGIN-CHECK: TakeStorage::function::has_balance
+    function has_balance(address owner) public view returns (bool) {
+        // CHECK: = contains storage slot((keccak256
+        // NOT-CHECK: load storage slot
+        return contains_storage(balances[owner]);
+    }
+}
@@ -14,6 +14,7 @@ use std::{
     ffi::OsStr,
     fs::{read_dir, File},
     io::{self, BufRead, BufReader, Read},
+    panic::{catch_unwind, AssertUnwindSafe},
     path::{Path, PathBuf},
 };
 
@@ -35,6 +36,76 @@ fn evm_contracts() -> io::Result<()> {
     contract_tests("tests/contract_testcases/evm", Target::EVM)
 }
 
+/// Compile every test case for every target, whatever target it was written for. The
+/// diagnostics differ between targets, but the compiler should never panic.
+#[test]
+fn cross_target_no_panics() -> io::Result<()> {
+    let mut entries = Vec::new();
+
+    collect_files(PathBuf::from("tests/contract_testcases"), &mut entries)?;
+
+    let targets = [Target::Solana, Target::default_substrate(), Target::EVM];
+
+    let panics: Vec<String> = entries
+        .par_iter()
+        .flat_map(|entry| targets.par_iter().map(move |target| (entry, *target)))
+        .filter_map(|(entry, target)| {
+            catch_unwind(AssertUnwindSafe(|| compile_file(entry, target).unwrap()))
+                .err()
+                .map(|_| format!("{} for {target}", entry.display()))
+        })
+        .collect();
+
+    assert!(panics.is_empty(), "compiler panicked on: {panics:#?}");
+
+    Ok(())
+}
+
+fn collect_files(path: PathBuf, entries: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in read_dir(path)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_files(path, entries)?;
+        } else if path.extension().map_or(false, |ext| ext == "sol") {
+            entries.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Run sema, codegen and emit without checking the diagnostics
+fn compile_file(path: &Path, target: Target) -> io::Result<()> {
+    let mut cache = FileResolver::new();
+
+    let filename = add_file(&mut cache, path, target)?;
+
+    let mut ns = parse_and_resolve(OsStr::new(&filename), &mut cache, target);
+
+    if ns.diagnostics.any_errors() {
+        return Ok(());
+    }
+
+    codegen::codegen(
+        &mut ns,
+        &codegen::Options {
+            opt_level: codegen::OptimizationLevel::Default,
+            ..Default::default()
+        },
+    );
+
+    if !ns.diagnostics.any_errors() && target != Target::EVM {
+        for contract in &ns.contracts {
+            if contract.instantiable {
+                let _ = contract.emit(&ns, &Default::default());
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn contract_tests(file_path: &str, target: Target) -> io::Result<()> {
     let path = PathBuf::from(file_path);
     recurse_directory(path, target)
@@ -0,0 +1,26 @@
+contract c {
+    @signer(authority)
+    @account(authority)
+    function f() public pure {}
+
+    @mutableAccount(dataAccount)
+    function g() public pure {}
+
+    @account(x)
+    function h() internal pure {}
+
+    function k() public view returns (address) {
+        return tx.accounts.foo.key;
+    }
+
+    @signer(y)
+    constructor() {}
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:14-23: account 'authority' already defined
+// 	note 2:5-23: previous definition
+// error: 6:21-32: 'dataAccount' is a reserved account name
+// error: 9:5-16: '@account' annotation only permitted on 'public' or 'external' function, not 'internal'
+// error: 13:28-31: account 'foo' not declared. Accounts can be declared with annotations like '@account(foo)' on the function
+// error: 16:5-15: unknown annotation signer for constructor
@@ -0,0 +1,24 @@
+contract c1 {
+    @account(program)
+    @signer(eventAuthority)
+    function f() external view returns (address) {
+        return tx.accounts.program.key;
+    }
+}
+
+@emit_cpi(true)
+contract c2 {
+    event E();
+
+    @account(program)
+    function f() external {
+        emit E();
+    }
+
+    @signer(eventAuthority)
+    function g() external {}
+}
+
+// ---- Expect: diagnostics ----
+// error: 13:14-21: 'program' is a reserved account name
+// error: 18:13-27: 'eventAuthority' is a reserved account name
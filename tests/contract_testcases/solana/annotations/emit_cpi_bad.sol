@@ -0,0 +1,11 @@
+@emit_cpi(true)
+@emit_cpi(false)
+contract c1 {}
+
+@emit_cpi("yes")
+contract c2 {}
+
+// ---- Expect: diagnostics ----
+// error: 2:1-17: duplicate emit_cpi annotation
+// 	note 1:1-16: location of previous emit_cpi annotation
+// error: 5:1-17: annotation takes a boolean, for example '@emit_cpi(true)'
@@ -0,0 +1,20 @@
+contract c {
+    @payer(p)
+    function f() public view {}
+
+    @payer(p)
+    function g() internal {}
+
+    @payer(dataAccount)
+    function h() public {}
+
+    function k() public pure returns (address) {
+        return tx.accounts.dataAccount.key;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 2:5-14: '@payer' annotation not permitted on 'view' function
+// error: 5:5-14: '@payer' annotation only permitted on 'public' or 'external' function, not 'internal'
+// error: 8:12-23: 'dataAccount' is a reserved account name
+// error: 12:28-39: account 'dataAccount' not declared. Accounts can be declared with annotations like '@account(dataAccount)' on the function
@@ -0,0 +1,17 @@
+type T is uint64;
+
+struct S {
+    T t;
+    bool b;
+}
+
+contract c {
+    S s;
+
+    function f() public returns (T) {
+        s.t = T.wrap(1);
+        return s.t;
+    }
+}
+
+// ---- Expect: diagnostics ----
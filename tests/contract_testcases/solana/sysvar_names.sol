@@ -0,0 +1,9 @@
+contract c {
+    function f() public pure returns (uint64) {
+        uint64 Rent = 1;
+        return Rent;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 3:16-20: 'Rent' shadows name of a builtin
@@ -0,0 +1,18 @@
+contract c {
+    uint64 transient x;
+    bool transient locked;
+
+    function f() public {
+        require(!locked);
+        locked = true;
+        x += 1;
+        delete locked;
+
+        assembly {
+            tstore(0, 1)
+        }
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 2:5-23: storage variable 'x' has been assigned, but never read
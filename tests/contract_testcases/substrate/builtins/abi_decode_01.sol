@@ -5,4 +5,4 @@
             }
         }
 // ---- Expect: diagnostics ----
-// error: 4:59-60: unrecognised token ')', expected "case", "default", "leave", "revert", "switch", identifier
+// error: 4:59-60: unrecognised token ')', expected "case", "default", "leave", "revert", "switch", "transient", identifier
@@ -4,7 +4,15 @@
                 address x = address(0);
 
                 x.delegatecall(hex"1222");
+                x.delegatecall{value: 1}(hex"1222");
+            }
+
+            function test2() public {
+                address x = address(0);
+
+                x.staticcall(hex"1222");
             }
         }
 // ---- Expect: diagnostics ----
-// error: 6:19-31: method 'delegatecall' does not exist
+// error: 7:17-52: 'delegatecall' cannot have value specified
+// error: 13:19-29: method 'staticcall' does not exist
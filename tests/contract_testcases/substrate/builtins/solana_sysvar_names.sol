@@ -0,0 +1,13 @@
+// The Solana sysvar builtins do not reserve their names on other targets
+struct Clock {
+    uint64 slot;
+}
+
+contract c {
+    function f(Clock memory Rent) public pure returns (uint64) {
+        uint64 Instructions = Rent.slot;
+        return Instructions;
+    }
+}
+
+// ---- Expect: diagnostics ----
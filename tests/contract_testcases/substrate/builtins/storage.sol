@@ -0,0 +1,35 @@
+contract c {
+    struct S {
+        int64 f1;
+    }
+    mapping(uint64 => S) structs;
+    mapping(uint64 => string) strings;
+    int64 transient t;
+    int64 v;
+
+    function test1(uint64 k) public view returns (bool) {
+        return contains_storage(structs[k]);
+    }
+
+    function test2(uint64 k) public returns (string) {
+        return take_storage(strings[k]);
+    }
+
+    function test3() public returns (int64) {
+        return take_storage(t);
+    }
+
+    function test4(int64 x) public view returns (bool) {
+        return contains_storage(x);
+    }
+
+    function test6(uint64 k) public view returns (bool) {
+        return contains_storage(strings[k], v);
+    }
+}
+// ---- Expect: diagnostics ----
+// error: 11:33-43: 'contains_storage' cannot be used on storage of type 'struct c.S'
+// error: 15:29-39: 'take_storage' cannot be used on storage of type 'string'
+// error: 19:29-30: 'take_storage' cannot be used on transient storage variables
+// error: 23:33-34: argument to 'contains_storage' should be storage reference
+// error: 27:16-32: builtin function 'contains_storage' expects 1 arguments, 2 provided
@@ -0,0 +1,18 @@
+contract c {
+    mapping(uint64 => int64) values;
+    mapping(uint64 => bytes) data;
+
+    function test1(uint64 k) public view returns (int64) {
+        return take_storage(values[k]);
+    }
+
+    function test2(uint64 k) public view returns (bool) {
+        return contains_storage(values[k]) && contains_storage(data[k]);
+    }
+
+    function test3(uint64 k) public returns (int64) {
+        return take_storage(values[k]);
+    }
+}
+// ---- Expect: diagnostics ----
+// error: 6:16-28: function declared 'view' but this expression writes to state
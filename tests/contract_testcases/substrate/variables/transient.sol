@@ -0,0 +1,17 @@
+contract x {
+    uint public transient transient a;
+    uint constant transient b = 1;
+    uint immutable transient c;
+    string transient d;
+    mapping(uint => uint) transient e;
+    uint transient f = 1;
+}
+
+// ---- Expect: diagnostics ----
+// error: 2:27-36: duplicate 'transient' attribute
+// 	note 2:17-26: previous 'transient' attribute
+// error: 3:19-28: variable cannot be declared both 'transient' and 'constant'
+// error: 4:20-29: variable cannot be declared both 'transient' and 'immutable'
+// error: 5:5-11: transient storage variable of type 'string' not supported, only value types can be transient
+// error: 6:5-26: transient storage variable of type 'mapping(uint256 => uint256)' not supported, only value types can be transient
+// error: 7:24-25: transient storage variable cannot have an initializer
@@ -155,3 +155,123 @@ fn contract_name_defined_twice() {
     assert!(err.contains("relative_import.sol:1:1-6:2 and "));
     assert!(err.ends_with("rel.sol:2:1-16\n"));
 }
+
+#[test]
+fn remappings() {
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args(["check", "--target", "solana", "remapped.sol"])
+        .current_dir("tests/imports_testcases/remappings")
+        .assert();
+
+    let output = assert.get_output();
+
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+
+    // remappings.txt is only read from the current directory
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args(["check", "--target", "solana", "remappings/remapped.sol"])
+        .current_dir("tests/imports_testcases")
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    println!("stderr: {stderr}");
+
+    assert!(stderr.contains("file not found '@acme/Lib.sol'; tried:"));
+    assert!(stderr.contains("/imports_testcases/remappings/@acme/Lib.sol\n"));
+    assert!(stderr.contains("/imports_testcases/node_modules/@acme/Lib.sol\n"));
+
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args([
+            "check",
+            "--target",
+            "solana",
+            "--remapping",
+            "@acme/=remappings/vendor/acme/",
+            "remappings/remapped.sol",
+        ])
+        .current_dir("tests/imports_testcases")
+        .assert();
+
+    let output = assert.get_output();
+
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+
+    // a relative context is relative to the current directory
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args([
+            "check",
+            "--target",
+            "solana",
+            "--remapping",
+            "remappings:@acme/=remappings/vendor/acme/",
+            "remappings/remapped.sol",
+        ])
+        .current_dir("tests/imports_testcases")
+        .assert();
+
+    let output = assert.get_output();
+
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+
+    // the remapping does not apply to files outside the context
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args([
+            "check",
+            "--target",
+            "solana",
+            "--remapping",
+            "imports:@acme/=remappings/vendor/acme/",
+            "remappings/remapped.sol",
+        ])
+        .current_dir("tests/imports_testcases")
+        .assert()
+        .failure();
+
+    let output = assert.get_output();
+
+    assert!(String::from_utf8_lossy(&output.stderr).contains("file not found '@acme/Lib.sol'"));
+}
+
+#[test]
+fn remapping_target_not_relative_to_importer() {
+    // contracts/vendor/acme/Lib.sol exists too, but the target of the remapping is relative to
+    // the import paths rather than to contracts/Token.sol which imports it
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args([
+            "check",
+            "--target",
+            "solana",
+            "--remapping",
+            "@acme/=vendor/acme/",
+            "main.sol",
+        ])
+        .current_dir("tests/imports_testcases/remapping_target")
+        .assert()
+        .success();
+
+    let output = assert.get_output();
+
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
+
+#[test]
+fn node_modules() {
+    let mut cmd = Command::cargo_bin("solang").unwrap();
+    let assert = cmd
+        .args(["check", "--target", "solana", "npm/contracts/npm.sol"])
+        .current_dir("tests/imports_testcases")
+        .assert();
+
+    let output = assert.get_output();
+
+    assert_eq!(String::from_utf8_lossy(&output.stderr), "");
+}
@@ -0,0 +1,7 @@
+import "@acme/math/Math.sol";
+
+contract npm {
+    function f(int64 a, int64 b) public pure returns (int64) {
+        return Math.max(a, b);
+    }
+}
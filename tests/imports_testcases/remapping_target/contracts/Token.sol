@@ -0,0 +1,7 @@
+import "@acme/Lib.sol";
+
+library Token {
+    function quadruple(int64 x) internal pure returns (int64) {
+        return Lib.double(Lib.double(x));
+    }
+}
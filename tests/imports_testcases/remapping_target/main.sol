@@ -0,0 +1,7 @@
+import "contracts/Token.sol";
+
+contract main {
+    function f(int64 x) public pure returns (int64) {
+        return Token.quadruple(x);
+    }
+}
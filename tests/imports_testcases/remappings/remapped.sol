@@ -0,0 +1,7 @@
+import "@acme/Lib.sol";
+
+contract remapped {
+    function f(int64 x) public pure returns (int64) {
+        return Lib.double(x);
+    }
+}
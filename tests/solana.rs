@@ -32,6 +32,7 @@ use std::{
     ffi::OsStr,
     io::Write,
     mem::size_of,
+    path::Path,
     rc::Rc,
     sync::Arc,
 };
@@ -122,6 +123,12 @@ struct CreateAccountWithSeed {
     program_id: Account,
 }
 
+#[derive(Deserialize)]
+struct Transfer {
+    instruction: u32,
+    lamports: u64,
+}
+
 #[derive(Deserialize)]
 struct Allocate {
     instruction: u32,
@@ -138,6 +145,7 @@ fn build_solidity(src: &str) -> VirtualMachine {
     let mut cache = FileResolver::new();
 
     cache.set_file_contents("test.sol", src.to_string());
+    cache.add_import_path(Path::new("solana-library")).unwrap();
 
     let (res, ns) = compile(
         OsStr::new("test.sol"),
@@ -302,8 +310,8 @@ fn serialize_parameters(
         v.write_u8(meta.is_writable.into()).unwrap();
         // executable
         v.write_u8(1).unwrap();
-        // padding
-        v.write_all(&[0u8; 4]).unwrap();
+        // original data length, stored in the padding
+        v.write_u32::<LittleEndian>(acc.data.len() as u32).unwrap();
         // key
         v.write_all(&meta.pubkey.0).unwrap();
         // owner
@@ -375,7 +383,9 @@ fn deserialize_parameters(
 ) {
     for r in refs {
         if let Some(entry) = accounts_data.get_mut(&r.account) {
-            let data = input[r.data_offset..r.data_offset + r.length].to_vec();
+            // the program may have resized the account data
+            let length = LittleEndian::read_u64(&input[r.data_offset - 8..]) as usize;
+            let data = input[r.data_offset..r.data_offset + length].to_vec();
 
             entry.data = data;
             entry.lamports = u64::from_ne_bytes(
@@ -719,7 +729,7 @@ fn sol_create_program_address(
     let mut seeds = Vec::new();
 
     for (addr, len) in arrays {
-        assert!(*len < 32);
+        assert!(*len <= 32);
 
         let buf = question_mark!(translate_slice::<u8>(memory_mapping, *addr, *len), result);
 
@@ -766,7 +776,7 @@ fn sol_try_find_program_address(
     let mut seeds = Vec::new();
 
     for (addr, len) in arrays {
-        assert!(*len < 32);
+        assert!(*len <= 32);
 
         let buf = translate_slice::<u8>(memory_mapping, *addr, *len).unwrap();
 
@@ -877,6 +887,99 @@ fn sol_get_return_data(
     }
 }
 
+fn sol_get_clock_sysvar(
+    context: &mut SyscallContext,
+    addr: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.heap_verify();
+
+    let clock_account: Account = "SysvarC1ock11111111111111111111111111111111"
+        .from_base58()
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    if let Ok(vm) = context.vm.try_borrow() {
+        let clock = &vm.account_data[&clock_account].data;
+
+        let clock_result = question_mark!(
+            translate_slice_mut::<u8>(memory_mapping, addr, clock.len() as u64),
+            result
+        );
+
+        clock_result.copy_from_slice(clock);
+
+        *result = ProgramResult::Ok(0);
+    } else {
+        panic!();
+    }
+}
+
+fn sol_get_rent_sysvar(
+    context: &mut SyscallContext,
+    addr: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.heap_verify();
+
+    // Rent::default(), laid out as #[repr(C)]
+    let mut rent = Vec::new();
+    rent.extend_from_slice(&3480u64.to_le_bytes());
+    rent.extend_from_slice(&2.0f64.to_le_bytes());
+    rent.push(50);
+    rent.resize(24, 0);
+
+    let rent_result = question_mark!(
+        translate_slice_mut::<u8>(memory_mapping, addr, rent.len() as u64),
+        result
+    );
+
+    rent_result.copy_from_slice(&rent);
+
+    *result = ProgramResult::Ok(0);
+}
+
+fn sol_get_epoch_schedule_sysvar(
+    context: &mut SyscallContext,
+    addr: u64,
+    _arg2: u64,
+    _arg3: u64,
+    _arg4: u64,
+    _arg5: u64,
+    memory_mapping: &mut MemoryMapping,
+    result: &mut ProgramResult,
+) {
+    context.heap_verify();
+
+    // EpochSchedule::default(), laid out as #[repr(C)]
+    let mut epoch_schedule = Vec::new();
+    epoch_schedule.extend_from_slice(&432000u64.to_le_bytes());
+    epoch_schedule.extend_from_slice(&432000u64.to_le_bytes());
+    epoch_schedule.extend_from_slice(&[1, 0, 0, 0, 0, 0, 0, 0]);
+    epoch_schedule.extend_from_slice(&14u64.to_le_bytes());
+    epoch_schedule.extend_from_slice(&524256u64.to_le_bytes());
+
+    let epoch_schedule_result = question_mark!(
+        translate_slice_mut::<u8>(memory_mapping, addr, epoch_schedule.len() as u64),
+        result
+    );
+
+    epoch_schedule_result.copy_from_slice(&epoch_schedule);
+
+    *result = ProgramResult::Ok(0);
+}
+
 fn sol_log_data(
     context: &mut SyscallContext,
     addr: u64,
@@ -1222,9 +1325,20 @@ fn sol_invoke_signed_c(
 
                     let mut refs = context.refs.try_borrow_mut().unwrap();
 
+                    let input = translate_slice_mut::<u8>(
+                        memory_mapping,
+                        ebpf::MM_INPUT_START,
+                        context.input_len as u64,
+                    )
+                    .unwrap();
+
                     for r in refs.iter_mut() {
                         if r.account == address.0 {
                             r.length = create_account.space as usize;
+                            LittleEndian::write_u64(
+                                &mut input[r.data_offset - 8..],
+                                r.length as u64,
+                            );
                         }
                     }
                 }
@@ -1252,6 +1366,49 @@ fn sol_invoke_signed_c(
                         entry.owner = Some(assign.owner);
                     }
                 }
+                2 => {
+                    let transfer: Transfer = bincode::deserialize(&instruction.data).unwrap();
+
+                    let from = &instruction.accounts[0].pubkey;
+                    let to = &instruction.accounts[1].pubkey;
+
+                    assert!(instruction.accounts[0].is_signer);
+
+                    assert_eq!(transfer.instruction, 2);
+
+                    println!(
+                        "transfer {} lamports from {} to {}",
+                        transfer.lamports,
+                        from.0.to_base58(),
+                        to.0.to_base58()
+                    );
+
+                    let refs = context.refs.try_borrow().unwrap();
+
+                    let input = translate_slice_mut::<u8>(
+                        memory_mapping,
+                        ebpf::MM_INPUT_START,
+                        context.input_len as u64,
+                    )
+                    .unwrap();
+
+                    // the lamports are in the serialized parameters, before the data length
+                    for r in refs.iter() {
+                        let lamports = LittleEndian::read_u64(&input[r.data_offset - 16..]);
+
+                        if r.account == from.0 {
+                            LittleEndian::write_u64(
+                                &mut input[r.data_offset - 16..],
+                                lamports.checked_sub(transfer.lamports).unwrap(),
+                            );
+                        } else if r.account == to.0 {
+                            LittleEndian::write_u64(
+                                &mut input[r.data_offset - 16..],
+                                lamports.checked_add(transfer.lamports).unwrap(),
+                            );
+                        }
+                    }
+                }
                 3 => {
                     let create_account: CreateAccountWithSeed =
                         bincode::deserialize(&instruction.data).unwrap();
@@ -1316,9 +1473,20 @@ fn sol_invoke_signed_c(
 
                     let mut refs = context.refs.try_borrow_mut().unwrap();
 
+                    let input = translate_slice_mut::<u8>(
+                        memory_mapping,
+                        ebpf::MM_INPUT_START,
+                        context.input_len as u64,
+                    )
+                    .unwrap();
+
                     for r in refs.iter_mut() {
                         if r.account == address.0 {
                             r.length = allocate.space as usize;
+                            LittleEndian::write_u64(
+                                &mut input[r.data_offset - 8..],
+                                r.length as u64,
+                            );
                         }
                     }
                 }
@@ -1431,6 +1599,21 @@ impl VirtualMachine {
             .register_function_by_name("sol_log_data", sol_log_data)
             .unwrap();
 
+        loader
+            .register_function_by_name("sol_get_clock_sysvar", sol_get_clock_sysvar)
+            .unwrap();
+
+        loader
+            .register_function_by_name("sol_get_rent_sysvar", sol_get_rent_sysvar)
+            .unwrap();
+
+        loader
+            .register_function_by_name(
+                "sol_get_epoch_schedule_sysvar",
+                sol_get_epoch_schedule_sysvar,
+            )
+            .unwrap();
+
         // program.program
         println!("program: {}", program.program.to_base58());
 
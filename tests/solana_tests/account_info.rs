@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{build_solidity, BorshToken};
+use crate::{account_new, build_solidity, AccountMeta, AccountState, BorshToken, Pubkey};
 use num_bigint::BigInt;
 
 #[test]
@@ -151,3 +151,118 @@ fn data() {
         ]),
     );
 }
+
+#[test]
+fn realloc() {
+    let mut vm = build_solidity(
+        r#"
+        contract c {
+            function resize(uint64 size) public {
+                tx.accounts.dataAccount.realloc(size);
+            }
+
+            function length() public view returns (uint64) {
+                return tx.accounts.dataAccount.data.length;
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let size = |size: u64| BorshToken::Uint {
+        width: 64,
+        value: BigInt::from(size),
+    };
+
+    vm.function("resize", &[size(8192)]);
+    assert_eq!(vm.data().len(), 8192);
+
+    let returns = vm.function("length", &[]).unwrap();
+    assert_eq!(returns, size(8192));
+
+    vm.function("resize", &[size(1024)]);
+    assert_eq!(vm.data().len(), 1024);
+
+    // AccountDataTooSmall: cannot grow by more than 10240 bytes
+    let res = vm.function_must_fail("resize", &[size(1024 + 10241)]);
+    assert_eq!(res.unwrap(), 5u64 << 32);
+
+    // AccountDataTooSmall: cannot shrink below the contract storage
+    let res = vm.function_must_fail("resize", &[size(8)]);
+    assert_eq!(res.unwrap(), 5u64 << 32);
+
+    assert_eq!(vm.data().len(), 1024);
+}
+
+#[test]
+fn data_account_grows_with_payer() {
+    let mut vm = build_solidity(
+        r#"
+        contract c {
+            string s;
+
+            @payer(payer)
+            function set(string v) public {
+                s = v;
+            }
+
+            function setNoPayer(string v) public {
+                s = v;
+            }
+
+            function get() public view returns (string) {
+                return s;
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let value = "abcdefgh".repeat(750);
+
+    // AccountDataTooSmall
+    let res = vm.function_must_fail("setNoPayer", &[BorshToken::String(value.clone())]);
+    assert_eq!(res.unwrap(), 5u64 << 32);
+
+    let payer = account_new();
+
+    vm.account_data.insert(
+        payer,
+        AccountState {
+            data: vec![],
+            owner: None,
+            lamports: 1_000_000_000,
+        },
+    );
+
+    let metas = vec![
+        AccountMeta {
+            pubkey: Pubkey(vm.stack[0].data),
+            is_writable: true,
+            is_signer: false,
+        },
+        AccountMeta {
+            pubkey: Pubkey(payer),
+            is_writable: true,
+            is_signer: true,
+        },
+    ];
+
+    vm.function_metas("set", &metas, &[BorshToken::String(value.clone())]);
+
+    let length = vm.data().len() as u64;
+    assert!(length > 6000);
+    assert_eq!(length % 1024, 0);
+
+    // rent exempt minimum balance for the new length
+    let minimum_balance = (128 + length) * 3480 * 2;
+
+    assert_eq!(vm.account_data[&vm.stack[0].data].lamports, minimum_balance);
+    assert_eq!(
+        vm.account_data[&payer].lamports,
+        1_000_000_000 - minimum_balance
+    );
+
+    let returns = vm.function("get", &[]).unwrap();
+    assert_eq!(returns, BorshToken::String(value));
+}
@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{build_solidity, BorshToken};
-use base58::ToBase58;
+use crate::{build_solidity, Account, AccountState, BorshToken};
+use base58::{FromBase58, ToBase58};
 use num_bigint::BigInt;
+use num_traits::One;
 
 #[test]
 fn builtins() {
@@ -277,3 +278,204 @@ fn out_of_bounds_string_write() {
     vm.constructor(&[]);
     let _ = vm.function("testStringOut", &[]);
 }
+
+#[test]
+fn sysvars() {
+    let mut vm = build_solidity(
+        r#"
+        import 'solana';
+
+        contract sysvars {
+            function clock() public view returns (uint64, int64, uint64) {
+                Clock clock = Clock.get();
+
+                return (clock.slot, clock.unix_timestamp, clock.epoch);
+            }
+
+            function epoch_schedule() public view returns (uint64, bool, uint64) {
+                EpochSchedule schedule = EpochSchedule.get();
+
+                return (schedule.slots_per_epoch, schedule.warmup, schedule.first_normal_slot);
+            }
+
+            function rent(uint64 space) public view returns (uint64) {
+                return Rent.minimumBalance(space);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let returns = vm.function("clock", &[]).unwrap().unwrap_tuple();
+
+    assert_eq!(
+        returns,
+        vec![
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(70818331u64)
+            },
+            BorshToken::Int {
+                width: 64,
+                value: BigInt::from(1620656423u64)
+            },
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(102u64)
+            },
+        ]
+    );
+
+    let returns = vm.function("epoch_schedule", &[]).unwrap().unwrap_tuple();
+
+    assert_eq!(
+        returns,
+        vec![
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(432000u64)
+            },
+            BorshToken::Bool(true),
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(524256u64)
+            },
+        ]
+    );
+
+    // (128 + space) * 3480 lamports per byte year * 2 years
+    for (space, lamports) in [(0u64, 890880u64), (165, 2039280)] {
+        let returns = vm
+            .function(
+                "rent",
+                &[BorshToken::Uint {
+                    width: 64,
+                    value: BigInt::from(space),
+                }],
+            )
+            .unwrap();
+
+        assert_eq!(
+            returns,
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(lamports)
+            }
+        );
+    }
+}
+
+#[test]
+fn instructions_sysvar() {
+    let mut vm = build_solidity(
+        r#"
+        import 'solana';
+
+        contract introspection {
+            function current() public view returns (uint16) {
+                return Instructions.currentIndex();
+            }
+
+            function load(uint16 index) public view returns (address, bytes, uint32, address, bool, bool) {
+                LoadedInstruction instr = Instructions.load(index);
+
+                AccountMeta meta = instr.accounts[1];
+
+                return (instr.program_id, instr.data, instr.accounts.length, meta.pubkey, meta.is_writable, meta.is_signer);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let instructions_account: Account = "Sysvar1nstructions1111111111111111111111111"
+        .from_base58()
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    // Two instructions; the first one has no accounts, the second has two
+    let instructions: [([u8; 32], Vec<(u8, [u8; 32])>, Vec<u8>); 2] = [
+        ([7; 32], vec![], vec![1, 2, 3]),
+        ([8; 32], vec![(1, [9; 32]), (2, [10; 32])], vec![4, 5]),
+    ];
+
+    let mut data = Vec::new();
+
+    data.extend_from_slice(&(instructions.len() as u16).to_le_bytes());
+
+    let mut serialized = Vec::new();
+
+    for (program_id, accounts, instr_data) in &instructions {
+        let offset = 2 + instructions.len() * 2 + serialized.len();
+        data.extend_from_slice(&(offset as u16).to_le_bytes());
+
+        serialized.extend_from_slice(&(accounts.len() as u16).to_le_bytes());
+        for (flags, pubkey) in accounts {
+            serialized.push(*flags);
+            serialized.extend_from_slice(pubkey);
+        }
+        serialized.extend_from_slice(program_id);
+        serialized.extend_from_slice(&(instr_data.len() as u16).to_le_bytes());
+        serialized.extend_from_slice(instr_data);
+    }
+
+    data.extend_from_slice(&serialized);
+    // index of the current instruction
+    data.extend_from_slice(&1u16.to_le_bytes());
+
+    vm.account_data.insert(
+        instructions_account,
+        AccountState {
+            data,
+            owner: None,
+            lamports: 0,
+        },
+    );
+
+    let returns = vm.function("current", &[]).unwrap();
+
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 16,
+            value: BigInt::one()
+        }
+    );
+
+    let returns = vm
+        .function(
+            "load",
+            &[BorshToken::Uint {
+                width: 16,
+                value: BigInt::one(),
+            }],
+        )
+        .unwrap()
+        .unwrap_tuple();
+
+    assert_eq!(
+        returns,
+        vec![
+            BorshToken::Address([8; 32]),
+            BorshToken::Bytes(vec![4, 5]),
+            BorshToken::Uint {
+                width: 32,
+                value: BigInt::from(2u8)
+            },
+            BorshToken::Address([10; 32]),
+            BorshToken::Bool(true),
+            BorshToken::Bool(false),
+        ]
+    );
+
+    let res = vm.function_must_fail(
+        "load",
+        &[BorshToken::Uint {
+            width: 16,
+            value: BigInt::from(2u8),
+        }],
+    );
+
+    assert!(res.is_err());
+}
@@ -6,6 +6,7 @@ use crate::{
 use base58::FromBase58;
 use num_bigint::BigInt;
 use num_traits::One;
+use solang::abi::anchor::discriminator;
 
 #[test]
 fn simple_external_call() {
@@ -214,6 +215,59 @@ fn call_external_func_type() {
     );
 }
 
+#[test]
+fn external_func_type_in_storage() {
+    let mut vm = build_solidity(
+        r#"
+    contract testing {
+    function(int64) external pure returns (int64) fPtr;
+
+    function half(int64 a) public pure returns (int64) {
+        return a / 2;
+    }
+
+    function setPtr() public {
+        fPtr = this.half;
+    }
+
+    function callPtr(int64 a) public view returns (int64) {
+        return fPtr(a);
+    }
+
+    function getSelector() public view returns (bytes8) {
+        return fPtr.selector;
+    }
+}
+    "#,
+    );
+
+    vm.constructor(&[]);
+
+    vm.function("setPtr", &[]);
+
+    let res = vm.function("getSelector", &[]).unwrap();
+
+    assert_eq!(res, BorshToken::FixedBytes(discriminator("global", "half")));
+
+    let res = vm
+        .function(
+            "callPtr",
+            &[BorshToken::Int {
+                width: 64,
+                value: BigInt::from(42u8),
+            }],
+        )
+        .unwrap();
+
+    assert_eq!(
+        res,
+        BorshToken::Int {
+            width: 64,
+            value: BigInt::from(21u8),
+        }
+    );
+}
+
 #[test]
 fn external_call_with_string_returns() {
     let mut vm = build_solidity(
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::build_solidity;
+use crate::{
+    account_new, build_solidity, create_program_address, AccountMeta, AccountState, Instruction,
+    Pubkey, VirtualMachine,
+};
 use borsh::BorshDeserialize;
 use sha2::{Digest, Sha256};
 
@@ -97,6 +100,130 @@ fn less_simple_event() {
     assert_eq!(decoded.d, S { f1: 102, f2: true });
 }
 
+#[test]
+fn emit_cpi_event() {
+    #[derive(BorshDeserialize, PartialEq, Eq, Debug)]
+    struct MyEvent {
+        a: i32,
+        b: String,
+    }
+
+    let mut vm = build_solidity(
+        r#"
+        @emit_cpi(true)
+        contract c {
+            event myevent(int32 indexed a, string b);
+
+            function go() public {
+                emit myevent(-102, "foobar");
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let program = vm.stack[0].program;
+
+    let check = |vm: &VirtualMachine, instr: &Instruction, signers: &[Pubkey]| {
+        let authority =
+            create_program_address(&vm.stack[0].program, &[b"__event_authority", &[255]]);
+
+        assert_eq!(instr.program_id, Pubkey(vm.stack[0].program));
+        assert_eq!(instr.accounts.len(), 1);
+        assert_eq!(instr.accounts[0].pubkey, authority);
+        assert!(instr.accounts[0].is_signer);
+        assert!(!instr.accounts[0].is_writable);
+        assert_eq!(signers, &[authority]);
+
+        assert_eq!(
+            &instr.data[..8],
+            &[0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d]
+        );
+        assert_eq!(&instr.data[8..16], &calculate_discriminator("myevent")[..]);
+
+        let decoded = MyEvent::try_from_slice(&instr.data[16..]).unwrap();
+        assert_eq!(decoded.a, -102);
+        assert_eq!(decoded.b, "foobar");
+    };
+
+    vm.call_params_check.insert(Pubkey(program), check);
+
+    vm.function("go", &[]);
+
+    // nothing is written to the program log
+    assert!(vm.events.is_empty());
+
+    let idl = vm.stack[0].idl.as_ref().unwrap();
+    let go = idl.instructions.iter().find(|i| i.name == "go").unwrap();
+    let names: Vec<String> = go
+        .accounts
+        .iter()
+        .map(|item| match item {
+            anchor_syn::idl::IdlAccountItem::IdlAccount(account) => account.name.clone(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert!(names.contains(&"eventAuthority".to_string()));
+    assert!(names.contains(&"program".to_string()));
+}
+
+#[test]
+fn emit_cpi_forged() {
+    let mut vm = build_solidity(
+        r#"
+        @emit_cpi(true)
+        contract c {
+            event myevent(int32 indexed a);
+
+            function go() public {
+                emit myevent(1);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let mut calldata = vec![0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+    calldata.extend_from_slice(&calculate_discriminator("myevent"));
+    calldata.extend_from_slice(&1i32.to_le_bytes());
+
+    let authority = create_program_address(&vm.stack[0].program, &[b"__event_authority", &[255]]);
+    let other = Pubkey(account_new());
+
+    for account in [&authority, &other] {
+        vm.account_data.insert(
+            account.0,
+            AccountState {
+                data: vec![],
+                owner: None,
+                lamports: 0,
+            },
+        );
+    }
+
+    // the event authority did not sign
+    let res = vm.execute(
+        &[AccountMeta {
+            pubkey: authority,
+            is_writable: false,
+            is_signer: false,
+        }],
+        &calldata,
+    );
+    assert_eq!(res.unwrap(), 8u64 << 32);
+
+    // some other account signed
+    let res = vm.execute(
+        &[AccountMeta {
+            pubkey: other,
+            is_writable: false,
+            is_signer: true,
+        }],
+        &calldata,
+    );
+    assert_eq!(res.unwrap(), 2u64 << 32);
+}
+
 fn calculate_discriminator(event_name: &str) -> Vec<u8> {
     let image = format!("event:{event_name}");
     let mut hasher = Sha256::new();
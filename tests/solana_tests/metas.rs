@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{account_new, build_solidity, AccountState, BorshToken};
+use crate::{account_new, build_solidity, AccountMeta, AccountState, BorshToken, Pubkey};
+use solang::abi::anchor::discriminator;
 
 #[test]
 fn use_authority() {
@@ -49,3 +50,76 @@ fn use_authority() {
         }
     );
 }
+
+#[test]
+fn declared_accounts() {
+    let mut vm = build_solidity(
+        r#"
+        contract counter {
+            uint64 count;
+
+            @signer(authority)
+            @mutableAccount(wallet)
+            function inc() public returns (address) {
+                count += 1;
+                return tx.accounts.authority.key;
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let authority = account_new();
+    let wallet = account_new();
+
+    for account in [authority, wallet] {
+        vm.account_data.insert(
+            account,
+            AccountState {
+                data: vec![],
+                owner: None,
+                lamports: 0,
+            },
+        );
+    }
+
+    let mut metas = vec![
+        AccountMeta {
+            pubkey: Pubkey(vm.stack[0].data),
+            is_writable: true,
+            is_signer: false,
+        },
+        AccountMeta {
+            pubkey: Pubkey(authority),
+            is_writable: false,
+            is_signer: false,
+        },
+        AccountMeta {
+            pubkey: Pubkey(wallet),
+            is_writable: true,
+            is_signer: false,
+        },
+    ];
+
+    let calldata = discriminator("global", "inc");
+
+    // NotEnoughAccountKeys
+    let res = vm.execute(&metas[..2], &calldata).unwrap();
+    assert_eq!(res, 11u64 << 32);
+
+    // MissingRequiredSignature
+    let res = vm.execute(&metas, &calldata).unwrap();
+    assert_eq!(res, 8u64 << 32);
+
+    metas[1].is_signer = true;
+    metas[2].is_writable = false;
+
+    // InvalidAccountData
+    let res = vm.execute(&metas, &calldata).unwrap();
+    assert_eq!(res, 4u64 << 32);
+
+    metas[2].is_writable = true;
+
+    let res = vm.function_metas("inc", &metas, &[]).unwrap();
+    assert_eq!(res, BorshToken::Address(authority));
+}
@@ -27,6 +27,7 @@ mod returns;
 mod runtime_errors;
 mod signature_verify;
 mod simple;
+mod spl_token;
 mod storage;
 mod strings;
 mod unused_variable_elimination;
@@ -0,0 +1,603 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    build_solidity, create_program_address, AccountState, BorshToken, Instruction, Pubkey,
+    VirtualMachine,
+};
+use base58::FromBase58;
+use num_bigint::BigInt;
+
+const TOKEN_PROGRAM: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_2022_PROGRAM: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+const ATA_PROGRAM: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+
+const WALLET: [u8; 32] = [1; 32];
+const MINT: [u8; 32] = [2; 32];
+const PAYER: [u8; 32] = [3; 32];
+const ACCOUNT: [u8; 32] = [4; 32];
+const AUTHORITY: [u8; 32] = [5; 32];
+
+fn pubkey(base58: &str) -> Pubkey {
+    Pubkey(base58.from_base58().unwrap().try_into().unwrap())
+}
+
+fn uint(width: u16, value: u64) -> BorshToken {
+    BorshToken::Uint {
+        width,
+        value: BigInt::from(value),
+    }
+}
+
+/// Layout of an spl-token mint, see `spl_token::state::Mint`
+fn mint_data(supply: u64, decimals: u8, freeze_authority: Option<[u8; 32]>) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&AUTHORITY);
+    data.extend_from_slice(&supply.to_le_bytes());
+    data.push(decimals);
+    data.push(1);
+    data.extend_from_slice(&(freeze_authority.is_some() as u32).to_le_bytes());
+    data.extend_from_slice(&freeze_authority.unwrap_or_default());
+    assert_eq!(data.len(), 82);
+    data
+}
+
+/// Layout of an spl-token account, see `spl_token::state::Account`
+fn token_account_data(amount: u64, close_authority: Option<[u8; 32]>) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(&MINT);
+    data.extend_from_slice(&WALLET);
+    data.extend_from_slice(&amount.to_le_bytes());
+    // delegate
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&AUTHORITY);
+    // frozen
+    data.push(2);
+    // is_native
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&0u64.to_le_bytes());
+    // delegated amount
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.extend_from_slice(&(close_authority.is_some() as u32).to_le_bytes());
+    data.extend_from_slice(&close_authority.unwrap_or_default());
+    assert_eq!(data.len(), 165);
+    data
+}
+
+/// Token-2022 stores extensions as type-length-value entries after the base account,
+/// which is padded to the size of a token account, and the account type.
+fn with_extensions(mut data: Vec<u8>, account_type: u8, extensions: &[(u16, Vec<u8>)]) -> Vec<u8> {
+    data.resize(165, 0);
+    data.push(account_type);
+
+    for (ty, value) in extensions {
+        data.extend_from_slice(&ty.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+
+    data
+}
+
+fn add_account(vm: &mut VirtualMachine, address: [u8; 32], data: Vec<u8>, owner: &str) {
+    vm.account_data.insert(
+        address,
+        AccountState {
+            data,
+            owner: Some(pubkey(owner).0),
+            lamports: 0,
+        },
+    );
+}
+
+#[test]
+fn associated_token_account() {
+    let mut vm = build_solidity(
+        r#"
+        import 'solana';
+        import 'spl_associated_token_account.sol';
+
+        contract c {
+            function derive(address wallet, address mint) public returns (address) {
+                return SplAssociatedTokenAccount.get_associated_token_address(wallet, mint);
+            }
+
+            function create(address payer, address wallet, address mint) public {
+                SplAssociatedTokenAccount.create_idempotent(payer, wallet, mint, address"TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let returns = vm
+        .function(
+            "derive",
+            &[BorshToken::Address(WALLET), BorshToken::Address(MINT)],
+        )
+        .unwrap();
+
+    let ata = create_program_address(
+        &pubkey(ATA_PROGRAM).0,
+        &[&WALLET, &pubkey(TOKEN_PROGRAM).0, &MINT, &[255]],
+    );
+
+    assert_eq!(returns, BorshToken::Address(ata.0));
+
+    let test_args = |_vm: &VirtualMachine, instr: &Instruction, _signers: &[Pubkey]| {
+        let token_2022 = pubkey(TOKEN_2022_PROGRAM);
+        let ata = create_program_address(
+            &pubkey(ATA_PROGRAM).0,
+            &[&WALLET, &token_2022.0, &MINT, &[255]],
+        );
+
+        // CreateIdempotent
+        assert_eq!(instr.data, vec![1]);
+
+        let accounts: Vec<_> = instr
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey.clone(), meta.is_writable, meta.is_signer))
+            .collect();
+
+        assert_eq!(
+            accounts,
+            vec![
+                (Pubkey(PAYER), true, true),
+                (ata, true, false),
+                (Pubkey(WALLET), false, false),
+                (Pubkey(MINT), false, false),
+                (Pubkey([0; 32]), false, false),
+                (token_2022, false, false),
+            ]
+        );
+    };
+
+    vm.call_params_check.insert(pubkey(ATA_PROGRAM), test_args);
+
+    vm.function(
+        "create",
+        &[
+            BorshToken::Address(PAYER),
+            BorshToken::Address(WALLET),
+            BorshToken::Address(MINT),
+        ],
+    );
+}
+
+#[test]
+fn token_instructions() {
+    let mut vm = build_solidity(
+        r#"
+        import 'solana';
+        import 'spl_token.sol';
+
+        contract c {
+            function transfer(address from, address mint, address to, address owner) public {
+                SplToken.transfer_checked(from, mint, to, owner, 1000, 6);
+            }
+
+            function close(address account, address destination, address owner) public {
+                SplToken.close_account(account, destination, owner);
+            }
+
+            function set_close_authority(address account, address owner, address authority) public {
+                SplToken.set_authority(account, owner, SplToken.AuthorityType.CloseAccount, authority);
+            }
+
+            function freeze(address account, address mint, address authority) public {
+                SplToken.freeze_account(account, mint, authority);
+            }
+
+            function thaw(address account, address mint, address authority) public {
+                SplToken.thaw_account(account, mint, authority);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let test_args = |_vm: &VirtualMachine, instr: &Instruction, _signers: &[Pubkey]| {
+        let accounts: Vec<_> = instr
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey.0, meta.is_writable, meta.is_signer))
+            .collect();
+
+        match instr.data[0] {
+            // TransferChecked
+            12 => {
+                let mut data = vec![12];
+                data.extend_from_slice(&1000u64.to_le_bytes());
+                data.push(6);
+                assert_eq!(instr.data, data);
+                assert_eq!(
+                    accounts,
+                    vec![
+                        (ACCOUNT, true, false),
+                        (MINT, false, false),
+                        (PAYER, true, false),
+                        (WALLET, false, true)
+                    ]
+                );
+            }
+            // CloseAccount
+            9 => {
+                assert_eq!(instr.data, vec![9]);
+                assert_eq!(
+                    accounts,
+                    vec![
+                        (ACCOUNT, true, false),
+                        (PAYER, true, false),
+                        (WALLET, false, true)
+                    ]
+                );
+            }
+            // SetAuthority
+            6 => {
+                let mut data = vec![6, 3];
+                if instr.data.len() == 3 {
+                    data.push(0);
+                } else {
+                    data.push(1);
+                    data.extend_from_slice(&AUTHORITY);
+                }
+                assert_eq!(instr.data, data);
+                assert_eq!(
+                    accounts,
+                    vec![(ACCOUNT, true, false), (WALLET, false, true)]
+                );
+            }
+            // FreezeAccount and ThawAccount
+            10 | 11 => {
+                assert_eq!(instr.data.len(), 1);
+                assert_eq!(
+                    accounts,
+                    vec![
+                        (ACCOUNT, true, false),
+                        (MINT, false, false),
+                        (AUTHORITY, false, true)
+                    ]
+                );
+            }
+            instruction => panic!("unexpected token instruction {instruction}"),
+        }
+    };
+
+    vm.call_params_check
+        .insert(pubkey(TOKEN_PROGRAM), test_args);
+
+    vm.function(
+        "transfer",
+        &[
+            BorshToken::Address(ACCOUNT),
+            BorshToken::Address(MINT),
+            BorshToken::Address(PAYER),
+            BorshToken::Address(WALLET),
+        ],
+    );
+
+    vm.function(
+        "close",
+        &[
+            BorshToken::Address(ACCOUNT),
+            BorshToken::Address(PAYER),
+            BorshToken::Address(WALLET),
+        ],
+    );
+
+    vm.function(
+        "set_close_authority",
+        &[
+            BorshToken::Address(ACCOUNT),
+            BorshToken::Address(WALLET),
+            BorshToken::Address(AUTHORITY),
+        ],
+    );
+
+    vm.function(
+        "set_close_authority",
+        &[
+            BorshToken::Address(ACCOUNT),
+            BorshToken::Address(WALLET),
+            BorshToken::Address([0; 32]),
+        ],
+    );
+
+    for name in ["freeze", "thaw"] {
+        vm.function(
+            name,
+            &[
+                BorshToken::Address(ACCOUNT),
+                BorshToken::Address(MINT),
+                BorshToken::Address(AUTHORITY),
+            ],
+        );
+    }
+}
+
+#[test]
+fn token_account_layouts() {
+    let mut vm = build_solidity(
+        r#"
+        import 'solana';
+        import 'spl_token.sol';
+
+        contract c {
+            function account(address account) public view returns (SplToken.TokenAccountData) {
+                return SplToken.get_token_account_data(account);
+            }
+
+            function mint(address mint) public view returns (SplToken.MintAccountData) {
+                return SplToken.get_mint_account_data(mint);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    add_account(
+        &mut vm,
+        ACCOUNT,
+        token_account_data(12345, Some(PAYER)),
+        TOKEN_PROGRAM,
+    );
+    add_account(
+        &mut vm,
+        MINT,
+        mint_data(1_000_000, 9, Some(AUTHORITY)),
+        TOKEN_PROGRAM,
+    );
+
+    let returns = vm
+        .function("account", &[BorshToken::Address(ACCOUNT)])
+        .unwrap();
+
+    assert_eq!(
+        returns.unwrap_tuple(),
+        vec![
+            BorshToken::Address(MINT),
+            BorshToken::Address(WALLET),
+            uint(64, 12345),
+            BorshToken::Bool(true),
+            BorshToken::Address(AUTHORITY),
+            uint(8, 2),
+            BorshToken::Bool(false),
+            uint(64, 0),
+            uint(64, 500),
+            BorshToken::Bool(true),
+            BorshToken::Address(PAYER),
+        ]
+    );
+
+    let returns = vm.function("mint", &[BorshToken::Address(MINT)]).unwrap();
+
+    assert_eq!(
+        returns.unwrap_tuple(),
+        vec![
+            BorshToken::Bool(true),
+            BorshToken::Address(AUTHORITY),
+            uint(64, 1_000_000),
+            uint(8, 9),
+            BorshToken::Bool(true),
+            BorshToken::Bool(true),
+            BorshToken::Address(AUTHORITY),
+        ]
+    );
+}
+
+#[test]
+fn token_2022_extensions() {
+    let mut vm = build_solidity(
+        r#"
+        import 'solana';
+        import 'spl_token_2022.sol';
+
+        contract c {
+            function extension(address account, SplToken2022.ExtensionType ty) public view returns (bool, bytes) {
+                return SplToken2022.get_extension_data(account, ty);
+            }
+
+            function fee(address mint, uint64 epoch, uint64 amount) public view returns (uint64) {
+                SplToken2022.TransferFeeConfig config = SplToken2022.get_transfer_fee_config(mint);
+
+                return SplToken2022.calculate_fee(config, epoch, amount);
+            }
+
+            function balance(address account) public view returns (uint64) {
+                return SplToken2022.get_balance(account);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    // TransferFeeConfig: a fee of 1% up to 5000 from epoch 10, and 0.5% up to 100 before
+    let mut transfer_fee_config = Vec::new();
+    transfer_fee_config.extend_from_slice(&AUTHORITY);
+    transfer_fee_config.extend_from_slice(&AUTHORITY);
+    transfer_fee_config.extend_from_slice(&77u64.to_le_bytes());
+    for (epoch, maximum_fee, basis_points) in [(0u64, 100u64, 50u16), (10, 5000, 100)] {
+        transfer_fee_config.extend_from_slice(&epoch.to_le_bytes());
+        transfer_fee_config.extend_from_slice(&maximum_fee.to_le_bytes());
+        transfer_fee_config.extend_from_slice(&basis_points.to_le_bytes());
+    }
+    assert_eq!(transfer_fee_config.len(), 108);
+
+    add_account(
+        &mut vm,
+        MINT,
+        with_extensions(
+            mint_data(1_000_000, 6, None),
+            1,
+            &[(3, AUTHORITY.to_vec()), (1, transfer_fee_config)],
+        ),
+        TOKEN_2022_PROGRAM,
+    );
+
+    // TransferFeeAmount and MemoTransfer
+    add_account(
+        &mut vm,
+        ACCOUNT,
+        with_extensions(
+            token_account_data(4242, None),
+            2,
+            &[(2, 3u64.to_le_bytes().to_vec()), (8, vec![1])],
+        ),
+        TOKEN_2022_PROGRAM,
+    );
+
+    let returns = vm
+        .function("extension", &[BorshToken::Address(ACCOUNT), uint(8, 8)])
+        .unwrap();
+    assert_eq!(
+        returns.unwrap_tuple(),
+        vec![BorshToken::Bool(true), BorshToken::Bytes(vec![1])]
+    );
+
+    let returns = vm
+        .function("extension", &[BorshToken::Address(MINT), uint(8, 3)])
+        .unwrap();
+    assert_eq!(
+        returns.unwrap_tuple(),
+        vec![
+            BorshToken::Bool(true),
+            BorshToken::Bytes(AUTHORITY.to_vec())
+        ]
+    );
+
+    // ImmutableOwner
+    let returns = vm
+        .function("extension", &[BorshToken::Address(ACCOUNT), uint(8, 7)])
+        .unwrap();
+    assert_eq!(
+        returns.unwrap_tuple(),
+        vec![BorshToken::Bool(false), BorshToken::Bytes(vec![])]
+    );
+
+    let returns = vm
+        .function("balance", &[BorshToken::Address(ACCOUNT)])
+        .unwrap();
+    assert_eq!(returns, uint(64, 4242));
+
+    for (epoch, amount, fee) in [
+        (5, 1000, 5),
+        (5, 1_000_000, 100),
+        (10, 1001, 11),
+        (12, 1_000_000, 5000),
+        (12, 0, 0),
+    ] {
+        let returns = vm
+            .function(
+                "fee",
+                &[BorshToken::Address(MINT), uint(64, epoch), uint(64, amount)],
+            )
+            .unwrap();
+        assert_eq!(returns, uint(64, fee));
+    }
+}
+
+#[test]
+fn token_2022_instructions() {
+    let mut vm = build_solidity(
+        r#"
+        import 'solana';
+        import 'spl_token_2022.sol';
+
+        contract c {
+            function transfer(address from, address mint, address to, address owner) public {
+                SplToken2022.transfer_checked_with_fee(from, mint, to, owner, 1000, 6, 10);
+            }
+
+            function close_authority(address mint, address authority) public {
+                SplToken2022.initialize_mint_close_authority(mint, authority);
+            }
+
+            function reallocate(address account, address payer, address owner) public {
+                SplToken2022.ExtensionType[] extensions = new SplToken2022.ExtensionType[](2);
+                extensions[0] = SplToken2022.ExtensionType.MemoTransfer;
+                extensions[1] = SplToken2022.ExtensionType.CpiGuard;
+
+                SplToken2022.reallocate(account, payer, owner, extensions);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let test_args = |_vm: &VirtualMachine, instr: &Instruction, _signers: &[Pubkey]| {
+        let accounts: Vec<_> = instr
+            .accounts
+            .iter()
+            .map(|meta| (meta.pubkey.0, meta.is_writable, meta.is_signer))
+            .collect();
+
+        match instr.data[0] {
+            // TransferFeeExtension
+            26 => {
+                let mut data = vec![26, 1];
+                data.extend_from_slice(&1000u64.to_le_bytes());
+                data.push(6);
+                data.extend_from_slice(&10u64.to_le_bytes());
+                assert_eq!(instr.data, data);
+                assert_eq!(
+                    accounts,
+                    vec![
+                        (ACCOUNT, true, false),
+                        (MINT, false, false),
+                        (PAYER, true, false),
+                        (WALLET, false, true)
+                    ]
+                );
+            }
+            // InitializeMintCloseAuthority
+            25 => {
+                let mut data = vec![25, 1];
+                data.extend_from_slice(&AUTHORITY);
+                assert_eq!(instr.data, data);
+                assert_eq!(accounts, vec![(MINT, true, false)]);
+            }
+            // Reallocate
+            29 => {
+                assert_eq!(instr.data, vec![29, 8, 0, 11, 0]);
+                assert_eq!(
+                    accounts,
+                    vec![
+                        (ACCOUNT, true, false),
+                        (PAYER, true, true),
+                        ([0; 32], false, false),
+                        (WALLET, false, true)
+                    ]
+                );
+            }
+            instruction => panic!("unexpected token instruction {instruction}"),
+        }
+    };
+
+    vm.call_params_check
+        .insert(pubkey(TOKEN_2022_PROGRAM), test_args);
+
+    vm.function(
+        "transfer",
+        &[
+            BorshToken::Address(ACCOUNT),
+            BorshToken::Address(MINT),
+            BorshToken::Address(PAYER),
+            BorshToken::Address(WALLET),
+        ],
+    );
+
+    vm.function(
+        "close_authority",
+        &[BorshToken::Address(MINT), BorshToken::Address(AUTHORITY)],
+    );
+
+    vm.function(
+        "reallocate",
+        &[
+            BorshToken::Address(ACCOUNT),
+            BorshToken::Address(PAYER),
+            BorshToken::Address(WALLET),
+        ],
+    );
+}
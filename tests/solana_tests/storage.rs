@@ -894,3 +894,128 @@ fn complex_struct() {
 }
 
 // dereference struct storage member (read/write)
+
+#[test]
+fn transient_storage() {
+    let mut vm = build_solidity(
+        r#"
+        contract foo {
+            uint64 transient counter;
+            bool transient locked;
+            uint64 total;
+
+            function bump() public returns (uint64) {
+                counter += 1;
+                counter++;
+                total += counter;
+                return counter;
+            }
+
+            function guarded() public returns (bool) {
+                require(!locked, "reentrant");
+                locked = true;
+                bool was_locked = locked;
+                delete locked;
+                return was_locked && !locked;
+            }
+
+            function get() public view returns (uint64, uint64) {
+                return (counter, total);
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let returns = vm.function("bump", &[]).unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(2u8),
+        }
+    );
+
+    // transient storage is discarded at the end of the instruction
+    let returns = vm.function("bump", &[]).unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(2u8),
+        }
+    );
+
+    let returns = vm.function("get", &[]).unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Tuple(vec![
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::zero(),
+            },
+            BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(4u8),
+            },
+        ])
+    );
+
+    let returns = vm.function("guarded", &[]).unwrap();
+    assert_eq!(returns, BorshToken::Bool(true));
+}
+
+#[test]
+fn transient_storage_assembly() {
+    let mut vm = build_solidity(
+        r#"
+        contract foo {
+            uint256 transient value;
+            uint64 transient counter;
+
+            function add(uint256 v) public returns (uint256 r, uint256 s) {
+                value = 100;
+                assembly {
+                    tstore(value.slot, add(tload(value.slot), v))
+                    r := tload(value.slot)
+                }
+                s = value;
+            }
+
+            function out_of_bounds() public {
+                assembly {
+                    tstore(counter.slot, 1)
+                }
+            }
+        }"#,
+    );
+
+    vm.constructor(&[]);
+
+    let returns = vm
+        .function(
+            "add",
+            &[BorshToken::Uint {
+                width: 256,
+                value: BigInt::from(5u8),
+            }],
+        )
+        .unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Tuple(vec![
+            BorshToken::Uint {
+                width: 256,
+                value: BigInt::from(105u8),
+            },
+            BorshToken::Uint {
+                width: 256,
+                value: BigInt::from(105u8),
+            },
+        ])
+    );
+
+    // tstore() writes 32 bytes, which does not fit after the uint64
+    let res = vm.function_must_fail("out_of_bounds", &[]);
+    assert_eq!(res.unwrap(), 4294967296);
+}
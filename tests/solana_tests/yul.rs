@@ -753,3 +753,134 @@ contract Testing {
         },
     );
 }
+
+#[test]
+fn immutable_and_length_assignment() {
+    let mut vm = build_solidity(
+        r#"
+contract C {
+    uint64 immutable scale;
+
+    constructor(uint64 s) {
+        scale = s;
+    }
+
+    function scaled(uint64 a) public view returns (uint64 r) {
+        assembly {
+            r := mul(a, scale)
+        }
+    }
+
+    function shrink(uint32[] memory vl, uint32 n) public pure returns (uint32[] memory) {
+        assembly {
+            vl.length := n
+        }
+
+        return vl;
+    }
+
+    function shrink_calldata(uint32[] calldata vl, uint32 n) public pure returns (uint256 len) {
+        assembly {
+            vl.length := n
+            len := vl.length
+        }
+    }
+}
+        "#,
+    );
+
+    vm.constructor(&[BorshToken::Uint {
+        width: 64,
+        value: BigInt::from(3u8),
+    }]);
+
+    let returns = vm
+        .function(
+            "scaled",
+            &[BorshToken::Uint {
+                width: 64,
+                value: BigInt::from(14u8),
+            }],
+        )
+        .unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 64,
+            value: BigInt::from(42u8),
+        }
+    );
+
+    let array = BorshToken::Array(vec![
+        BorshToken::Uint {
+            width: 32,
+            value: BigInt::from(1u8),
+        },
+        BorshToken::Uint {
+            width: 32,
+            value: BigInt::from(2u8),
+        },
+        BorshToken::Uint {
+            width: 32,
+            value: BigInt::from(3u8),
+        },
+    ]);
+
+    let returns = vm
+        .function(
+            "shrink",
+            &[
+                array.clone(),
+                BorshToken::Uint {
+                    width: 32,
+                    value: BigInt::from(2u8),
+                },
+            ],
+        )
+        .unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Array(vec![
+            BorshToken::Uint {
+                width: 32,
+                value: BigInt::from(1u8),
+            },
+            BorshToken::Uint {
+                width: 32,
+                value: BigInt::from(2u8),
+            },
+        ])
+    );
+
+    let returns = vm
+        .function(
+            "shrink_calldata",
+            &[
+                array.clone(),
+                BorshToken::Uint {
+                    width: 32,
+                    value: BigInt::zero(),
+                },
+            ],
+        )
+        .unwrap();
+    assert_eq!(
+        returns,
+        BorshToken::Uint {
+            width: 256,
+            value: BigInt::zero(),
+        }
+    );
+
+    let res = vm.function_must_fail(
+        "shrink",
+        &[
+            array,
+            BorshToken::Uint {
+                width: 32,
+                value: BigInt::from(4u8),
+            },
+        ],
+    );
+    assert!(res.is_err());
+}
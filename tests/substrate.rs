@@ -109,6 +109,8 @@ impl WasmCode {
 pub struct Contract {
     code: WasmCode,
     storage: HashMap<StorageKey, Vec<u8>>,
+    /// Transient storage is discarded at the end of each transaction.
+    transient_storage: HashMap<StorageKey, Vec<u8>>,
 }
 
 impl From<WasmCode> for Contract {
@@ -116,6 +118,7 @@ impl From<WasmCode> for Contract {
         Self {
             code,
             storage: HashMap::new(),
+            transient_storage: HashMap::new(),
         }
     }
 }
@@ -226,8 +229,14 @@ struct Runtime {
     debug_buffer: String,
     /// Stores all events emitted during contract execution.
     events: Vec<Event>,
+    /// Stores all runtime calls dispatched during contract execution.
+    runtime_calls: Vec<Vec<u8>>,
+    /// Stores all XCM messages executed or sent during contract execution.
+    xcm_messages: Vec<Vec<u8>>,
     /// The set of called events, needed for reentrancy protection.
     called_accounts: HashSet<usize>,
+    /// The accounts of all contract executions currently on the call stack.
+    call_stack: Vec<usize>,
 }
 
 impl Runtime {
@@ -258,16 +267,20 @@ impl Runtime {
         runtime.input = Some(input);
         runtime.output = Default::default();
         runtime.called_accounts.insert(self.caller_account);
+        runtime.call_stack.push(callee);
         runtime
     }
 
     /// After a succesfull contract execution, merge the runtime context of the callee back.
     ///
-    /// We take over accounts (the callee might deploy new ones), debug buffer and emitted events.
+    /// We take over accounts (the callee might deploy new ones), debug buffer, emitted events,
+    /// dispatched runtime calls and XCM messages.
     /// The transferred balance will now be deducted from the caller.
     fn accept_state(&mut self, callee_state: Self, transferred_value: u128) {
         self.debug_buffer = callee_state.debug_buffer;
         self.events = callee_state.events;
+        self.runtime_calls = callee_state.runtime_calls;
+        self.xcm_messages = callee_state.xcm_messages;
         self.accounts = callee_state.accounts;
         self.accounts[self.caller_account].value -= transferred_value;
     }
@@ -304,6 +317,28 @@ impl Runtime {
             .into()
     }
 
+    /// Execute the given `code` in the context of the current account, like `delegate_call` does.
+    ///
+    /// The caller, the transferred value and the storage of the current account are kept.
+    fn delegate_call(&mut self, code: WasmCode, input: Vec<u8>) -> Result<Store<Runtime>, Error> {
+        println!(
+            "delegate_call: code_hash={} input={}",
+            hex::encode(code.hash),
+            hex::encode(&input)
+        );
+
+        let mut runtime = self.clone();
+        runtime.input = Some(input);
+        runtime.output = Default::default();
+
+        Contract::from(code)
+            .execute("call", runtime)
+            .map_err(|(err, debug_buffer)| {
+                self.debug_buffer = debug_buffer;
+                err
+            })
+    }
+
     /// Add a new contract account and call its "deploy" function accordingly.
     ///
     /// Returns `None` if there is no contract corresponding to the given `code_hash`.
@@ -349,6 +384,128 @@ fn read_account(mem: &[u8], ptr: u32) -> Address {
     Address::try_from(&mem[ptr as usize..(ptr + 32) as usize]).unwrap()
 }
 
+/// Call another contract; the versions of the call host function only differ in their limits.
+#[allow(clippy::too_many_arguments)]
+fn call_contract(
+    mem: &mut [u8],
+    vm: &mut Runtime,
+    flags: u32,
+    callee_ptr: u32,
+    value_ptr: u32,
+    input_ptr: u32,
+    input_len: u32,
+    output_ptr: u32,
+    output_len_ptr: u32,
+) -> Result<u32, Trap> {
+    assert!(flags <= 0b1111);
+
+    let input = if CallFlags::ForwardInput.set(flags) {
+        if vm.input.is_none() {
+            return Ok(1);
+        }
+        vm.input.take().unwrap()
+    } else if CallFlags::CloneInput.set(flags) {
+        if vm.input.is_none() {
+            return Ok(1);
+        }
+        vm.input.as_ref().unwrap().clone()
+    } else {
+        read_buf(mem, input_ptr, input_len)
+    };
+    let value = read_value(mem, value_ptr);
+    let callee_address = read_account(mem, callee_ptr);
+
+    let callee = match vm
+        .accounts
+        .iter()
+        .enumerate()
+        .find(|(_, account)| account.address == callee_address)
+        .map(|(index, _)| index)
+    {
+        Some(index) => index,
+        None => return Ok(8), // ReturnCode::NotCallable
+    };
+
+    if vm.called_accounts.contains(&callee) && !CallFlags::AllowReentry.set(flags) {
+        return Ok(1);
+    }
+
+    if value > vm.accounts[vm.account].value {
+        return Ok(5); // ReturnCode::TransferFailed
+    }
+
+    let ((ret, data), state) = match vm.call("call", callee, input, value) {
+        Some(Ok(state)) => ((state.data().output.as_data()), state),
+        Some(Err(_)) => return Ok(1), // ReturnCode::CalleeTrapped
+        None => return Ok(8),
+    };
+
+    if output_len_ptr != u32::MAX {
+        assert!(read_len(mem, output_len_ptr) >= data.len());
+        write_buf(mem, output_ptr, &data);
+        write_buf(mem, output_len_ptr, &(data.len() as u32).to_le_bytes());
+    }
+
+    if ret == 2 {
+        return Ok(2); // ReturnCode::CalleeReverted
+    }
+
+    vm.accept_state(state.into_data(), value);
+    if CallFlags::TailCall.set(flags) {
+        return Err(HostReturn::Data(0, data).into());
+    }
+    Ok(0)
+}
+
+/// Instantiate a contract; the versions of the instantiate host function only differ in their
+/// limits.
+#[allow(clippy::too_many_arguments)]
+fn instantiate_contract(
+    mem: &mut [u8],
+    vm: &mut Runtime,
+    code_hash_ptr: u32,
+    value_ptr: u32,
+    input_data_ptr: u32,
+    input_data_len: u32,
+    address_ptr: u32,
+    address_len_ptr: u32,
+    output_ptr: u32,
+    output_len_ptr: u32,
+    salt_ptr: u32,
+    salt_len: u32,
+) -> Result<u32, Trap> {
+    let code_hash = read_account(mem, code_hash_ptr);
+    let salt = read_buf(mem, salt_ptr, salt_len);
+    let input = read_buf(mem, input_data_ptr, input_data_len);
+    let value = read_value(mem, value_ptr);
+
+    if value > vm.accounts[vm.account].value {
+        return Ok(5); // ReturnCode::TransferFailed
+    }
+
+    let ((flags, data), state) = match vm.deploy(code_hash, value, &salt, input) {
+        Some(Ok(state)) => ((state.data().output.as_data()), state),
+        Some(Err(_)) => return Ok(1), // ReturnCode::CalleeTrapped
+        None => return Ok(7),         // ReturnCode::CodeNotFound
+    };
+
+    if output_len_ptr != u32::MAX {
+        write_buf(mem, output_ptr, &data);
+        write_buf(mem, output_len_ptr, &(data.len() as u32).to_le_bytes());
+    }
+
+    let address = state.data().accounts.last().unwrap().address;
+    write_buf(mem, address_ptr, &address);
+    write_buf(mem, address_len_ptr, &(address.len() as u32).to_le_bytes());
+
+    if flags == 2 {
+        return Ok(2); // ReturnCode::CalleeReverted
+    }
+
+    vm.accept_state(state.into_data(), value);
+    Ok(0)
+}
+
 /// Host functions mock the original implementation, refer to the [pallet docs][1] for more information.
 ///
 /// [1]: https://docs.rs/pallet-contracts/latest/pallet_contracts/api_doc/index.html
@@ -445,6 +602,99 @@ impl Runtime {
         }
     }
 
+    #[seal(0)]
+    fn get_transient_storage(
+        key_ptr: u32,
+        key_len: u32,
+        out_ptr: u32,
+        out_len_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        let value = match vm.contract().transient_storage.get(&key) {
+            Some(value) => value,
+            _ => return Ok(3), // In pallet-contracts, ReturnCode::KeyNotFound == 3
+        };
+        println!(
+            "get_transient_storage: {}={}",
+            hex::encode(key),
+            hex::encode(value)
+        );
+
+        write_buf(mem, out_ptr, value);
+        write_buf(mem, out_len_ptr, &(value.len() as u32).to_le_bytes());
+
+        Ok(0)
+    }
+
+    #[seal(1)]
+    fn contains_storage(key_ptr: u32, key_len: u32) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        println!("contains_storage: {}", hex::encode(key));
+
+        match vm.contract().storage.get(&key) {
+            Some(value) => Ok(value.len() as u32),
+            _ => Ok(u32::MAX), // In pallets contract, u32::MAX is the "none sentinel"
+        }
+    }
+
+    #[seal(0)]
+    fn take_storage(
+        key_ptr: u32,
+        key_len: u32,
+        out_ptr: u32,
+        out_len_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        let value = match vm.contract().storage.remove(&key) {
+            Some(value) => value,
+            _ => return Ok(3), // In pallet-contracts, ReturnCode::KeyNotFound == 3
+        };
+        println!("take_storage: {}={}", hex::encode(key), hex::encode(&value));
+
+        assert!(read_len(mem, out_len_ptr) >= value.len());
+        write_buf(mem, out_ptr, &value);
+        write_buf(mem, out_len_ptr, &(value.len() as u32).to_le_bytes());
+
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn set_transient_storage(
+        key_ptr: u32,
+        key_len: u32,
+        value_ptr: u32,
+        value_len: u32,
+    ) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        let value = read_buf(mem, value_ptr, value_len);
+        println!(
+            "set_transient_storage: {}={}",
+            hex::encode(key),
+            hex::encode(&value)
+        );
+
+        match vm.contract().transient_storage.insert(key, value) {
+            Some(value) => Ok(value.len() as u32),
+            _ => Ok(u32::MAX), // In pallets contract, u32::MAX is the "none sentinel"
+        }
+    }
+
+    #[seal(0)]
+    fn clear_transient_storage(key_ptr: u32, key_len: u32) -> Result<u32, Trap> {
+        let key = StorageKey::try_from(read_buf(mem, key_ptr, key_len))
+            .expect("storage key size must be 32 bytes");
+        println!("clear_transient_storage: {}", hex::encode(key));
+
+        match vm.contract().transient_storage.remove(&key) {
+            Some(value) => Ok(value.len() as u32),
+            _ => Ok(u32::MAX), // In pallets contract, u32::MAX is the "none sentinel"
+        }
+    }
+
     #[seal(0)]
     fn hash_keccak_256(input_ptr: u32, input_len: u32, output_ptr: u32) -> Result<(), Trap> {
         let mut hasher = Keccak::v256();
@@ -486,7 +736,60 @@ impl Runtime {
         output_ptr: u32,
         output_len_ptr: u32,
     ) -> Result<u32, Trap> {
-        assert!(flags <= 0b1111);
+        call_contract(
+            mem,
+            vm,
+            flags,
+            callee_ptr,
+            value_ptr,
+            input_ptr,
+            input_len,
+            output_ptr,
+            output_len_ptr,
+        )
+    }
+
+    #[seal(2)]
+    fn call(
+        flags: u32,
+        callee_ptr: u32,
+        _ref_time_limit: u64,
+        _proof_size_limit: u64,
+        deposit_ptr: u32,
+        value_ptr: u32,
+        input_ptr: u32,
+        input_len: u32,
+        output_ptr: u32,
+        output_len_ptr: u32,
+    ) -> Result<u32, Trap> {
+        assert_eq!(deposit_ptr, u32::MAX, "storage deposit limit is unlimited");
+
+        call_contract(
+            mem,
+            vm,
+            flags,
+            callee_ptr,
+            value_ptr,
+            input_ptr,
+            input_len,
+            output_ptr,
+            output_len_ptr,
+        )
+    }
+
+    #[seal(0)]
+    fn delegate_call(
+        flags: u32,
+        code_hash_ptr: u32,
+        input_ptr: u32,
+        input_len: u32,
+        output_ptr: u32,
+        output_len_ptr: u32,
+    ) -> Result<u32, Trap> {
+        assert!(
+            flags <= 0b0111,
+            "reentrancy flag is not allowed for delegate calls"
+        );
 
         let input = if CallFlags::ForwardInput.set(flags) {
             if vm.input.is_none() {
@@ -501,32 +804,16 @@ impl Runtime {
         } else {
             read_buf(mem, input_ptr, input_len)
         };
-        let value = read_value(mem, value_ptr);
-        let callee_address = read_account(mem, callee_ptr);
+        let code_hash = read_account(mem, code_hash_ptr);
 
-        let callee = match vm
-            .accounts
-            .iter()
-            .enumerate()
-            .find(|(_, account)| account.address == callee_address)
-            .map(|(index, _)| index)
-        {
-            Some(index) => index,
-            None => return Ok(8), // ReturnCode::NotCallable
+        let code = match vm.blobs.iter().find(|code| code.hash == code_hash) {
+            Some(code) => code.clone(),
+            None => return Ok(7), // ReturnCode::CodeNotFound
         };
 
-        if vm.called_accounts.contains(&callee) && !CallFlags::AllowReentry.set(flags) {
-            return Ok(1);
-        }
-
-        if value > vm.accounts[vm.account].value {
-            return Ok(5); // ReturnCode::TransferFailed
-        }
-
-        let ((ret, data), state) = match vm.call("call", callee, input, value) {
-            Some(Ok(state)) => ((state.data().output.as_data()), state),
-            Some(Err(_)) => return Ok(1), // ReturnCode::CalleeTrapped
-            None => return Ok(8),
+        let ((ret, data), state) = match vm.delegate_call(code, input) {
+            Ok(state) => ((state.data().output.as_data()), state),
+            Err(_) => return Ok(1), // ReturnCode::CalleeTrapped
         };
 
         if output_len_ptr != u32::MAX {
@@ -539,13 +826,159 @@ impl Runtime {
             return Ok(2); // ReturnCode::CalleeReverted
         }
 
-        vm.accept_state(state.into_data(), value);
+        vm.accept_state(state.into_data(), 0);
         if CallFlags::TailCall.set(flags) {
             return Err(HostReturn::Data(0, data).into());
         }
         Ok(0)
     }
 
+    #[seal(0)]
+    fn code_hash(account_ptr: u32, out_ptr: u32, out_len_ptr: u32) -> Result<u32, Trap> {
+        let address = read_account(mem, account_ptr);
+        let code_hash = match vm
+            .accounts
+            .iter()
+            .find(|account| account.address == address)
+            .and_then(|account| account.contract.as_ref())
+        {
+            Some(contract) => contract.code.hash,
+            None => return Ok(3), // ReturnCode::KeyNotFound
+        };
+        assert!(read_len(mem, out_len_ptr) >= code_hash.len());
+
+        write_buf(mem, out_ptr, &code_hash);
+        write_buf(mem, out_len_ptr, &(code_hash.len() as u32).to_le_bytes());
+
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn set_code_hash(code_hash_ptr: u32) -> Result<u32, Trap> {
+        let code_hash = read_account(mem, code_hash_ptr);
+        println!("set_code_hash: {}", hex::encode(code_hash));
+
+        match vm.blobs.iter().find(|code| code.hash == code_hash).cloned() {
+            Some(code) => {
+                vm.contract().code = code;
+                Ok(0)
+            }
+            None => Ok(7), // ReturnCode::CodeNotFound
+        }
+    }
+
+    #[seal(0)]
+    fn ecdsa_recover(
+        signature_ptr: u32,
+        message_hash_ptr: u32,
+        output_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let signature = read_buf(mem, signature_ptr, 65);
+        let message_hash = <[u8; 32]>::try_from(read_buf(mem, message_hash_ptr, 32)).unwrap();
+        let recovery_id = match signature[64] {
+            id if id > 26 => id - 27,
+            id => id,
+        };
+
+        let public_key =
+            libsecp256k1::Signature::parse_standard_slice(&signature[..64]).and_then(|signature| {
+                libsecp256k1::recover(
+                    &libsecp256k1::Message::parse(&message_hash),
+                    &signature,
+                    &libsecp256k1::RecoveryId::parse(recovery_id)?,
+                )
+            });
+
+        match public_key {
+            Ok(public_key) => {
+                write_buf(mem, output_ptr, &public_key.serialize_compressed());
+                Ok(0)
+            }
+            Err(_) => Ok(11), // ReturnCode::EcdsaRecoverFailed
+        }
+    }
+
+    #[seal(0)]
+    fn ecdsa_to_eth_address(key_ptr: u32, output_ptr: u32) -> Result<u32, Trap> {
+        let key = <[u8; 33]>::try_from(read_buf(mem, key_ptr, 33)).unwrap();
+
+        match libsecp256k1::PublicKey::parse_compressed(&key) {
+            Ok(public_key) => {
+                let mut hash = [0; 32];
+                let mut hasher = Keccak::v256();
+                hasher.update(&public_key.serialize()[1..]);
+                hasher.finalize(&mut hash);
+                write_buf(mem, output_ptr, &hash[12..]);
+                Ok(0)
+            }
+            Err(_) => Ok(11), // ReturnCode::EcdsaRecoverFailed
+        }
+    }
+
+    #[seal(0)]
+    fn sr25519_verify(
+        signature_ptr: u32,
+        pub_key_ptr: u32,
+        message_len: u32,
+        message_ptr: u32,
+    ) -> Result<u32, Trap> {
+        let signature = schnorrkel::Signature::from_bytes(&read_buf(mem, signature_ptr, 64));
+        let public_key = schnorrkel::PublicKey::from_bytes(&read_buf(mem, pub_key_ptr, 32));
+        let message = read_buf(mem, message_ptr, message_len);
+
+        match (signature, public_key) {
+            (Ok(signature), Ok(public_key))
+                if public_key
+                    .verify_simple(b"substrate", &message, &signature)
+                    .is_ok() =>
+            {
+                Ok(0)
+            }
+            _ => Ok(12), // ReturnCode::Sr25519VerifyFailed
+        }
+    }
+
+    /// Mock runtime call dispatch, which records the call. Empty calls fail to dispatch.
+    #[seal(0)]
+    fn call_runtime(call_ptr: u32, call_len: u32) -> Result<u32, Trap> {
+        let call = read_buf(mem, call_ptr, call_len);
+        println!("call_runtime: {}", hex::encode(&call));
+
+        if call.is_empty() {
+            return Ok(10); // ReturnCode::CallRuntimeFailed
+        }
+        vm.runtime_calls.push(call);
+        Ok(0)
+    }
+
+    /// Mock XCM execution, which records the message. Empty messages fail to execute.
+    #[seal(0)]
+    fn xcm_execute(msg_ptr: u32, msg_len: u32) -> Result<u32, Trap> {
+        let message = read_buf(mem, msg_ptr, msg_len);
+        println!("xcm_execute: {}", hex::encode(&message));
+
+        if message.is_empty() {
+            return Ok(13); // ReturnCode::XcmExecutionFailed
+        }
+        vm.xcm_messages.push(message);
+        Ok(0)
+    }
+
+    /// Mock XCM sending, which records the message and ignores the destination.
+    /// The message hash is the blake2_256 hash of the message. Empty messages fail to send.
+    #[seal(0)]
+    fn xcm_send(_dest_ptr: u32, msg_ptr: u32, msg_len: u32, output_ptr: u32) -> Result<u32, Trap> {
+        let message = read_buf(mem, msg_ptr, msg_len);
+        println!("xcm_send: {}", hex::encode(&message));
+
+        if message.is_empty() {
+            return Ok(14); // ReturnCode::XcmSendFailed
+        }
+        write_buf(mem, output_ptr, blake2b(32, &[], &message).as_bytes());
+        vm.xcm_messages.push(message);
+        Ok(0)
+    }
+
     #[seal(0)]
     fn instantiation_nonce() -> Result<u64, Trap> {
         Ok(vm.accounts.len() as u64)
@@ -558,8 +991,8 @@ impl Runtime {
         Ok(())
     }
 
-    #[seal(1)]
-    fn instantiate(
+    #[seal(1, instantiate)]
+    fn instantiate_v1(
         code_hash_ptr: u32,
         _gas: u64,
         value_ptr: u32,
@@ -572,36 +1005,54 @@ impl Runtime {
         salt_ptr: u32,
         salt_len: u32,
     ) -> Result<u32, Trap> {
-        let code_hash = read_account(mem, code_hash_ptr);
-        let salt = read_buf(mem, salt_ptr, salt_len);
-        let input = read_buf(mem, input_data_ptr, input_data_len);
-        let value = read_value(mem, value_ptr);
-
-        if value > vm.accounts[vm.account].value {
-            return Ok(5); // ReturnCode::TransferFailed
-        }
-
-        let ((flags, data), state) = match vm.deploy(code_hash, value, &salt, input) {
-            Some(Ok(state)) => ((state.data().output.as_data()), state),
-            Some(Err(_)) => return Ok(1), // ReturnCode::CalleeTrapped
-            None => return Ok(7),         // ReturnCode::CodeNotFound
-        };
-
-        if output_len_ptr != u32::MAX {
-            write_buf(mem, output_ptr, &data);
-            write_buf(mem, output_len_ptr, &(data.len() as u32).to_le_bytes());
-        }
-
-        let address = state.data().accounts.last().unwrap().address;
-        write_buf(mem, address_ptr, &address);
-        write_buf(mem, address_len_ptr, &(address.len() as u32).to_le_bytes());
-
-        if flags == 2 {
-            return Ok(2); // ReturnCode::CalleeReverted
-        }
+        instantiate_contract(
+            mem,
+            vm,
+            code_hash_ptr,
+            value_ptr,
+            input_data_ptr,
+            input_data_len,
+            address_ptr,
+            address_len_ptr,
+            output_ptr,
+            output_len_ptr,
+            salt_ptr,
+            salt_len,
+        )
+    }
 
-        vm.accept_state(state.into_data(), value);
-        Ok(0)
+    #[seal(2)]
+    fn instantiate(
+        code_hash_ptr: u32,
+        _ref_time_limit: u64,
+        _proof_size_limit: u64,
+        deposit_ptr: u32,
+        value_ptr: u32,
+        input_data_ptr: u32,
+        input_data_len: u32,
+        address_ptr: u32,
+        address_len_ptr: u32,
+        output_ptr: u32,
+        output_len_ptr: u32,
+        salt_ptr: u32,
+        salt_len: u32,
+    ) -> Result<u32, Trap> {
+        assert_eq!(deposit_ptr, u32::MAX, "storage deposit limit is unlimited");
+
+        instantiate_contract(
+            mem,
+            vm,
+            code_hash_ptr,
+            value_ptr,
+            input_data_ptr,
+            input_data_len,
+            address_ptr,
+            address_len_ptr,
+            output_ptr,
+            output_len_ptr,
+            salt_ptr,
+            salt_len,
+        )
     }
 
     #[seal(0)]
@@ -653,6 +1104,34 @@ impl Runtime {
         Ok(())
     }
 
+    #[seal(0)]
+    fn caller_is_origin() -> Result<u32, Trap> {
+        Ok((vm.call_stack.len() == 1) as u32)
+    }
+
+    #[seal(0)]
+    fn caller_is_root() -> Result<u32, Trap> {
+        Ok(0)
+    }
+
+    #[seal(0)]
+    fn reentrance_count() -> Result<u32, Trap> {
+        let account = vm.account;
+        let count = vm.call_stack.iter().filter(|a| **a == account).count();
+        Ok(count as u32 - 1)
+    }
+
+    #[seal(0)]
+    fn account_reentrance_count(account_ptr: u32) -> Result<u32, Trap> {
+        let address = read_account(mem, account_ptr);
+        let count = vm
+            .call_stack
+            .iter()
+            .filter(|a| vm.accounts[**a].address == address)
+            .count();
+        Ok(count as u32)
+    }
+
     #[seal(0)]
     fn balance(out_ptr: u32, out_len_ptr: u32) -> Result<(), Trap> {
         let balance = vm.accounts[vm.account].value.to_le_bytes();
@@ -797,7 +1276,17 @@ impl MockSubstrate {
 
         runtime.debug_buffer.clear();
         runtime.events.clear();
+        runtime.runtime_calls.clear();
+        runtime.xcm_messages.clear();
         runtime.called_accounts.clear();
+        runtime.call_stack.clear();
+        for contract in runtime
+            .accounts
+            .iter_mut()
+            .filter_map(|a| a.contract.as_mut())
+        {
+            contract.transient_storage.clear();
+        }
         self.0 = runtime.call(export, callee, input, value).unwrap()?;
         self.0.data_mut().transferred_value = 0;
 
@@ -842,6 +1331,16 @@ impl MockSubstrate {
         self.0.data().events.clone()
     }
 
+    /// Get the runtime calls dispatched by the last function or constructor call.
+    pub fn runtime_calls(&self) -> Vec<Vec<u8>> {
+        self.0.data().runtime_calls.clone()
+    }
+
+    /// Get the XCM messages executed or sent by the last function or constructor call.
+    pub fn xcm_messages(&self) -> Vec<Vec<u8>> {
+        self.0.data().xcm_messages.clone()
+    }
+
     /// Get a list of all deployed contracts.
     pub fn contracts(&self) -> Vec<&Contract> {
         self.0
@@ -1001,12 +1500,31 @@ pub fn build_solidity_with_options(src: &str, log_ret: bool, log_err: bool) -> M
     MockSubstrate(Store::new(&Engine::default(), Runtime::new(blobs)))
 }
 
+/// A variant of `MockSubstrate::build_solidity()` for another Substrate target, e.g. with an
+/// older API version.
+pub fn build_solidity_with_target(src: &str, target: Target) -> MockSubstrate {
+    let blobs = build_wasm_with_target(src, target, false, true)
+        .iter()
+        .map(|(code, abi)| WasmCode::new(abi, code))
+        .collect();
+
+    MockSubstrate(Store::new(&Engine::default(), Runtime::new(blobs)))
+}
+
 pub fn build_wasm(src: &str, log_ret: bool, log_err: bool) -> Vec<(Vec<u8>, String)> {
+    build_wasm_with_target(src, Target::default_substrate(), log_ret, log_err)
+}
+
+fn build_wasm_with_target(
+    src: &str,
+    target: Target,
+    log_ret: bool,
+    log_err: bool,
+) -> Vec<(Vec<u8>, String)> {
     let tmp_file = OsStr::new("test.sol");
     let mut cache = FileResolver::new();
     cache.set_file_contents(tmp_file.to_str().unwrap(), src.to_string());
     let opt = inkwell::OptimizationLevel::Default;
-    let target = Target::default_substrate();
     let (wasm, ns) = compile(
         tmp_file,
         &mut cache,
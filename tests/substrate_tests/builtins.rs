@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use blake2_rfc::blake2b::blake2b;
 use parity_scale_codec::{Decode, Encode};
+use tiny_keccak::{Hasher, Keccak};
 
 use crate::build_solidity;
 
@@ -794,3 +796,240 @@ fn is_contract() {
     runtime.function("test", [0; 32].to_vec());
     assert_eq!(runtime.output(), vec![0]);
 }
+
+#[test]
+fn set_code_hash() {
+    let mut runtime = build_solidity(
+        r##"
+        import "substrate";
+
+        contract Counter {
+            uint64 count;
+
+            function bump() public returns (uint64) {
+                count += 1;
+                return count;
+            }
+
+            function upgrade(bytes32 code_hash) public {
+                set_code_hash(code_hash);
+            }
+        }
+
+        contract CounterV2 {
+            uint64 count;
+
+            function bump() public returns (uint64) {
+                count += 10;
+                return count;
+            }
+        }"##,
+    );
+
+    runtime.constructor(0, Vec::new());
+    runtime.function("bump", Vec::new());
+    assert_eq!(runtime.output(), 1u64.encode());
+
+    // Upgrading to unknown code must fail
+    runtime.function_expect_failure("upgrade", [1; 32].to_vec());
+
+    let code_hash = runtime.0.data().blobs[1].hash;
+    runtime.function("upgrade", code_hash.to_vec());
+
+    // The new code operates on the existing storage
+    let selector = runtime.selector(1, "bump").to_vec();
+    runtime.raw_function(selector);
+    assert_eq!(runtime.output(), 11u64.encode());
+}
+
+#[test]
+fn ecdsa() {
+    let mut runtime = build_solidity(
+        r##"
+        import "substrate";
+
+        contract Signatures {
+            function recover(bytes signature, bytes32 message_hash) public pure returns (bool, bytes) {
+                (bool ok, bytes public_key) = ecdsa_recover(signature, message_hash);
+                return (ok, public_key);
+            }
+
+            function to_eth_address(bytes public_key) public pure returns (bool, bytes20) {
+                (bool ok, bytes20 eth_address) = ecdsa_to_eth_address(public_key);
+                return (ok, eth_address);
+            }
+        }"##,
+    );
+
+    let secret_key = libsecp256k1::SecretKey::parse(&[1; 32]).unwrap();
+    let public_key = libsecp256k1::PublicKey::from_secret_key(&secret_key);
+    let message_hash = [42; 32];
+    let (signature, recovery_id) =
+        libsecp256k1::sign(&libsecp256k1::Message::parse(&message_hash), &secret_key);
+    let mut signature = signature.serialize().to_vec();
+    signature.push(recovery_id.serialize());
+
+    runtime.function("recover", (signature, message_hash).encode());
+    assert_eq!(
+        runtime.output(),
+        (true, public_key.serialize_compressed().to_vec()).encode()
+    );
+
+    runtime.function("recover", (vec![0u8; 65], message_hash).encode());
+    assert_eq!(runtime.output(), (false, vec![0u8; 33]).encode());
+
+    runtime.function_expect_failure("recover", (vec![0u8; 64], message_hash).encode());
+
+    let mut hash = [0; 32];
+    let mut hasher = Keccak::v256();
+    hasher.update(&public_key.serialize()[1..]);
+    hasher.finalize(&mut hash);
+    let eth_address = <[u8; 20]>::try_from(&hash[12..]).unwrap();
+
+    runtime.function(
+        "to_eth_address",
+        public_key.serialize_compressed().to_vec().encode(),
+    );
+    assert_eq!(runtime.output(), (true, eth_address).encode());
+
+    runtime.function("to_eth_address", vec![0u8; 33].encode());
+    assert_eq!(runtime.output(), (false, [0u8; 20]).encode());
+
+    runtime.function_expect_failure("to_eth_address", vec![0u8; 65].encode());
+}
+
+#[test]
+fn sr25519_verify() {
+    let mut runtime = build_solidity(
+        r##"
+        import "substrate";
+
+        contract Signatures {
+            function verify(address public_key, bytes message, bytes signature) public pure returns (bool) {
+                return sr25519_verify(public_key, message, signature);
+            }
+        }"##,
+    );
+
+    let keypair = schnorrkel::MiniSecretKey::from_bytes(&[7; 32])
+        .unwrap()
+        .expand_to_keypair(schnorrkel::ExpansionMode::Ed25519);
+    let public_key = keypair.public.to_bytes();
+    let message = b"Hello, Substrate!".to_vec();
+    let signature = keypair
+        .sign_simple(b"substrate", &message)
+        .to_bytes()
+        .to_vec();
+
+    runtime.function(
+        "verify",
+        (public_key, message.clone(), signature.clone()).encode(),
+    );
+    assert_eq!(runtime.output(), true.encode());
+
+    runtime.function(
+        "verify",
+        (public_key, b"Hello, Solana!".to_vec(), signature.clone()).encode(),
+    );
+    assert_eq!(runtime.output(), false.encode());
+
+    runtime.function_expect_failure(
+        "verify",
+        (public_key, message, signature[..63].to_vec()).encode(),
+    );
+}
+
+#[test]
+fn call_runtime_and_xcm() {
+    let mut runtime = build_solidity(
+        r##"
+        import "substrate";
+
+        contract Dispatcher {
+            // System.remark_with_event
+            function remark(bytes remark) public returns (bool) {
+                return call_runtime(abi.encode(uint8(0), uint8(7), remark));
+            }
+
+            function dispatch(bytes call) public returns (bool) {
+                return call_runtime(call);
+            }
+
+            function execute(bytes message) public returns (bool) {
+                return xcm_execute(message);
+            }
+
+            function send(bytes destination, bytes message) public returns (bool, Hash) {
+                return xcm_send(destination, message);
+            }
+        }"##,
+    );
+
+    runtime.function("remark", b"Hello, Substrate!".to_vec().encode());
+    assert_eq!(runtime.output(), true.encode());
+    assert_eq!(
+        runtime.runtime_calls(),
+        vec![(0u8, 7u8, b"Hello, Substrate!".to_vec()).encode()]
+    );
+
+    runtime.function("dispatch", Vec::<u8>::new().encode());
+    assert_eq!(runtime.output(), false.encode());
+    assert!(runtime.runtime_calls().is_empty());
+
+    let message = vec![3u8, 4, 0];
+
+    runtime.function("execute", message.encode());
+    assert_eq!(runtime.output(), true.encode());
+    assert_eq!(runtime.xcm_messages(), vec![message.clone()]);
+
+    runtime.function("send", (vec![3u8, 1, 0], message.clone()).encode());
+    let message_hash = <[u8; 32]>::try_from(blake2b(32, &[], &message).as_bytes()).unwrap();
+    assert_eq!(runtime.output(), (true, message_hash).encode());
+    assert_eq!(runtime.xcm_messages(), vec![message]);
+
+    runtime.function("send", (vec![3u8, 1, 0], Vec::<u8>::new()).encode());
+    assert_eq!(runtime.output(), (false, [0u8; 32]).encode());
+}
+
+#[test]
+fn caller_and_reentrancy() {
+    let mut runtime = build_solidity(
+        r##"
+        import "substrate";
+
+        contract Introspection {
+            function test() public view returns (bool, bool, uint32, uint32, uint32) {
+                return (
+                    caller_is_origin(),
+                    caller_is_root(),
+                    reentrance_count(),
+                    account_reentrance_count(address(this)),
+                    account_reentrance_count(msg.sender)
+                );
+            }
+
+            function nested() public returns (bytes) {
+                Other other = new Other();
+                return other.reenter(address(this));
+            }
+        }
+
+        contract Other {
+            function reenter(address introspection) public returns (bytes) {
+                // ALLOW_REENTRY
+                (bool ok, bytes raw) = introspection.call{flags: 8}(
+                    abi.encodeCall(Introspection.test, ())
+                );
+                require(ok);
+                return raw;
+            }
+        }"##,
+    );
+
+    runtime.function("test", Vec::new());
+    assert_eq!(runtime.output(), (true, false, 0u32, 1u32, 0u32).encode());
+
+    runtime.function("nested", Vec::new());
+    let raw = Vec::<u8>::decode(&mut &runtime.output()[..]).unwrap();
+    assert_eq!(raw, (false, false, 1u32, 2u32, 1u32).encode());
+}
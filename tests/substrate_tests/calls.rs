@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{build_solidity, build_solidity_with_options};
+use crate::{build_solidity, build_solidity_with_options, build_solidity_with_target};
 use parity_scale_codec::{Decode, Encode};
+use solang::{SubstrateApiVersion, Target};
 
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 struct RevertReturn(u32, String);
@@ -868,6 +869,43 @@ call: seal_call=0,
     );
 }
 
+#[test]
+fn api_version_1() {
+    let mut runtime = build_solidity_with_target(
+        r##"
+        contract Test {
+            constructor () payable {}
+
+            function test(int64 a) public returns (int64) {
+                Other o = new Other{value: 100}(a);
+                return o.double();
+            }
+        }
+        contract Other {
+            int64 v;
+
+            constructor (int64 a) payable {
+                v = a;
+            }
+
+            function double() public view returns (int64) {
+                return v * 2;
+            }
+        }
+        "##,
+        Target::Substrate {
+            address_length: 32,
+            value_length: 16,
+            api_version: SubstrateApiVersion::V1,
+        },
+    );
+
+    runtime.set_transferred_value(1000);
+    runtime.constructor(0, vec![]);
+    runtime.function("test", 21i64.encode());
+    assert_eq!(runtime.output(), 42i64.encode());
+}
+
 #[test]
 fn selector() {
     let mut runtime = build_solidity_with_options(
@@ -1041,3 +1079,49 @@ contract Flagger {
     );
     assert_eq!(u32::decode(&mut &runtime.output()[..]).unwrap(), voyager);
 }
+
+#[test]
+fn delegate_call() {
+    let mut runtime = build_solidity(
+        r##"
+        contract Delegator {
+            uint64 public value;
+            address public sender;
+
+            function delegate(address callee, uint64 v) public {
+                (bool ok, ) = callee.delegatecall(abi.encodeCall(Delegatee.set, (v)));
+                require(ok, "delegatecall failed");
+            }
+        }
+
+        contract Delegatee {
+            uint64 public value;
+            address public sender;
+
+            function set(uint64 v) public {
+                value = v;
+                sender = msg.sender;
+            }
+        }"##,
+    );
+
+    runtime.constructor(0, Vec::new());
+    let delegatee = runtime.0.data().accounts[1].address;
+
+    runtime.function("delegate", (delegatee, 42u64).encode());
+
+    // The code of the delegatee ran on the storage of the delegator
+    runtime.function("value", Vec::new());
+    assert_eq!(runtime.output(), 42u64.encode());
+    runtime.function("sender", Vec::new());
+    assert_eq!(runtime.output(), runtime.caller().to_vec());
+    assert!(runtime.0.data().accounts[1]
+        .contract
+        .as_ref()
+        .unwrap()
+        .storage
+        .is_empty());
+
+    // Delegating to an account without code must fail
+    runtime.function_expect_failure("delegate", ([0u8; 32], 1u64).encode());
+}
@@ -401,3 +401,56 @@ fn test_address() {
         assert_eq!(runtime.output(), Val(val.1).encode());
     }
 }
+
+#[test]
+fn contains_and_take_storage() {
+    let mut runtime = build_solidity(
+        r##"
+        contract c {
+            mapping(uint64 => int64) values;
+            mapping(uint64 => string) names;
+
+            function set(uint64 key, int64 value, string name) public {
+                values[key] = value;
+                names[key] = name;
+            }
+
+            function contains(uint64 key) public view returns (bool, bool) {
+                return (contains_storage(values[key]), contains_storage(names[key]));
+            }
+
+            function remove(uint64 key) public returns (int64) {
+                return take_storage(values[key]);
+            }
+
+            function get(uint64 key) public view returns (int64) {
+                return values[key];
+            }
+        }"##,
+    );
+
+    runtime.function("contains", 1u64.encode());
+    assert_eq!(runtime.output(), (false, false).encode());
+
+    runtime.function("set", (1u64, -102i64, "foo".to_string()).encode());
+    runtime.function("set", (2u64, 0i64, String::new()).encode());
+
+    runtime.function("contains", 1u64.encode());
+    assert_eq!(runtime.output(), (true, true).encode());
+
+    // a zero integer is stored, but an empty string is not
+    runtime.function("contains", 2u64.encode());
+    assert_eq!(runtime.output(), (true, false).encode());
+
+    runtime.function("remove", 1u64.encode());
+    assert_eq!(runtime.output(), (-102i64).encode());
+
+    runtime.function("contains", 1u64.encode());
+    assert_eq!(runtime.output(), (false, true).encode());
+
+    runtime.function("get", 1u64.encode());
+    assert_eq!(runtime.output(), 0i64.encode());
+
+    runtime.function("remove", 1u64.encode());
+    assert_eq!(runtime.output(), 0i64.encode());
+}
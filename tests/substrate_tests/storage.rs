@@ -2,6 +2,7 @@
 
 use crate::build_solidity;
 use parity_scale_codec::{Decode, Encode};
+use primitive_types::U256;
 
 #[test]
 fn storage_load_on_return() {
@@ -37,3 +38,91 @@ contract foo {
         [SStruct { f1: 1 }, SStruct { f1: 2 }].encode(),
     );
 }
+
+#[test]
+fn transient_storage() {
+    let mut runtime = build_solidity(
+        r##"
+contract foo {
+    uint64 transient counter;
+    bool transient locked;
+    uint64 total;
+
+    function bump() public returns (uint64) {
+        counter += 1;
+        counter++;
+        total += counter;
+        return counter;
+    }
+
+    function guarded() public returns (bool) {
+        require(!locked, "reentrant");
+        locked = true;
+        bool was_locked = locked;
+        delete locked;
+        return was_locked && !locked;
+    }
+
+    function get() public view returns (uint64, uint64) {
+        return (counter, total);
+    }
+}
+        "##,
+    );
+
+    runtime.function("bump", Vec::new());
+    assert_eq!(runtime.output(), 2u64.encode());
+
+    // transient storage does not survive the transaction
+    runtime.function("bump", Vec::new());
+    assert_eq!(runtime.output(), 2u64.encode());
+
+    runtime.function("get", Vec::new());
+    assert_eq!(runtime.output(), (0u64, 4u64).encode());
+
+    runtime.function("guarded", Vec::new());
+    assert_eq!(runtime.output(), true.encode());
+
+    // only the persistent variable is in contract storage
+    assert_eq!(runtime.storage().len(), 1);
+}
+
+#[test]
+fn transient_storage_assembly() {
+    let mut runtime = build_solidity(
+        r##"
+contract foo {
+    uint256 transient value;
+    uint64 transient counter;
+
+    function add(uint256 v) public returns (uint256 r, uint256 s) {
+        value = 100;
+        assembly {
+            tstore(value.slot, add(tload(value.slot), v))
+            r := tload(value.slot)
+        }
+        s = value;
+    }
+
+    function counter_slot() public returns (uint256 r) {
+        counter = 7;
+        assembly {
+            r := tload(counter.slot)
+        }
+    }
+}
+        "##,
+    );
+
+    runtime.function("add", U256::from(5).encode());
+    assert_eq!(
+        runtime.output(),
+        (U256::from(105), U256::from(105)).encode()
+    );
+
+    // the uint64 is zero extended
+    runtime.function("counter_slot", Vec::new());
+    assert_eq!(runtime.output(), U256::from(7).encode());
+
+    assert!(runtime.storage().is_empty());
+}
@@ -3,7 +3,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{quote, ToTokens};
-use syn::{ImplItem, ItemImpl, LitInt, Type};
+use syn::{parse::ParseStream, Ident, ImplItem, ItemImpl, LitInt, Token, Type};
 
 struct HostFn {
     name: String,
@@ -20,15 +20,25 @@ impl HostFn {
             _ => return None, // Only care about functions
         };
 
-        let module = item
+        let (version, name) = item
             .attrs
             .iter()
             .find(|attr| attr.path().get_ident().unwrap() == "seal")
-            .map(|attr| format!("seal{}", attr.parse_args::<LitInt>().unwrap()))?;
+            .map(|attr| {
+                attr.parse_args_with(|input: ParseStream| {
+                    let version: LitInt = input.parse()?;
+                    let name = match input.parse::<Option<Token![,]>>()? {
+                        Some(_) => Some(input.parse::<Ident>()?),
+                        None => None,
+                    };
+                    Ok((version, name))
+                })
+                .unwrap()
+            })?;
 
         Some(HostFn {
-            name: item.sig.ident.to_string(),
-            module,
+            name: name.unwrap_or_else(|| item.sig.ident.clone()).to_string(),
+            module: format!("seal{version}"),
             params: item.sig.inputs.to_token_stream(),
             block: item.block.to_token_stream(),
             returns: item.sig.output.to_token_stream(),
@@ -60,6 +70,8 @@ impl HostFn {
 /// Should be used on a dedicated impl block on the host state type.
 ///
 /// Wraps functions with the `[seal(n)]` attribute, where n is the version number, into a wasmi host function.
+/// The function is imported under its own name, unless another name is given with `[seal(n, name)]`; this
+/// allows for defining several versions of the same host function.
 /// The function signature should match exactly the signature of the closure going into [`Func::wrap`][1].
 /// There will be two local variables brought into scope:
 /// * `mem` for accessing the memory